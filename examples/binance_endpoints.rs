@@ -7,7 +7,7 @@ use binance::config::Config;
 use binance::errors::Error as BinanceLibError;
 use binance::general::*;
 use binance::market::*;
-use binance::rest_model::{OrderSide, OrderType, SymbolPrice, TimeInForce};
+use binance::rest_model::{Number, OrderSide, OrderType, SymbolPrice, TimeInForce};
 use env_logger::Builder;
 
 #[tokio::main]
@@ -56,6 +56,8 @@ async fn account() {
     let account: Account = Binance::new_with_env(&Config::testnet());
     let symbol = "BTCUSDT";
     let SymbolPrice { price, .. } = market.get_price(symbol).await.unwrap();
+    // parsed so that the example builds with the decimal feature
+    let qty: Number = "0.001".parse().unwrap();
     match account.get_account().await {
         Ok(answer) => info!("{:?}", answer.balances),
         Err(e) => error!("Error: {e}"),
@@ -68,7 +70,7 @@ async fn account() {
 
     let limit_buy = OrderRequest {
         symbol: symbol.to_string(),
-        quantity: Some(0.001),
+        quantity: Some(qty),
         price: Some(price),
         order_type: OrderType::Limit,
         side: OrderSide::Buy,
@@ -82,7 +84,7 @@ async fn account() {
 
     let market_buy = OrderRequest {
         symbol: symbol.to_string(),
-        quantity: Some(0.001),
+        quantity: Some(qty),
         order_type: OrderType::Market,
         side: OrderSide::Buy,
         ..OrderRequest::default()
@@ -94,7 +96,7 @@ async fn account() {
 
     let limit_sell = OrderRequest {
         symbol: symbol.to_string(),
-        quantity: Some(0.001),
+        quantity: Some(qty),
         price: Some(price),
         order_type: OrderType::Limit,
         side: OrderSide::Sell,
@@ -108,7 +110,7 @@ async fn account() {
 
     let market_sell = OrderRequest {
         symbol: symbol.to_string(),
-        quantity: Some(0.001),
+        quantity: Some(qty),
         order_type: OrderType::Market,
         side: OrderSide::Sell,
         ..OrderRequest::default()
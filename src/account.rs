@@ -9,6 +9,12 @@ static API_V3_ALL_ORDERS: &str = "/api/v3/allOrders";
 static API_V3_MYTRADES: &str = "/api/v3/myTrades";
 static API_V3_ORDER: &str = "/api/v3/order";
 static API_V3_CANCEL_REPLACE: &str = "/api/v3/order/cancelReplace";
+static API_V3_ORDER_LIST: &str = "/api/v3/orderList";
+static API_V3_ORDER_LIST_OCO: &str = "/api/v3/orderList/oco";
+static API_V3_ORDER_LIST_OTO: &str = "/api/v3/orderList/oto";
+static API_V3_ORDER_LIST_OTOCO: &str = "/api/v3/orderList/otoco";
+static API_V3_ALL_ORDER_LIST: &str = "/api/v3/allOrderList";
+static API_V3_OPEN_ORDER_LIST: &str = "/api/v3/openOrderList";
 /// Endpoint for test orders.
 /// Orders issued to this endpoint are validated, but not sent into the matching engine.
 static API_V3_ORDER_TEST: &str = "/api/v3/order/test";
@@ -30,15 +36,15 @@ pub struct OrderRequest {
     #[serde(rename = "type")]
     pub order_type: OrderType,
     pub time_in_force: Option<TimeInForce>,
-    pub quantity: Option<f64>,
-    pub quote_order_qty: Option<f64>,
-    pub price: Option<f64>,
+    pub quantity: Option<Number>,
+    pub quote_order_qty: Option<Number>,
+    pub price: Option<Number>,
     /// A unique id for the order, automatically generated if not sent.
     pub new_client_order_id: Option<String>,
     /// Used with stop loss, stop loss limit, take profit and take profit limit order types.
-    pub stop_price: Option<f64>,
+    pub stop_price: Option<Number>,
     /// Used with limit, stop loss limit and take profit limit to create an iceberg order.
-    pub iceberg_qty: Option<f64>,
+    pub iceberg_qty: Option<Number>,
     /// Set the response json, market and limit default to full others to ack.
     pub new_order_resp_type: Option<OrderResponse>,
     /// Cannot be greater than 60000
@@ -46,7 +52,7 @@ pub struct OrderRequest {
 }
 
 impl OrderRequest {
-    fn valid(&self) -> Result<()> {
+    pub(crate) fn valid(&self) -> Result<()> {
         if self.iceberg_qty.is_some() && self.time_in_force != Some(TimeInForce::GTC) {
             return Err(Error::InvalidOrderError {
                 msg: "Time in force has to be GTC for iceberg orders".to_string(),
@@ -83,15 +89,15 @@ pub struct CancelReplaceRequest {
     pub order_type: OrderType,
     pub cancel_replace_mode: CancelReplaceMode,
     pub time_in_force: Option<TimeInForce>,
-    pub quantity: Option<f64>,
-    pub quote_order_qty: Option<f64>,
-    pub price: Option<f64>,
+    pub quantity: Option<Number>,
+    pub quote_order_qty: Option<Number>,
+    pub price: Option<Number>,
     pub cancel_new_client_order_id: Option<String>,
     pub cancel_orig_client_order_id: Option<String>,
     pub cancel_order_id: Option<u64>,
     pub new_client_order_id: Option<String>,
-    pub stop_price: Option<f64>,
-    pub iceberg_qty: Option<f64>,
+    pub stop_price: Option<Number>,
+    pub iceberg_qty: Option<Number>,
     pub new_order_resp_type: Option<OrderResponse>,
     /// Cannot be greater than 60000
     pub recv_window: Option<u64>,
@@ -136,6 +142,149 @@ pub struct OrdersQuery {
     pub recv_window: Option<u64>,
 }
 
+/// OCO Order Request
+/// places a pair of orders on each side of the market price, when one of them executes the other is canceled
+///
+/// The above order is one of LIMIT_MAKER, STOP_LOSS or STOP_LOSS_LIMIT for a sell
+/// and TAKE_PROFIT or TAKE_PROFIT_LIMIT for a buy, the below order is the mirror.
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OCOOrderRequest {
+    pub symbol: String,
+    /// A unique id for the list, automatically generated if not sent.
+    pub list_client_order_id: Option<String>,
+    pub side: OrderSide,
+    /// Quantity of both orders
+    pub quantity: Number,
+    pub above_type: OrderType,
+    pub above_client_order_id: Option<String>,
+    pub above_iceberg_qty: Option<Number>,
+    pub above_price: Option<Number>,
+    pub above_stop_price: Option<Number>,
+    pub above_trailing_delta: Option<u64>,
+    pub above_time_in_force: Option<TimeInForce>,
+    pub below_type: OrderType,
+    pub below_client_order_id: Option<String>,
+    pub below_iceberg_qty: Option<Number>,
+    pub below_price: Option<Number>,
+    pub below_stop_price: Option<Number>,
+    pub below_trailing_delta: Option<u64>,
+    pub below_time_in_force: Option<TimeInForce>,
+    pub new_order_resp_type: Option<OrderResponse>,
+    /// Cannot be greater than 60000
+    pub recv_window: Option<u64>,
+}
+
+/// OTO Order Request
+/// places a working order and a pending order that is only placed once the working order fills
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OTOOrderRequest {
+    pub symbol: String,
+    /// A unique id for the list, automatically generated if not sent.
+    pub list_client_order_id: Option<String>,
+    pub new_order_resp_type: Option<OrderResponse>,
+    /// LIMIT or LIMIT_MAKER
+    pub working_type: OrderType,
+    pub working_side: OrderSide,
+    pub working_client_order_id: Option<String>,
+    pub working_price: Number,
+    pub working_quantity: Number,
+    pub working_iceberg_qty: Option<Number>,
+    pub working_time_in_force: Option<TimeInForce>,
+    pub pending_type: OrderType,
+    pub pending_side: OrderSide,
+    pub pending_client_order_id: Option<String>,
+    pub pending_price: Option<Number>,
+    pub pending_stop_price: Option<Number>,
+    pub pending_trailing_delta: Option<u64>,
+    pub pending_quantity: Number,
+    pub pending_iceberg_qty: Option<Number>,
+    pub pending_time_in_force: Option<TimeInForce>,
+    /// Cannot be greater than 60000
+    pub recv_window: Option<u64>,
+}
+
+/// OTOCO Order Request
+/// places a working order and a pending OCO pair that is only placed once the working order fills
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OTOCOOrderRequest {
+    pub symbol: String,
+    /// A unique id for the list, automatically generated if not sent.
+    pub list_client_order_id: Option<String>,
+    pub new_order_resp_type: Option<OrderResponse>,
+    /// LIMIT or LIMIT_MAKER
+    pub working_type: OrderType,
+    pub working_side: OrderSide,
+    pub working_client_order_id: Option<String>,
+    pub working_price: Number,
+    pub working_quantity: Number,
+    pub working_iceberg_qty: Option<Number>,
+    pub working_time_in_force: Option<TimeInForce>,
+    /// Side of both pending orders
+    pub pending_side: OrderSide,
+    /// Quantity of both pending orders
+    pub pending_quantity: Number,
+    pub pending_above_type: OrderType,
+    pub pending_above_client_order_id: Option<String>,
+    pub pending_above_price: Option<Number>,
+    pub pending_above_stop_price: Option<Number>,
+    pub pending_above_trailing_delta: Option<u64>,
+    pub pending_above_iceberg_qty: Option<Number>,
+    pub pending_above_time_in_force: Option<TimeInForce>,
+    pub pending_below_type: Option<OrderType>,
+    pub pending_below_client_order_id: Option<String>,
+    pub pending_below_price: Option<Number>,
+    pub pending_below_stop_price: Option<Number>,
+    pub pending_below_trailing_delta: Option<u64>,
+    pub pending_below_iceberg_qty: Option<Number>,
+    pub pending_below_time_in_force: Option<TimeInForce>,
+    /// Cannot be greater than 60000
+    pub recv_window: Option<u64>,
+}
+
+/// Order List Cancellation Request
+/// cancels every order of a list,
+/// either order_list_id or list_client_order_id must be set
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderListCancellation {
+    pub symbol: String,
+    pub order_list_id: Option<u64>,
+    pub list_client_order_id: Option<String>,
+    /// Used to uniquely identify this cancel. Automatically generated by default.
+    pub new_client_order_id: Option<String>,
+    /// Cannot be greater than 60000
+    pub recv_window: Option<u64>,
+}
+
+/// Order List Status Request
+/// either order_list_id or orig_client_order_id must be set
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderListStatusRequest {
+    pub order_list_id: Option<u64>,
+    /// The list client order id
+    pub orig_client_order_id: Option<String>,
+    /// Cannot be greater than 60000
+    pub recv_window: Option<u64>,
+}
+
+/// Order Lists Query
+/// perform a query on all order lists for the account, from_id cannot be combined with a time range
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderListsQuery {
+    pub from_id: Option<u64>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Default 500 max 1000
+    pub limit: Option<u32>,
+    /// Cannot be greater than 60000
+    pub recv_window: Option<u64>,
+}
+
 impl Account {
     /// General account information
     /// # Examples
@@ -297,8 +446,8 @@ impl Account {
     /// let account: Account = Binance::new_with_env(&Config::testnet());
     /// let limit_buy = OrderRequest {
     ///         symbol: "BTCUSDT".to_string(),
-    ///         quantity: Some(10.0),
-    ///         price: Some(0.014000),
+    ///         quantity: Some("10.0".parse().unwrap()),
+    ///         price: Some("0.014000".parse().unwrap()),
     ///         order_type: OrderType::Limit,
     ///         side: OrderSide::Buy,
     ///         time_in_force: Some(TimeInForce::FOK),
@@ -324,8 +473,8 @@ impl Account {
     /// let account: Account = Binance::new_with_env(&Config::testnet());
     /// let limit_buy = OrderRequest {
     ///         symbol: "BTCUSDT".to_string(),
-    ///         quantity: Some(10.0),
-    ///         price: Some(0.014000),
+    ///         quantity: Some("10.0".parse().unwrap()),
+    ///         price: Some("0.014000".parse().unwrap()),
     ///         order_type: OrderType::Limit,
     ///         side: OrderSide::Buy,
     ///         time_in_force: Some(TimeInForce::FOK),
@@ -392,6 +541,110 @@ impl Account {
         self.client.delete_signed(API_V3_ORDER_TEST, &request).await
     }
 
+    /// Place an OCO order list
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, account::*, config::*, rest_model::*};
+    /// let account: Account = Binance::new_with_env(&Config::testnet());
+    /// let oco = OCOOrderRequest {
+    ///     symbol: "BTCUSDT".to_string(),
+    ///     side: OrderSide::Sell,
+    ///     quantity: "0.01".parse().unwrap(),
+    ///     above_type: OrderType::LimitMaker,
+    ///     above_price: Some("70000.0".parse().unwrap()),
+    ///     below_type: OrderType::StopLossLimit,
+    ///     below_price: Some("59000.0".parse().unwrap()),
+    ///     below_stop_price: Some("60000.0".parse().unwrap()),
+    ///     below_time_in_force: Some(TimeInForce::GTC),
+    ///     ..OCOOrderRequest::default()
+    /// };
+    /// let order_list = tokio_test::block_on(account.place_oco_order(oco));
+    /// assert!(order_list.is_ok(), "{:?}", order_list);
+    /// ```
+    pub async fn place_oco_order(&self, order: OCOOrderRequest) -> Result<OrderList> {
+        let recv_window = order.recv_window.unwrap_or(self.recv_window);
+        let request = build_signed_request_p(order, recv_window)?;
+        self.client.post_signed(API_V3_ORDER_LIST_OCO, &request).await
+    }
+
+    /// Place an OTO order list
+    pub async fn place_oto_order(&self, order: OTOOrderRequest) -> Result<OrderList> {
+        let recv_window = order.recv_window.unwrap_or(self.recv_window);
+        let request = build_signed_request_p(order, recv_window)?;
+        self.client.post_signed(API_V3_ORDER_LIST_OTO, &request).await
+    }
+
+    /// Place an OTOCO order list
+    pub async fn place_otoco_order(&self, order: OTOCOOrderRequest) -> Result<OrderList> {
+        let recv_window = order.recv_window.unwrap_or(self.recv_window);
+        let request = build_signed_request_p(order, recv_window)?;
+        self.client.post_signed(API_V3_ORDER_LIST_OTOCO, &request).await
+    }
+
+    /// Cancel every order of an order list
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, account::*, config::*};
+    /// let account: Account = Binance::new_with_env(&Config::testnet());
+    /// let query = OrderListCancellation {
+    ///     symbol: "BTCUSDT".to_string(),
+    ///     order_list_id: Some(1),
+    ///     ..OrderListCancellation::default()
+    /// };
+    /// let canceled = tokio_test::block_on(account.cancel_order_list(query));
+    /// assert!(canceled.is_ok(), "{:?}", canceled);
+    /// ```
+    pub async fn cancel_order_list(&self, o: OrderListCancellation) -> Result<OrderList> {
+        let recv_window = o.recv_window.unwrap_or(self.recv_window);
+        let request = build_signed_request_p(o, recv_window)?;
+        self.client.delete_signed(API_V3_ORDER_LIST, &request).await
+    }
+
+    /// Check an order list's status
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, account::*, config::*};
+    /// let account: Account = Binance::new_with_env(&Config::testnet());
+    /// let query = OrderListStatusRequest {
+    ///     order_list_id: Some(1),
+    ///     ..OrderListStatusRequest::default()
+    /// };
+    /// let order_list = tokio_test::block_on(account.order_list_status(query));
+    /// assert!(order_list.is_ok(), "{:?}", order_list);
+    /// ```
+    pub async fn order_list_status(&self, olsr: OrderListStatusRequest) -> Result<OrderList> {
+        let recv_window = olsr.recv_window.unwrap_or(self.recv_window);
+        let request = build_signed_request_p(olsr, recv_window)?;
+        self.client.get_signed(API_V3_ORDER_LIST, &request).await
+    }
+
+    /// All order lists for the account
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, account::*, config::*};
+    /// let account: Account = Binance::new_with_env(&Config::testnet());
+    /// let order_lists = tokio_test::block_on(account.get_all_order_lists(OrderListsQuery::default()));
+    /// assert!(order_lists.is_ok(), "{:?}", order_lists);
+    /// ```
+    pub async fn get_all_order_lists(&self, query: OrderListsQuery) -> Result<Vec<OrderList>> {
+        let recv_window = query.recv_window.unwrap_or(self.recv_window);
+        let request = build_signed_request_p(query, recv_window)?;
+        self.client.get_signed(API_V3_ALL_ORDER_LIST, &request).await
+    }
+
+    /// All currently open order lists for the account
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, account::*, config::*};
+    /// let account: Account = Binance::new_with_env(&Config::testnet());
+    /// let order_lists = tokio_test::block_on(account.get_open_order_lists());
+    /// assert!(order_lists.is_ok(), "{:?}", order_lists);
+    /// ```
+    pub async fn get_open_order_lists(&self) -> Result<Vec<OrderList>> {
+        let request = build_signed_request([("", "")], self.recv_window)?;
+        self.client.get_signed(API_V3_OPEN_ORDER_LIST, &request).await
+    }
+
     /// Trade history
     /// # Examples
     /// ```rust,no_run
@@ -29,7 +29,7 @@ pub trait Binance: Sized {
 impl Binance for General {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> General {
         General {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone(), config.timeout),
+            client: Client::new_with_config(api_key, secret_key, config.rest_api_endpoint.clone(), config),
         }
     }
 }
@@ -37,7 +37,7 @@ impl Binance for General {
 impl Binance for Account {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Account {
         Account {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone(), config.timeout),
+            client: Client::new_with_config(api_key, secret_key, config.rest_api_endpoint.clone(), config),
             recv_window: config.recv_window,
         }
     }
@@ -47,7 +47,7 @@ impl Binance for Account {
 impl Binance for crate::savings::Savings {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
         Self {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone(), config.timeout),
+            client: Client::new_with_config(api_key, secret_key, config.rest_api_endpoint.clone(), config),
             recv_window: config.recv_window,
         }
     }
@@ -56,7 +56,7 @@ impl Binance for crate::savings::Savings {
 impl Binance for Market {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Market {
         Market {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone(), config.timeout),
+            client: Client::new_with_config(api_key, secret_key, config.rest_api_endpoint.clone(), config),
             recv_window: config.recv_window,
         }
     }
@@ -65,7 +65,7 @@ impl Binance for Market {
 impl Binance for UserStream {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> UserStream {
         UserStream {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone(), config.timeout),
+            client: Client::new_with_config(api_key, secret_key, config.rest_api_endpoint.clone(), config),
             recv_window: config.recv_window,
         }
     }
@@ -75,7 +75,7 @@ impl Binance for UserStream {
 impl Binance for crate::futures::userstream::FuturesUserStream {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> FuturesUserStream {
         FuturesUserStream {
-            client: Client::new(api_key, secret_key, config.futures_rest_api_endpoint.clone(), config.timeout),
+            client: Client::new_with_config(api_key, secret_key, config.futures_rest_api_endpoint.clone(), config),
             recv_window: config.recv_window,
         }
     }
@@ -86,11 +86,11 @@ impl Binance for crate::futures::userstream::FuturesUserStream {
 impl Binance for crate::futures::general::FuturesGeneral {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
         Self {
-            client: Client::new(
+            client: Client::new_with_config(
                 api_key,
                 secret_key,
                 config.futures_rest_api_endpoint.clone(),
-                config.timeout,
+                config,
             ),
         }
     }
@@ -100,11 +100,11 @@ impl Binance for crate::futures::general::FuturesGeneral {
 impl Binance for crate::futures::market::FuturesMarket {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
         Self {
-            client: Client::new(
+            client: Client::new_with_config(
                 api_key,
                 secret_key,
                 config.futures_rest_api_endpoint.clone(),
-                config.timeout,
+                config,
             ),
             recv_window: config.recv_window,
         }
@@ -115,22 +115,157 @@ impl Binance for crate::futures::market::FuturesMarket {
 impl Binance for crate::futures::account::FuturesAccount {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
         Self {
-            client: Client::new(
+            client: Client::new_with_config(
                 api_key,
                 secret_key,
                 config.futures_rest_api_endpoint.clone(),
-                config.timeout,
+                config,
             ),
             recv_window: config.recv_window,
         }
     }
 }
 
+#[cfg(feature = "futures_api")]
+impl Binance for crate::coin_futures::userstream::CoinFuturesUserStream {
+    fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
+        Self {
+            client: Client::new_with_config(
+                api_key,
+                secret_key,
+                config.coin_futures_rest_api_endpoint.clone(),
+                config,
+            ),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+#[cfg(feature = "futures_api")]
+impl Binance for crate::coin_futures::general::CoinFuturesGeneral {
+    fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
+        Self {
+            client: Client::new_with_config(
+                api_key,
+                secret_key,
+                config.coin_futures_rest_api_endpoint.clone(),
+                config,
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "futures_api")]
+impl Binance for crate::coin_futures::market::CoinFuturesMarket {
+    fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
+        Self {
+            client: Client::new_with_config(
+                api_key,
+                secret_key,
+                config.coin_futures_rest_api_endpoint.clone(),
+                config,
+            ),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+#[cfg(feature = "futures_api")]
+impl Binance for crate::coin_futures::account::CoinFuturesAccount {
+    fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
+        Self {
+            client: Client::new_with_config(
+                api_key,
+                secret_key,
+                config.coin_futures_rest_api_endpoint.clone(),
+                config,
+            ),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+#[cfg(feature = "options")]
+impl Binance for crate::options::general::OptionsGeneral {
+    fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
+        Self {
+            client: Client::new_with_config(
+                api_key,
+                secret_key,
+                config.options_rest_api_endpoint.clone(),
+                config,
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "options")]
+impl Binance for crate::options::market::OptionsMarket {
+    fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
+        Self {
+            client: Client::new_with_config(
+                api_key,
+                secret_key,
+                config.options_rest_api_endpoint.clone(),
+                config,
+            ),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+#[cfg(feature = "options")]
+impl Binance for crate::options::account::OptionsAccount {
+    fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
+        Self {
+            client: Client::new_with_config(
+                api_key,
+                secret_key,
+                config.options_rest_api_endpoint.clone(),
+                config,
+            ),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+#[cfg(feature = "portfolio_margin")]
+impl Binance for crate::portfolio_margin::account::PortfolioMarginAccount {
+    fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
+        Self {
+            client: Client::new_with_config(
+                api_key,
+                secret_key,
+                config.portfolio_margin_rest_api_endpoint.clone(),
+                config,
+            )
+            .with_time_host(&config.rest_api_endpoint),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+#[cfg(feature = "portfolio_margin")]
+impl Binance for crate::portfolio_margin::userstream::PortfolioMarginUserStream {
+    fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
+        Self {
+            client: Client::new_with_config(
+                api_key,
+                secret_key,
+                config.portfolio_margin_rest_api_endpoint.clone(),
+                config,
+            )
+            .with_time_host(&config.rest_api_endpoint),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
 #[cfg(feature = "margin_api")]
 impl Binance for crate::margin::Margin {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
         Self {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone(), config.timeout),
+            client: Client::new_with_config(api_key, secret_key, config.rest_api_endpoint.clone(), config),
             recv_window: config.recv_window,
         }
     }
@@ -140,9 +275,213 @@ impl Binance for crate::margin::Margin {
 impl Binance for crate::wallet::Wallet {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
         Self {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone(), config.timeout),
+            client: Client::new_with_config(api_key, secret_key, config.rest_api_endpoint.clone(), config),
             recv_window: config.recv_window,
             binance_us_api: config.binance_us_api,
         }
     }
 }
+
+/// Handles to every API sharing one connection pool, the credentials, the rate limiter and the time sync
+///
+/// Handles created with [`Binance::new_with_config`] each open their own connections,
+/// those of a `BinanceClient` are cheap to create and can be created per call.
+/// # Examples
+/// ```rust,no_run
+/// use binance::{api::*, config::*};
+/// let binance = BinanceClient::new_with_env(&Config::testnet()).unwrap();
+/// tokio_test::block_on(async {
+///     binance.sync_time().await?;
+///     let account = binance.spot().get_account().await?;
+///     let price = binance.market().get_price("BTCUSDT").await?;
+///     Ok::<_, binance::errors::Error>(())
+/// });
+/// ```
+#[derive(Clone)]
+pub struct BinanceClient {
+    client: Client,
+    config: Config,
+}
+
+impl BinanceClient {
+    /// Fails when the private key of the signature method is invalid or the HTTP client cannot be built
+    pub fn new(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> crate::errors::Result<Self> {
+        Ok(BinanceClient {
+            client: Client::try_new_with_config(api_key, secret_key, config.rest_api_endpoint.clone(), config)?,
+            config: config.clone(),
+        })
+    }
+
+    /// Create a client using the BINANCE_API_KEY and BINANCE_API_SECRET_KEY environment variables for credentials
+    pub fn new_with_env(config: &Config) -> crate::errors::Result<Self> {
+        let api_key = std::env::var("BINANCE_API_KEY").ok();
+        let secret = std::env::var("BINANCE_API_SECRET_KEY").ok();
+        Self::new(api_key, secret, config)
+    }
+
+    pub fn config(&self) -> &Config { &self.config }
+
+    /// Measure the offset between the server clock and the local clock, applied to signed requests of every API
+    pub async fn sync_time(&self) -> crate::errors::Result<i64> { self.general().sync_time().await }
+
+    pub fn general(&self) -> General {
+        General {
+            client: self.client.with_host(&self.config.rest_api_endpoint),
+        }
+    }
+
+    pub fn market(&self) -> Market {
+        Market {
+            client: self.client.with_host(&self.config.rest_api_endpoint),
+            recv_window: self.config.recv_window,
+        }
+    }
+
+    pub fn spot(&self) -> Account {
+        Account {
+            client: self.client.with_host(&self.config.rest_api_endpoint),
+            recv_window: self.config.recv_window,
+        }
+    }
+
+    pub fn user_stream(&self) -> UserStream {
+        UserStream {
+            client: self.client.with_host(&self.config.rest_api_endpoint),
+            recv_window: self.config.recv_window,
+        }
+    }
+
+    #[cfg(feature = "margin_api")]
+    pub fn margin(&self) -> crate::margin::Margin {
+        crate::margin::Margin {
+            client: self.client.with_host(&self.config.rest_api_endpoint),
+            recv_window: self.config.recv_window,
+        }
+    }
+
+    #[cfg(feature = "wallet_api")]
+    pub fn wallet(&self) -> crate::wallet::Wallet {
+        crate::wallet::Wallet {
+            client: self.client.with_host(&self.config.rest_api_endpoint),
+            recv_window: self.config.recv_window,
+            binance_us_api: self.config.binance_us_api,
+        }
+    }
+
+    #[cfg(feature = "savings_api")]
+    pub fn savings(&self) -> crate::savings::Savings {
+        crate::savings::Savings {
+            client: self.client.with_host(&self.config.rest_api_endpoint),
+            recv_window: self.config.recv_window,
+        }
+    }
+
+    #[cfg(feature = "futures_api")]
+    pub fn futures_general(&self) -> crate::futures::general::FuturesGeneral {
+        crate::futures::general::FuturesGeneral {
+            client: self.client.with_host(&self.config.futures_rest_api_endpoint),
+        }
+    }
+
+    #[cfg(feature = "futures_api")]
+    pub fn futures_market(&self) -> crate::futures::market::FuturesMarket {
+        crate::futures::market::FuturesMarket {
+            client: self.client.with_host(&self.config.futures_rest_api_endpoint),
+            recv_window: self.config.recv_window,
+        }
+    }
+
+    /// USD-M futures account
+    #[cfg(feature = "futures_api")]
+    pub fn futures(&self) -> crate::futures::account::FuturesAccount {
+        crate::futures::account::FuturesAccount {
+            client: self.client.with_host(&self.config.futures_rest_api_endpoint),
+            recv_window: self.config.recv_window,
+        }
+    }
+
+    #[cfg(feature = "futures_api")]
+    pub fn futures_user_stream(&self) -> FuturesUserStream {
+        FuturesUserStream {
+            client: self.client.with_host(&self.config.futures_rest_api_endpoint),
+            recv_window: self.config.recv_window,
+        }
+    }
+
+    #[cfg(feature = "futures_api")]
+    pub fn coin_futures_general(&self) -> crate::coin_futures::general::CoinFuturesGeneral {
+        crate::coin_futures::general::CoinFuturesGeneral {
+            client: self.client.with_host(&self.config.coin_futures_rest_api_endpoint),
+        }
+    }
+
+    #[cfg(feature = "futures_api")]
+    pub fn coin_futures_market(&self) -> crate::coin_futures::market::CoinFuturesMarket {
+        crate::coin_futures::market::CoinFuturesMarket {
+            client: self.client.with_host(&self.config.coin_futures_rest_api_endpoint),
+            recv_window: self.config.recv_window,
+        }
+    }
+
+    /// COIN-M futures account
+    #[cfg(feature = "futures_api")]
+    pub fn coin_futures(&self) -> crate::coin_futures::account::CoinFuturesAccount {
+        crate::coin_futures::account::CoinFuturesAccount {
+            client: self.client.with_host(&self.config.coin_futures_rest_api_endpoint),
+            recv_window: self.config.recv_window,
+        }
+    }
+
+    #[cfg(feature = "futures_api")]
+    pub fn coin_futures_user_stream(&self) -> crate::coin_futures::userstream::CoinFuturesUserStream {
+        crate::coin_futures::userstream::CoinFuturesUserStream {
+            client: self.client.with_host(&self.config.coin_futures_rest_api_endpoint),
+            recv_window: self.config.recv_window,
+        }
+    }
+
+    #[cfg(feature = "options")]
+    pub fn options_general(&self) -> crate::options::general::OptionsGeneral {
+        crate::options::general::OptionsGeneral {
+            client: self.client.with_host(&self.config.options_rest_api_endpoint),
+        }
+    }
+
+    #[cfg(feature = "options")]
+    pub fn options_market(&self) -> crate::options::market::OptionsMarket {
+        crate::options::market::OptionsMarket {
+            client: self.client.with_host(&self.config.options_rest_api_endpoint),
+            recv_window: self.config.recv_window,
+        }
+    }
+
+    #[cfg(feature = "options")]
+    pub fn options(&self) -> crate::options::account::OptionsAccount {
+        crate::options::account::OptionsAccount {
+            client: self.client.with_host(&self.config.options_rest_api_endpoint),
+            recv_window: self.config.recv_window,
+        }
+    }
+
+    #[cfg(feature = "portfolio_margin")]
+    pub fn portfolio_margin(&self) -> crate::portfolio_margin::account::PortfolioMarginAccount {
+        crate::portfolio_margin::account::PortfolioMarginAccount {
+            client: self
+                .client
+                .with_host(&self.config.portfolio_margin_rest_api_endpoint)
+                .with_time_host(&self.config.rest_api_endpoint),
+            recv_window: self.config.recv_window,
+        }
+    }
+
+    #[cfg(feature = "portfolio_margin")]
+    pub fn portfolio_margin_user_stream(&self) -> crate::portfolio_margin::userstream::PortfolioMarginUserStream {
+        crate::portfolio_margin::userstream::PortfolioMarginUserStream {
+            client: self
+                .client
+                .with_host(&self.config.portfolio_margin_rest_api_endpoint)
+                .with_time_host(&self.config.rest_api_endpoint),
+            recv_window: self.config.recv_window,
+        }
+    }
+}
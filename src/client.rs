@@ -1,24 +1,37 @@
-use std::time::Duration;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use boolinator::Boolinator;
-use hex::encode as hex_encode;
-use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE, USER_AGENT};
-use reqwest::Response;
+use chrono::Utc;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE, RETRY_AFTER, USER_AGENT};
+use reqwest::Method;
 use reqwest::StatusCode;
-use ring::hmac;
 use serde::de;
 use serde::de::DeserializeOwned;
+use tracing::warn;
 
 use crate::errors::error_messages;
+use crate::config::{Config, RetryPolicy};
 use crate::errors::*;
-use crate::util::{build_request_p, build_signed_request_p};
+use crate::rate_limit::{request_cost, RateLimiter};
+use crate::rest_model::ServerTime;
+use crate::signer::{config_signer, url_encoded_signature, HmacSigner, Signer};
+use crate::transport::{HttpRequest, HttpResponse, ReqwestTransport, Transport};
+use crate::util::{build_request_p, build_signed_request_p, refresh_timestamp, TimeSync, TimeSyncs};
 
 #[derive(Clone)]
 pub struct Client {
     api_key: String,
-    secret_key: String,
-    inner: reqwest::Client,
+    signer: std::result::Result<Arc<dyn Signer>, String>,
+    transport: std::result::Result<Arc<dyn Transport>, String>,
     host: String,
+    rate_limiter: Option<RateLimiter>,
+    retry_policy: Option<RetryPolicy>,
+    time_sync_interval: Option<u64>,
+    time_sync: Arc<TimeSync>,
+    time_syncs: TimeSyncs,
+    /// Host measuring the server time when the API of this client has no time endpoint
+    time_host: Option<String>,
 }
 
 impl Client {
@@ -26,24 +39,86 @@ impl Client {
     /// Credentials do not need to be specified when using public endpoints
     /// Host is mandatory
     pub fn new(api_key: Option<String>, secret_key: Option<String>, host: String, timeout: Option<u64>) -> Self {
-        let mut builder: reqwest::ClientBuilder = reqwest::ClientBuilder::new();
-        if let Some(timeout_secs) = timeout {
-            builder = builder.timeout(Duration::from_secs(timeout_secs))
-        }
+        let config = Config {
+            timeout,
+            ..Config::default()
+        };
+        Self::new_with_config(api_key, secret_key, host, &config)
+    }
+
+    /// Returns a client for the specified host using the options of `config`,
+    /// the secret key holds the private key of the configured signature method.
+    /// An invalid private key is reported by signed requests and an HTTP client that cannot be built by every request,
+    /// see [`Client::try_new_with_config`] to fail early
+    pub fn new_with_config(
+        api_key: Option<String>,
+        secret_key: Option<String>,
+        host: String,
+        config: &Config,
+    ) -> Self {
+        let signer = client_signer(config, secret_key.as_deref()).map_err(|e| e.to_string());
+        let transport = config_transport(config).map_err(|e| {
+            warn!("Cannot build the HTTP client, requests will fail: {e}");
+            e.to_string()
+        });
+        Self::with_parts(api_key, signer, transport, host, config)
+    }
+
+    /// Same as [`Client::new_with_config`], failing early when the private key is invalid
+    /// or the HTTP client cannot be built
+    pub fn try_new_with_config(
+        api_key: Option<String>,
+        secret_key: Option<String>,
+        host: String,
+        config: &Config,
+    ) -> Result<Self> {
+        let signer = client_signer(config, secret_key.as_deref())?;
+        let transport = config_transport(config).map_err(|e| Error::InvalidConfig(e.to_string()))?;
+        Ok(Self::with_parts(api_key, Ok(signer), Ok(transport), host, config))
+    }
+
+    fn with_parts(
+        api_key: Option<String>,
+        signer: std::result::Result<Arc<dyn Signer>, String>,
+        transport: std::result::Result<Arc<dyn Transport>, String>,
+        host: String,
+        config: &Config,
+    ) -> Self {
         Client {
             // Does it ever make sense for api_key and secret_key to be ""?
             api_key: api_key.unwrap_or_else(|| "".into()),
-            secret_key: secret_key.unwrap_or_else(|| "".into()),
-            inner: builder.build().unwrap(),
+            signer,
+            transport,
+            rate_limiter: config.rate_limiter.clone(),
+            retry_policy: config.retry_policy.clone(),
+            time_sync_interval: config.time_sync_interval,
+            time_sync: config.time_syncs.get(&host),
+            time_syncs: config.time_syncs.clone(),
             host,
+            time_host: None,
         }
     }
 
-    pub async fn get_signed<T: DeserializeOwned>(&self, endpoint: &str, request: &str) -> Result<T> {
-        let url = self.sign_request(endpoint, request);
-        let response = self.inner.get(&url).headers(self.build_headers(true)?).send().await?;
+    /// A client for another host sharing the connections, credentials and options of this one
+    pub(crate) fn with_host(&self, host: &str) -> Self {
+        Client {
+            host: host.to_string(),
+            time_sync: self.time_syncs.get(host),
+            time_host: None,
+            ..self.clone()
+        }
+    }
 
-        self.handler(response).await
+    /// Measure the server time with the spot API of `host`, for APIs without a time endpoint
+    #[cfg(feature = "portfolio_margin")]
+    pub(crate) fn with_time_host(mut self, host: &str) -> Self {
+        self.time_host = Some(host.to_string());
+        self.time_sync = self.time_syncs.get(host);
+        self
+    }
+
+    pub async fn get_signed<T: DeserializeOwned>(&self, endpoint: &str, request: &str) -> Result<T> {
+        self.send_signed(Method::GET, endpoint, request).await
     }
 
     pub async fn get_signed_d<T: de::DeserializeOwned>(&self, endpoint: &str, request: &str) -> Result<T> {
@@ -61,10 +136,7 @@ impl Client {
     }
 
     pub async fn post_signed<T: DeserializeOwned>(&self, endpoint: &str, request: &str) -> Result<T> {
-        let url = self.sign_request(endpoint, request);
-        let response = self.inner.post(&url).headers(self.build_headers(true)?).send().await?;
-
-        self.handler(response).await
+        self.send_signed(Method::POST, endpoint, request).await
     }
 
     pub async fn post_signed_d<T: de::DeserializeOwned>(&self, endpoint: &str, request: &str) -> Result<T> {
@@ -92,25 +164,26 @@ impl Client {
     }
 
     pub async fn delete_signed<T: DeserializeOwned>(&self, endpoint: &str, request: &str) -> Result<T> {
-        let url = self.sign_request(endpoint, request);
-        let response = self
-            .inner
-            .delete(&url)
-            .headers(self.build_headers(true)?)
-            .send()
-            .await?;
-
-        self.handler(response).await
+        self.send_signed(Method::DELETE, endpoint, request).await
     }
 
-    pub async fn get<T: DeserializeOwned>(&self, endpoint: &str, request: Option<&str>) -> Result<T> {
-        let url = request
-            .map(|r| format!("{}{}?{}", self.host, endpoint, r))
-            .unwrap_or_else(|| format!("{}{}", self.host, endpoint));
+    pub async fn put_signed<T: DeserializeOwned>(&self, endpoint: &str, request: &str) -> Result<T> {
+        self.send_signed(Method::PUT, endpoint, request).await
+    }
 
-        let response = self.inner.get(&url).send().await?;
+    pub async fn put_signed_p<T: de::DeserializeOwned, P: serde::Serialize>(
+        &self,
+        endpoint: &str,
+        payload: P,
+        recv_window: u64,
+    ) -> Result<T> {
+        let request = build_signed_request_p(payload, recv_window)?;
+        self.put_signed(endpoint, &request).await
+    }
 
-        self.handler(response).await
+    pub async fn get<T: DeserializeOwned>(&self, endpoint: &str, request: Option<&str>) -> Result<T> {
+        self.send(Method::GET, endpoint, request.unwrap_or_default(), false, None)
+            .await
     }
 
     pub async fn get_p<T: DeserializeOwned>(&self, endpoint: &str, request: Option<&str>) -> Result<T> {
@@ -131,13 +204,10 @@ impl Client {
     }
 
     pub async fn post<T: DeserializeOwned>(&self, endpoint: &str, symbol: Option<&str>) -> Result<T> {
-        let url = symbol
-            .map(|s| format!("{}{}?symbol={}", self.host, endpoint, s))
-            .unwrap_or_else(|| format!("{}{}", self.host, endpoint));
+        let query = symbol.map(|s| format!("symbol={s}")).unwrap_or_default();
 
-        let response = self.inner.post(url).headers(self.build_headers(false)?).send().await?;
-
-        self.handler(response).await
+        self.send(Method::POST, endpoint, &query, false, Some(self.build_headers(false)?))
+            .await
     }
 
     pub async fn put<T: DeserializeOwned>(&self, endpoint: &str, listen_key: &str, symbol: Option<&str>) -> Result<T> {
@@ -145,10 +215,7 @@ impl Client {
             .map(|s| format!("listenKey={listen_key}&symbol={s}"))
             .unwrap_or_else(|| format!("listenKey={listen_key}"));
         let headers = self.build_headers(false)?;
-        let url = format!("{}{}?{}", self.host, endpoint, data);
-        let response = self.inner.put(&url).headers(headers).send().await?;
-
-        self.handler(response).await
+        self.send(Method::PUT, endpoint, &data, false, Some(headers)).await
     }
 
     pub async fn delete<T: DeserializeOwned>(
@@ -160,24 +227,137 @@ impl Client {
         let data = symbol
             .map(|s| format!("listenKey={listen_key}&symbol={s}"))
             .unwrap_or_else(|| format!("listenKey={listen_key}"));
-        let url = format!("{}{}?{}", self.host, endpoint, data);
-        let response = self
-            .inner
-            .delete(url)
-            .headers(self.build_headers(false)?)
-            .send()
-            .await?;
+        self.send(Method::DELETE, endpoint, &data, false, Some(self.build_headers(false)?))
+            .await
+    }
 
-        self.handler(response).await
+    /// Send a request, accounting for its weight when a rate limiter is configured
+    /// and retrying idempotent requests according to the retry policy.
+    /// Signed requests are stamped and signed before each attempt, once the rate limiter let them through,
+    /// so that waiting does not push them outside of the receive window
+    async fn send<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        query: &str,
+        signed: bool,
+        headers: Option<HeaderMap>,
+    ) -> Result<T> {
+        let mut retry = 0;
+        loop {
+            if let Some(limiter) = &self.rate_limiter {
+                limiter
+                    .acquire(&self.host, request_cost(&method, endpoint, query))
+                    .await?;
+            }
+            let url = if signed {
+                self.sign_request(endpoint, &refresh_timestamp(query, self.time_sync.timestamp()))?
+            } else if query.is_empty() {
+                format!("{}{}", self.host, endpoint)
+            } else {
+                format!("{}{}?{}", self.host, endpoint, query)
+            };
+            let request = HttpRequest {
+                method: method.clone(),
+                url,
+                headers: headers.clone().unwrap_or_default(),
+            };
+            retry += 1;
+            let transport = self.transport.as_ref().map_err(|e| Error::InvalidConfig(e.clone()))?;
+            let response = match transport.send(request).await {
+                Ok(response) => response,
+                Err(Error::ReqError(e)) if e.is_timeout() => match self.retry_delay(&method, retry, None) {
+                    Some(delay) => {
+                        warn!("{method} {endpoint} timed out, retrying in {delay:?}");
+                        tokio::time::sleep(delay).await;
+                        continue;
+                    }
+                    None => return Err(e.into()),
+                },
+                Err(e) => return Err(e),
+            };
+            if let Some(limiter) = &self.rate_limiter {
+                limiter.update_from_headers(&self.host, &response.headers);
+            }
+            let status = response.status;
+            if status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS {
+                if let Some(delay) = self.retry_delay(&method, retry, retry_after(&response)) {
+                    warn!("{method} {endpoint} failed with {status}, retrying in {delay:?}");
+                    tokio::time::sleep(delay).await;
+                    continue;
+                }
+            }
+
+            return self.handler(response);
+        }
+    }
+
+    /// Send a signed request, resyncing with the server clock when the offset is stale
+    /// and retrying once when the timestamp was rejected with -1021
+    async fn send_signed<T: DeserializeOwned>(&self, method: Method, endpoint: &str, request: &str) -> Result<T> {
+        if let Some(interval) = self.time_sync_interval {
+            if self.time_sync.since_sync() > (interval * 1000) as i64 {
+                if let Some((client, time_endpoint)) = self.time_source(endpoint) {
+                    client.sync_time(time_endpoint).await?;
+                }
+            }
+        }
+        match self
+            .send(method.clone(), endpoint, request, true, Some(self.build_headers(true)?))
+            .await
+        {
+            Err(Error::BinanceError { response }) if response.code == -1021 => {
+                let Some((client, time_endpoint)) = self.time_source(endpoint) else {
+                    return Err(Error::BinanceError { response });
+                };
+                warn!("{method} {endpoint} rejected with \"{}\", resyncing server time", response.msg);
+                client.sync_time(time_endpoint).await?;
+                self.send(method, endpoint, request, true, Some(self.build_headers(true)?))
+                    .await
+            }
+            result => result,
+        }
+    }
+
+    /// Measure the offset between the server clock and the local clock with the `time_endpoint` of the API,
+    /// the offset is then applied to the timestamp of signed requests of the clients of this host built from the same
+    /// configuration
+    pub async fn sync_time(&self, time_endpoint: &str) -> Result<i64> {
+        let before = Utc::now().timestamp_millis();
+        let time: ServerTime = self.get(time_endpoint, None).await?;
+        let after = Utc::now().timestamp_millis();
+        // assume the server time was taken halfway through the round trip
+        let offset = time.server_time as i64 - (before + after) / 2;
+        self.time_sync.set_offset(offset);
+        Ok(offset)
+    }
+
+    /// Offset applied to the timestamp of signed requests, in milliseconds
+    pub fn server_time_offset(&self) -> i64 { self.time_sync.offset() }
+
+    /// The client and endpoint measuring the server time for `endpoint`, `None` if it cannot be measured
+    fn time_source(&self, endpoint: &str) -> Option<(Client, &'static str)> {
+        match &self.time_host {
+            Some(host) => Some((self.with_host(host), "/api/v3/time")),
+            None => time_endpoint(endpoint).map(|time_endpoint| (self.clone(), time_endpoint)),
+        }
+    }
+
+    /// Delay before retrying a failed request, `None` if it must not be retried.
+    /// Only GETs are retried since other methods may have taken effect, e.g. placed an order
+    fn retry_delay(&self, method: &Method, retry: u32, retry_after: Option<Duration>) -> Option<Duration> {
+        let policy = self.retry_policy.as_ref()?;
+        (*method == Method::GET && retry <= policy.max_retries).as_option()?;
+        Some(policy.delay(retry).max(retry_after.unwrap_or_default()))
     }
 
     // Request must be signed
-    fn sign_request(&self, endpoint: &str, request: &str) -> String {
-        let signed_key = hmac::Key::new(hmac::HMAC_SHA256, self.secret_key.as_bytes());
-        let signature = hex_encode(hmac::sign(&signed_key, request.as_bytes()).as_ref());
+    fn sign_request(&self, endpoint: &str, request: &str) -> Result<String> {
+        let signer = self.signer.as_ref().map_err(|e| Error::InvalidKey(e.clone()))?;
+        let signature = url_encoded_signature(signer.as_ref(), request)?;
         let url = format!("{}{}?{}&signature={}", self.host, endpoint, request, signature);
 
-        url
+        Ok(url)
     }
 
     fn build_headers(&self, content_type: bool) -> Result<HeaderMap> {
@@ -203,14 +383,23 @@ impl Client {
         Ok(header)
     }
 
-    async fn handler<T: de::DeserializeOwned>(&self, response: Response) -> Result<T> {
-        match response.status() {
-            StatusCode::OK => Ok(response.json().await?),
+    fn handler<T: de::DeserializeOwned>(&self, response: HttpResponse) -> Result<T> {
+        match response.status {
+            StatusCode::OK => Ok(serde_json::from_slice(&response.body)?),
             StatusCode::INTERNAL_SERVER_ERROR => Err(Error::InternalServerError),
             StatusCode::SERVICE_UNAVAILABLE => Err(Error::ServiceUnavailable),
             StatusCode::UNAUTHORIZED => Err(Error::Unauthorized),
+            StatusCode::TOO_MANY_REQUESTS => Err(Error::RateLimited {
+                retry_after: retry_after(&response),
+            }),
+            StatusCode::IM_A_TEAPOT => Err(Error::IpBanned {
+                until: retry_after(&response).and_then(|retry_after| {
+                    let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?;
+                    Some((now + retry_after).as_millis() as u64)
+                }),
+            }),
             StatusCode::BAD_REQUEST => {
-                let error: BinanceContentError = response.json().await?;
+                let error: BinanceContentError = serde_json::from_slice(&response.body)?;
                 Err(handle_content_error(error))
             }
             s => Err(Error::Msg(format!("Received response: {s:?}"))),
@@ -218,7 +407,69 @@ impl Client {
     }
 }
 
-fn handle_content_error(error: BinanceContentError) -> crate::errors::Error {
+/// Signer of the configuration, public endpoints only need a client without secret key
+fn client_signer(config: &Config, secret_key: Option<&str>) -> Result<Arc<dyn Signer>> {
+    Ok(config_signer(config, secret_key)?.unwrap_or_else(|| Arc::new(HmacSigner::new(""))))
+}
+
+fn config_transport(config: &Config) -> Result<Arc<dyn Transport>> {
+    match &config.transport {
+        Some(transport) => Ok(transport.0.clone()),
+        None => default_transport(config),
+    }
+}
+
+fn default_transport(config: &Config) -> Result<Arc<dyn Transport>> {
+    let mut builder = reqwest::ClientBuilder::new()
+        .local_address(config.local_address)
+        .tcp_nodelay(config.tcp_nodelay)
+        .tcp_keepalive(config.tcp_keepalive.map(Duration::from_secs));
+    if let Some(timeout_secs) = config.timeout {
+        builder = builder.timeout(Duration::from_secs(timeout_secs))
+    }
+    if let Some(timeout_ms) = config.connect_timeout_ms {
+        builder = builder.connect_timeout(Duration::from_millis(timeout_ms))
+    }
+    if let Some(proxy) = &config.proxy {
+        builder = builder.proxy(reqwest::Proxy::all(proxy)?)
+    }
+    #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+    for pem in &config.root_certificates {
+        for certificate in reqwest::Certificate::from_pem_bundle(pem)? {
+            builder = builder.add_root_certificate(certificate)
+        }
+    }
+    Ok(Arc::new(ReqwestTransport::new(builder.build()?)))
+}
+
+/// Server time endpoint of the API serving `endpoint`, the portfolio margin API has none
+fn time_endpoint(endpoint: &str) -> Option<&'static str> {
+    if endpoint.starts_with("/fapi") {
+        Some("/fapi/v1/time")
+    } else if endpoint.starts_with("/dapi") {
+        Some("/dapi/v1/time")
+    } else if endpoint.starts_with("/eapi") {
+        Some("/eapi/v1/time")
+    } else if endpoint.starts_with("/papi") {
+        None
+    } else {
+        Some("/api/v3/time")
+    }
+}
+
+/// Seconds to wait from the `Retry-After` header
+fn retry_after(response: &HttpResponse) -> Option<Duration> {
+    response
+        .headers
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+pub(crate) fn handle_content_error(error: BinanceContentError) -> crate::errors::Error {
     match (error.code, error.msg.as_ref()) {
         (-1013, error_messages::INVALID_PRICE) => Error::InvalidPrice,
         (-1125, msg) => Error::InvalidListenKey(msg.to_string()),
@@ -0,0 +1,136 @@
+use super::rest_model::{
+    AccountBalance, AccountInformation, ChangeLeverageResponse, Income, MarginType, Order, Position, Success,
+    UserTrade,
+};
+use crate::account::{OrderCancellation, OrderStatusRequest, OrdersQuery};
+use crate::client::Client;
+use crate::errors::*;
+use crate::futures::account::{IncomeQuery, OrderRequest, UserTradesQuery};
+use crate::rest_model::PairQuery;
+use crate::util::*;
+
+/// Account of COIN-M futures, orders and positions are sized in contracts
+#[derive(Clone)]
+pub struct CoinFuturesAccount {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PositionQuery {
+    pair: Option<String>,
+}
+
+impl CoinFuturesAccount {
+    pub async fn place_order(&self, order: OrderRequest) -> Result<Order> {
+        self.client
+            .post_signed_p("/dapi/v1/order", order, self.recv_window)
+            .await
+    }
+
+    pub async fn order_status(&self, osr: OrderStatusRequest) -> Result<Order> {
+        let recv_window = osr.recv_window.unwrap_or(self.recv_window);
+        let request = build_signed_request_p(osr, recv_window)?;
+        self.client.get_signed("/dapi/v1/order", &request).await
+    }
+
+    pub async fn get_open_orders(&self, symbol: impl Into<String>) -> Result<Vec<Order>> {
+        let payload = build_signed_request([("symbol", symbol.into())], self.recv_window)?;
+        self.client.get_signed("/dapi/v1/openOrders", &payload).await
+    }
+
+    pub async fn get_all_orders(&self, query: OrdersQuery) -> Result<Vec<Order>> {
+        let recv_window = query.recv_window.unwrap_or(self.recv_window);
+        let request = build_signed_request_p(query, recv_window)?;
+        self.client.get_signed("/dapi/v1/allOrders", &request).await
+    }
+
+    /// Place a cancellation order
+    pub async fn cancel_order(&self, o: OrderCancellation) -> Result<Order> {
+        let recv_window = o.recv_window.unwrap_or(self.recv_window);
+        self.client.delete_signed_p("/dapi/v1/order", &o, recv_window).await
+    }
+
+    pub async fn cancel_all_open_orders<S>(&self, symbol: S) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let _: Success = self
+            .client
+            .delete_signed_p(
+                "/dapi/v1/allOpenOrders",
+                PairQuery { symbol: symbol.into() },
+                self.recv_window,
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Positions of every contract of `pair` (ex: BTCUSD), or of all pairs
+    pub async fn position_information(&self, pair: Option<String>) -> Result<Vec<Position>> {
+        self.client
+            .get_signed_p("/dapi/v1/positionRisk", Some(PositionQuery { pair }), self.recv_window)
+            .await
+    }
+
+    pub async fn account_information(&self) -> Result<AccountInformation> {
+        self.client
+            .get_signed_p("/dapi/v1/account", None::<()>, self.recv_window)
+            .await
+    }
+
+    pub async fn account_balance(&self) -> Result<Vec<AccountBalance>> {
+        self.client
+            .get_signed_p("/dapi/v1/balance", None::<()>, self.recv_window)
+            .await
+    }
+
+    pub async fn change_initial_leverage<S>(&self, symbol: S, leverage: u8) -> Result<ChangeLeverageResponse>
+    where
+        S: Into<String>,
+    {
+        let request = build_signed_request(
+            [("symbol", symbol.into()), ("leverage", leverage.to_string())],
+            self.recv_window,
+        )?;
+        self.client.post_signed("/dapi/v1/leverage", &request).await
+    }
+
+    pub async fn change_position_mode(&self, dual_side_position: bool) -> Result<()> {
+        let request = build_signed_request([("dualSidePosition", dual_side_position.to_string())], self.recv_window)?;
+        let _: Success = self.client.post_signed("/dapi/v1/positionSide/dual", &request).await?;
+        Ok(())
+    }
+
+    /// Switch a symbol between isolated and cross margin, fails with open orders or positions
+    pub async fn change_margin_type<S>(&self, symbol: S, margin_type: MarginType) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let margin_type = match margin_type {
+            MarginType::Isolated => "ISOLATED",
+            MarginType::Cross => "CROSSED",
+        };
+        let request = build_signed_request(
+            [("symbol", symbol.into()), ("marginType", margin_type.to_string())],
+            self.recv_window,
+        )?;
+        let _: Success = self.client.post_signed("/dapi/v1/marginType", &request).await?;
+        Ok(())
+    }
+
+    /// Trades of the account for a symbol
+    pub async fn trade_history(&self, query: UserTradesQuery) -> Result<Vec<UserTrade>> {
+        self.client
+            .get_signed_p("/dapi/v1/userTrades", Some(query), self.recv_window)
+            .await
+    }
+
+    /// Realized pnl, funding fees, commissions, transfers and other incomes of the account
+    pub async fn income_history(&self, query: IncomeQuery) -> Result<Vec<Income>> {
+        self.client
+            .get_signed_p("/dapi/v1/income", Some(query), self.recv_window)
+            .await
+    }
+}
@@ -0,0 +1,214 @@
+use crate::client::*;
+use crate::coin_futures::rest_model::*;
+use crate::errors::*;
+use crate::futures::rest_model::HistoryQuery;
+use crate::rest_model::PairQuery;
+use crate::util::*;
+use serde_json::Value;
+
+/// Market data of COIN-M futures, quantities are in contracts unless stated otherwise
+#[derive(Clone)]
+pub struct CoinFuturesMarket {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl CoinFuturesMarket {
+    // Order book (Default 500; max 1000)
+    pub async fn get_depth<S>(&self, symbol: S) -> Result<OrderBook>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_d("/dapi/v1/depth", Some(PairQuery { symbol: symbol.into() }))
+            .await
+    }
+
+    // Order book with a custom depth limit
+    // Supported limits are: 5, 10, 20, 50, 100, 500, 1000
+    pub async fn get_custom_depth<S>(&self, symbol: S, limit: u16) -> Result<OrderBook>
+    where
+        S: Into<String>,
+    {
+        let request = build_request([("symbol", symbol.into()), ("limit", limit.to_string())]);
+        self.client.get("/dapi/v1/depth", Some(&request)).await
+    }
+
+    /// Get recent trades
+    pub async fn get_trades<S>(&self, symbol: S) -> Result<Vec<Trade>>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_d("/dapi/v1/trades", Some(PairQuery { symbol: symbol.into() }))
+            .await
+    }
+
+    /// Get aggregated trades
+    pub async fn get_agg_trades<S1, S2, S3, S4, S5>(
+        &self,
+        symbol: S1,
+        from_id: S2,
+        start_time: S3,
+        end_time: S4,
+        limit: S5,
+    ) -> Result<AggTrades>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u64>>,
+        S5: Into<u16>,
+    {
+        self.client
+            .get_d(
+                "/dapi/v1/aggTrades",
+                Some(HistoryQuery {
+                    start_time: start_time.into(),
+                    end_time: end_time.into(),
+                    from_id: from_id.into(),
+                    limit: limit.into(),
+                    symbol: symbol.into(),
+                    interval: None,
+                    period: None,
+                }),
+            )
+            .await
+    }
+
+    /// Get funding rate history of a perpetual contract
+    pub async fn get_funding_rate<S1, S3, S4, S5>(
+        &self,
+        symbol: S1,
+        start_time: S3,
+        end_time: S4,
+        limit: S5,
+    ) -> Result<Vec<FundingRate>>
+    where
+        S1: Into<String>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u64>>,
+        S5: Into<u16>,
+    {
+        self.client
+            .get_d(
+                "/dapi/v1/fundingRate",
+                Some(HistoryQuery {
+                    start_time: start_time.into(),
+                    end_time: end_time.into(),
+                    limit: limit.into(),
+                    symbol: symbol.into(),
+                    from_id: None,
+                    interval: None,
+                    period: None,
+                }),
+            )
+            .await
+    }
+
+    /// Returns up to 'limit' klines for given symbol and interval ("1m", "5m", ...)
+    /// The volume is in contracts and `quote_asset_volume` holds the volume in the base asset
+    pub async fn get_klines<S1, S2, S3, S4, S5>(
+        &self,
+        symbol: S1,
+        interval: S2,
+        limit: S3,
+        start_time: S4,
+        end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<u16>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let query = HistoryQuery {
+            start_time: start_time.into(),
+            end_time: end_time.into(),
+            limit: limit.into(),
+            symbol: symbol.into(),
+            interval: Some(interval.into()),
+            from_id: None,
+            period: None,
+        };
+        let data: Vec<Vec<Value>> = self.client.get_d("/dapi/v1/klines", Some(query)).await?;
+
+        let klines = KlineSummaries::AllKlineSummaries(
+            data.iter()
+                .map(|row| {
+                    Ok(KlineSummary {
+                        open_time: to_i64(&row[0]),
+                        open: to_number(&row[1])?,
+                        high: to_number(&row[2])?,
+                        low: to_number(&row[3])?,
+                        close: to_number(&row[4])?,
+                        volume: to_number(&row[5])?,
+                        close_time: to_i64(&row[6]),
+                        quote_asset_volume: to_number(&row[7])?,
+                        number_of_trades: to_i64(&row[8]),
+                        taker_buy_base_asset_volume: to_number(&row[9])?,
+                        taker_buy_quote_asset_volume: to_number(&row[10])?,
+                    })
+                })
+                .collect::<Result<_>>()?,
+        );
+        Ok(klines)
+    }
+
+    /// 24hr ticker price change statistics, one per contract of the symbol
+    pub async fn get_24h_price_stats<S>(&self, symbol: S) -> Result<Vec<PriceStats>>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_d("/dapi/v1/ticker/24hr", Some(PairQuery { symbol: symbol.into() }))
+            .await
+    }
+
+    /// 24hr ticker price change statistics for all symbols
+    pub async fn get_all_24h_price_stats(&self) -> Result<Vec<PriceStats>> {
+        self.client.get_p("/dapi/v1/ticker/24hr", None).await
+    }
+
+    /// Latest price of a symbol
+    pub async fn get_price<S>(&self, symbol: S) -> Result<Vec<SymbolPrice>>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_d("/dapi/v1/ticker/price", Some(PairQuery { symbol: symbol.into() }))
+            .await
+    }
+
+    // -> Best price/qty on the order book for a symbol
+    pub async fn get_book_ticker<S>(&self, symbol: S) -> Result<Vec<Tickers>>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_d("/dapi/v1/ticker/bookTicker", Some(PairQuery { symbol: symbol.into() }))
+            .await
+    }
+
+    pub async fn get_mark_prices(&self, symbol: Option<String>) -> Result<Vec<MarkPrice>> {
+        match symbol {
+            Some(symbol) => {
+                self.client
+                    .get_d("/dapi/v1/premiumIndex", Some(PairQuery { symbol }))
+                    .await
+            }
+            None => self.client.get_p("/dapi/v1/premiumIndex", None).await,
+        }
+    }
+
+    /// Open interest of a symbol, in contracts
+    pub async fn open_interest<S>(&self, symbol: S) -> Result<OpenInterest>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_d("/dapi/v1/openInterest", Some(PairQuery { symbol: symbol.into() }))
+            .await
+    }
+}
@@ -0,0 +1,8 @@
+//! COIN-M futures, inverse contracts margined and settled in their base asset
+
+pub mod account;
+pub mod general;
+pub mod market;
+pub mod rest_model;
+pub mod userstream;
+pub mod websockets;
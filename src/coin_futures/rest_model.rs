@@ -0,0 +1,426 @@
+use serde::{Deserialize, Serialize};
+
+pub use crate::futures::rest_model::{
+    AggTrade, AggTrades, ContractType, Filters, FundingRate, FuturesUserDataStream, Income, IncomeType, MarginType,
+    OrderBook, OrderType, PositionSide, WorkingType,
+};
+use crate::rest_model::{string_or_bool, string_or_float, string_or_float_opt, string_or_u64};
+pub use crate::rest_model::{
+    KlineSummaries, KlineSummary, Number, OrderSide, OrderStatus, RateLimit, ServerTime, Success, SymbolPrice, Tickers,
+    TimeInForce,
+};
+
+/// Value of `contracts` contracts of `contract_size` quote units, in the base asset
+fn base_value(contracts: Number, contract_size: Number, price: Number) -> Number {
+    if price == Number::default() {
+        return Number::default();
+    }
+    contracts * contract_size / price
+}
+
+/// Profit in the base asset of an inverse contract position opened at `entry_price` and closed at `exit_price`,
+/// `contracts` is negative for short positions
+pub fn inverse_pnl(contracts: Number, contract_size: Number, entry_price: Number, exit_price: Number) -> Number {
+    base_value(contracts, contract_size, entry_price) - base_value(contracts, contract_size, exit_price)
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeInformation {
+    pub timezone: String,
+    pub server_time: u64,
+    pub rate_limits: Vec<RateLimit>,
+    pub exchange_filters: Vec<Filters>,
+    pub symbols: Vec<Symbol>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ContractStatus {
+    PendingTrading,
+    Trading,
+    PreDelivering,
+    Delivering,
+    Delivered,
+    PreSettle,
+    Settling,
+    Close,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Symbol {
+    pub symbol: String,
+    pub pair: String,
+    pub contract_type: ContractType,
+    pub delivery_date: u64,
+    pub onboard_date: u64,
+    pub contract_status: ContractStatus,
+    /// Value of one contract in the quote asset
+    #[serde(with = "string_or_float")]
+    pub contract_size: Number,
+    pub base_asset: String,
+    pub quote_asset: String,
+    pub margin_asset: String,
+    pub price_precision: u16,
+    pub quantity_precision: u16,
+    pub base_asset_precision: u64,
+    pub quote_precision: u64,
+    pub equal_qty_precision: u64,
+    #[serde(with = "string_or_float")]
+    pub maint_margin_percent: Number,
+    #[serde(with = "string_or_float")]
+    pub required_margin_percent: Number,
+    #[serde(with = "string_or_float")]
+    pub trigger_protect: Number,
+    #[serde(default, with = "string_or_float_opt")]
+    pub liquidation_fee: Option<Number>,
+    /// Max price difference rate of market orders from the mark price
+    #[serde(default, with = "string_or_float_opt")]
+    pub market_take_bound: Option<Number>,
+    pub underlying_type: String,
+    pub underlying_sub_type: Vec<String>,
+    pub filters: Vec<Filters>,
+    pub order_types: Vec<OrderType>,
+    pub time_in_force: Vec<TimeInForce>,
+}
+
+impl Symbol {
+    /// Value of `contracts` contracts at `price`, in the base asset
+    pub fn base_value(&self, contracts: Number, price: Number) -> Number {
+        base_value(contracts, self.contract_size, price)
+    }
+
+    /// Profit in the base asset of `contracts` contracts opened at `entry_price` and closed at `exit_price`
+    pub fn pnl(&self, contracts: Number, entry_price: Number, exit_price: Number) -> Number {
+        inverse_pnl(contracts, self.contract_size, entry_price, exit_price)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceStats {
+    pub symbol: String,
+    pub pair: String,
+    pub price_change: String,
+    pub price_change_percent: String,
+    pub weighted_avg_price: String,
+    #[serde(with = "string_or_float")]
+    pub last_price: Number,
+    #[serde(with = "string_or_float")]
+    pub last_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub open_price: Number,
+    #[serde(with = "string_or_float")]
+    pub high_price: Number,
+    #[serde(with = "string_or_float")]
+    pub low_price: Number,
+    /// Volume in contracts
+    #[serde(with = "string_or_float")]
+    pub volume: Number,
+    #[serde(with = "string_or_float")]
+    pub base_volume: Number,
+    pub open_time: u64,
+    pub close_time: u64,
+    pub first_id: u64,
+    pub last_id: u64,
+    pub count: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    pub id: u64,
+    pub is_buyer_maker: bool,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    /// Quantity in contracts
+    #[serde(with = "string_or_float")]
+    pub qty: Number,
+    #[serde(with = "string_or_float")]
+    pub base_qty: Number,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkPrice {
+    pub symbol: String,
+    pub pair: String,
+    #[serde(with = "string_or_float")]
+    pub mark_price: Number,
+    #[serde(with = "string_or_float")]
+    pub index_price: Number,
+    #[serde(with = "string_or_float")]
+    pub estimated_settle_price: Number,
+    /// Empty for delivery contracts
+    #[serde(with = "string_or_float_opt")]
+    pub last_funding_rate: Option<Number>,
+    #[serde(with = "string_or_float_opt")]
+    pub interest_rate: Option<Number>,
+    /// 0 for delivery contracts
+    pub next_funding_time: u64,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterest {
+    pub symbol: String,
+    pub pair: String,
+    /// Open interest in contracts
+    #[serde(with = "string_or_float")]
+    pub open_interest: Number,
+    pub contract_type: ContractType,
+    pub time: u64,
+}
+
+/// An order as returned when placing, querying or canceling it
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
+    pub symbol: String,
+    pub pair: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+    /// Only returned when placing or canceling an order
+    #[serde(default, with = "string_or_float_opt")]
+    pub cum_qty: Option<Number>,
+    /// Filled value in the base asset
+    #[serde(with = "string_or_float")]
+    pub cum_base: Number,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub avg_price: Number,
+    /// Quantity in contracts
+    #[serde(with = "string_or_float")]
+    pub orig_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    pub reduce_only: bool,
+    pub side: OrderSide,
+    pub position_side: PositionSide,
+    pub status: OrderStatus,
+    #[serde(default, with = "string_or_float_opt")]
+    pub stop_price: Option<Number>,
+    pub close_position: bool,
+    pub time_in_force: TimeInForce,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    pub orig_type: OrderType,
+    #[serde(default, with = "string_or_float_opt")]
+    pub activate_price: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub price_rate: Option<Number>,
+    /// Not returned when placing or canceling an order
+    #[serde(default)]
+    pub time: u64,
+    pub update_time: u64,
+    pub working_type: WorkingType,
+    pub price_protect: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserTrade {
+    pub id: u64,
+    pub order_id: u64,
+    pub symbol: String,
+    pub pair: String,
+    pub side: OrderSide,
+    pub position_side: PositionSide,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    /// Quantity in contracts
+    #[serde(with = "string_or_float")]
+    pub qty: Number,
+    #[serde(with = "string_or_float")]
+    pub base_qty: Number,
+    /// Realized profit in the margin asset
+    #[serde(with = "string_or_float")]
+    pub realized_pnl: Number,
+    pub margin_asset: String,
+    #[serde(with = "string_or_float")]
+    pub commission: Number,
+    pub commission_asset: String,
+    pub buyer: bool,
+    pub maker: bool,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Position {
+    pub symbol: String,
+    /// Position size in contracts, negative for short positions
+    #[serde(with = "string_or_float", rename = "positionAmt")]
+    pub position_amount: Number,
+    #[serde(with = "string_or_float")]
+    pub entry_price: Number,
+    #[serde(with = "string_or_float")]
+    pub mark_price: Number,
+    /// Unrealized profit in the margin asset
+    #[serde(with = "string_or_float", rename = "unRealizedProfit")]
+    pub unrealized_profit: Number,
+    #[serde(with = "string_or_float")]
+    pub liquidation_price: Number,
+    #[serde(with = "string_or_u64")]
+    pub leverage: u64,
+    /// Maximum position size in contracts at the current leverage
+    #[serde(with = "string_or_float")]
+    pub max_qty: Number,
+    pub margin_type: MarginType,
+    #[serde(with = "string_or_float")]
+    pub isolated_margin: Number,
+    #[serde(with = "string_or_bool")]
+    pub is_auto_add_margin: bool,
+    pub position_side: PositionSide,
+    /// Position value in the base asset
+    #[serde(with = "string_or_float")]
+    pub notional_value: Number,
+    #[serde(with = "string_or_float")]
+    pub isolated_wallet: Number,
+    pub update_time: u64,
+}
+
+impl Position {
+    /// Unrealized profit in the base asset if the position were closed at `price`
+    pub fn pnl_at(&self, contract_size: Number, price: Number) -> Number {
+        inverse_pnl(self.position_amount, contract_size, self.entry_price, price)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountAsset {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub wallet_balance: Number,
+    #[serde(with = "string_or_float")]
+    pub unrealized_profit: Number,
+    #[serde(with = "string_or_float")]
+    pub margin_balance: Number,
+    #[serde(with = "string_or_float")]
+    pub maint_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub initial_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub position_initial_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub open_order_initial_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub max_withdraw_amount: Number,
+    #[serde(with = "string_or_float")]
+    pub cross_wallet_balance: Number,
+    #[serde(with = "string_or_float")]
+    pub cross_un_pnl: Number,
+    #[serde(with = "string_or_float")]
+    pub available_balance: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountPosition {
+    pub symbol: String,
+    #[serde(with = "string_or_float", rename = "positionAmt")]
+    pub position_amount: Number,
+    #[serde(with = "string_or_float")]
+    pub initial_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub maint_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub unrealized_profit: Number,
+    #[serde(with = "string_or_float")]
+    pub position_initial_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub open_order_initial_margin: Number,
+    #[serde(with = "string_or_u64")]
+    pub leverage: u64,
+    pub isolated: bool,
+    pub position_side: PositionSide,
+    #[serde(with = "string_or_float")]
+    pub entry_price: Number,
+    #[serde(with = "string_or_float")]
+    pub max_qty: Number,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInformation {
+    pub fee_tier: u64,
+    pub can_trade: bool,
+    pub can_deposit: bool,
+    pub can_withdraw: bool,
+    pub update_time: u64,
+    pub assets: Vec<AccountAsset>,
+    pub positions: Vec<AccountPosition>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountBalance {
+    pub account_alias: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub balance: Number,
+    #[serde(with = "string_or_float")]
+    pub withdraw_available: Number,
+    #[serde(with = "string_or_float")]
+    pub cross_wallet_balance: Number,
+    #[serde(with = "string_or_float")]
+    pub cross_un_pnl: Number,
+    #[serde(with = "string_or_float")]
+    pub available_balance: Number,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeLeverageResponse {
+    pub symbol: String,
+    pub leverage: u8,
+    /// Maximum position size in contracts at this leverage
+    #[serde(with = "string_or_float")]
+    pub max_qty: Number,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::num;
+
+    fn assert_close(value: Number, expected: Number) {
+        assert!((value - expected).abs() < num(1e-12), "{value} != {expected}");
+    }
+
+    fn position(contracts: f64, entry_price: f64) -> Position {
+        let recorded = include_str!("../../test_data/fixtures/coin_futures/rest/position_risk.json");
+        let mut position: Position = serde_json::from_str(recorded).unwrap();
+        position.position_amount = num(contracts);
+        position.entry_price = num(entry_price);
+        position
+    }
+
+    #[test]
+    fn inverse_profit() {
+        // 10 contracts of 100 USD are worth 0.05 BTC at 20000 and 0.04 BTC at 25000
+        let size = num(100.0);
+        assert_close(inverse_pnl(num(10.0), size, num(20000.0), num(25000.0)), num(0.01));
+        assert_close(inverse_pnl(num(10.0), size, num(25000.0), num(20000.0)), num(-0.01));
+        assert_close(inverse_pnl(num(-10.0), size, num(25000.0), num(20000.0)), num(0.01));
+        assert_close(inverse_pnl(num(-10.0), size, num(20000.0), num(25000.0)), num(-0.01));
+        assert_close(inverse_pnl(num(10.0), size, num(20000.0), num(20000.0)), Number::default());
+
+        let long = position(10.0, 20000.0);
+        assert_close(long.pnl_at(size, num(25000.0)), num(0.01));
+        assert_close(long.pnl_at(size, num(16000.0)), num(-0.0125));
+        let short = position(-10.0, 20000.0);
+        assert_close(short.pnl_at(size, num(25000.0)), num(-0.01));
+        assert_close(short.pnl_at(size, num(16000.0)), num(0.0125));
+        assert_close(position(0.0, 20000.0).pnl_at(size, num(25000.0)), Number::default());
+    }
+}
@@ -0,0 +1,25 @@
+use crate::client::*;
+use crate::coin_futures::rest_model::*;
+use crate::errors::*;
+
+static COIN_FUTURES_USER_DATA_STREAM: &str = "/dapi/v1/listenKey";
+
+/// User data streams emit the same events as USD-M futures,
+/// see [`crate::futures::ws_model::FuturesWebsocketEvent`]
+#[derive(Clone)]
+pub struct CoinFuturesUserStream {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl CoinFuturesUserStream {
+    pub async fn start(&self) -> Result<FuturesUserDataStream> { self.client.post(COIN_FUTURES_USER_DATA_STREAM, None).await }
+
+    pub async fn keep_alive(&self, listen_key: &str) -> Result<Success> {
+        self.client.put(COIN_FUTURES_USER_DATA_STREAM, listen_key, None).await
+    }
+
+    pub async fn close(&self, listen_key: &str) -> Result<Success> {
+        self.client.delete(COIN_FUTURES_USER_DATA_STREAM, listen_key, None).await
+    }
+}
@@ -0,0 +1,95 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::config::Config;
+use crate::errors::*;
+use crate::futures::websockets::FuturesWebSockets;
+pub use crate::futures::websockets::{
+    agg_trade_stream, all_book_ticker_stream, all_mini_ticker_stream, all_ticker_stream, book_ticker_stream,
+    diff_book_depth_stream, kline_stream, mini_ticker_stream, partial_book_depth_stream, ticker_stream,
+};
+use crate::websockets::{combined_url, connect_split, endpoint_url, ControlSink, EventStream};
+
+/// # Arguments
+///
+/// * `pair`: the underlying pair, ex: btcusd
+/// * `update_speed`: 1000 or 3000
+pub fn index_price_stream(pair: &str, update_speed: u16) -> String {
+    format!("{pair}@indexPrice@{update_speed}ms")
+}
+
+/// Mark price of a single contract, updated every 3 seconds or every second with `fast`
+pub fn mark_price_stream(symbol: &str, fast: bool) -> String {
+    if fast {
+        format!("{symbol}@markPrice@1s")
+    } else {
+        format!("{symbol}@markPrice")
+    }
+}
+
+/// Mark prices of every contract of a pair, updated every 3 seconds or every second with `fast`
+pub fn pair_mark_price_stream(pair: &str, fast: bool) -> String { mark_price_stream(pair, fast) }
+
+/// # Arguments
+///
+/// * `pair`: the underlying pair, ex: btcusd
+/// * `contract_type`: perpetual, current_quarter or next_quarter
+/// * `interval`: the kline interval, ex: 1m
+pub fn continuous_kline_stream(pair: &str, contract_type: &str, interval: &str) -> String {
+    format!("{pair}_{contract_type}@continuousKline_{interval}")
+}
+
+pub fn index_price_kline_stream(pair: &str, interval: &str) -> String { format!("{pair}@indexPriceKline_{interval}") }
+
+pub fn mark_price_kline_stream(symbol: &str, interval: &str) -> String {
+    format!("{symbol}@markPriceKline_{interval}")
+}
+
+/// Connect to a COIN-M futures websocket endpoint and return its events as a [`futures::Stream`]
+pub async fn connect_stream<WE: serde::de::DeserializeOwned>(
+    conf: &Config,
+    endpoint: &str,
+) -> Result<(ControlSink, EventStream<WE>)> {
+    connect_split(endpoint_url(&conf.coin_futures_ws_endpoint, endpoint)?, conf).await
+}
+
+/// Connect to multiple COIN-M futures websocket endpoints and return their events as a [`futures::Stream`]
+/// N.B: WE has to be CombinedStreamEvent
+pub async fn connect_multiple_streams<WE: serde::de::DeserializeOwned>(
+    conf: &Config,
+    endpoints: Vec<String>,
+) -> Result<(ControlSink, EventStream<WE>)> {
+    connect_split(combined_url(&conf.coin_futures_ws_endpoint, endpoints)?, conf).await
+}
+
+/// [`FuturesWebSockets`] connected to the COIN-M streams, market and user data events
+/// deserialize into the USD-M [`crate::futures::ws_model`] events
+pub struct CoinFuturesWebSockets<'a, WE>(FuturesWebSockets<'a, WE>);
+
+impl<'a, WE: serde::de::DeserializeOwned> CoinFuturesWebSockets<'a, WE> {
+    /// New websocket holder with default configuration
+    pub fn new<Callback>(handler: Callback) -> CoinFuturesWebSockets<'a, WE>
+    where
+        Callback: FnMut(WE) -> Result<()> + 'a + Send,
+    {
+        Self::new_with_options(handler, Config::default())
+    }
+
+    /// New websocket holder with provided configuration
+    pub fn new_with_options<Callback>(handler: Callback, conf: Config) -> CoinFuturesWebSockets<'a, WE>
+    where
+        Callback: FnMut(WE) -> Result<()> + 'a + Send,
+    {
+        let ws_endpoint = conf.coin_futures_ws_endpoint.clone();
+        CoinFuturesWebSockets(FuturesWebSockets::new_with_options(handler, conf).with_ws_endpoint(ws_endpoint))
+    }
+}
+
+impl<'a, WE> Deref for CoinFuturesWebSockets<'a, WE> {
+    type Target = FuturesWebSockets<'a, WE>;
+
+    fn deref(&self) -> &Self::Target { &self.0 }
+}
+
+impl<WE> DerefMut for CoinFuturesWebSockets<'_, WE> {
+    fn deref_mut(&mut self) -> &mut Self::Target { &mut self.0 }
+}
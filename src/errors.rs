@@ -2,6 +2,8 @@ use serde_json::Value;
 use std::collections::HashMap;
 use thiserror::Error;
 
+use crate::rest_model::Number;
+
 #[derive(Debug, Deserialize, Error)]
 #[error("code: {code}, msg: {msg}")]
 pub struct BinanceContentError {
@@ -38,11 +40,18 @@ pub enum Error {
     TimestampError(#[from] std::time::SystemTimeError),
     #[error(transparent)]
     UTF8Err(#[from] std::str::Utf8Error),
+    #[error(transparent)]
+    OrderFilter(#[from] FilterError),
     #[error("{response}")]
     BinanceError {
         #[from]
         response: BinanceContentError,
     },
+    #[error("invalid key: {0}")]
+    InvalidKey(String),
+    /// The HTTP client could not be built from the configuration, e.g. with a malformed proxy url
+    #[error("invalid configuration: {0}")]
+    InvalidConfig(String),
     #[error("invalid listen key : {0}")]
     InvalidListenKey(String),
     #[error("unknown symbol {0}")]
@@ -59,10 +68,33 @@ pub enum Error {
     ServiceUnavailable,
     #[error("Unauthorized")]
     Unauthorized,
+    /// Request limits reached, either by the local rate limiter or reported with a 429
+    #[error("rate limit reached, retry after {retry_after:?}")]
+    RateLimited { retry_after: Option<std::time::Duration> },
+    /// The IP was banned with a 418 for repeatedly ignoring 429s, `until` is a timestamp in milliseconds
+    #[error("ip banned until {until:?}")]
+    IpBanned { until: Option<u64> },
     #[error("{0}")]
     Msg(String),
 }
 
+/// An order field that does not pass a symbol filter
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum FilterError {
+    #[error("price {price} is outside of [{min}, {max}]")]
+    Price { price: Number, min: Number, max: Number },
+    #[error("price {price} is outside of [{min}, {max}] around the average price")]
+    PercentPrice { price: Number, min: Number, max: Number },
+    #[error("quantity {qty} is outside of [{min}, {max}]")]
+    Quantity { qty: Number, min: Number, max: Number },
+    #[error("notional {notional} is below {min}")]
+    MinNotional { notional: Number, min: Number },
+    #[error("notional {notional} is above {max}")]
+    MaxNotional { notional: Number, max: Number },
+    #[error("iceberg order needs {parts} parts, at most {limit} are allowed")]
+    IcebergParts { parts: Number, limit: u16 },
+}
+
 /// Custom error messages
 pub mod error_messages {
     pub const INVALID_PRICE: &str = "Invalid price.";
@@ -0,0 +1,539 @@
+//! A common trading interface over the spot, margin and futures accounts
+//!
+//! Code written against [`Exchange`] runs on [`Account`], [`crate::margin::Margin`] and
+//! [`crate::futures::account::FuturesAccount`] alike, orders, balances and positions are converted to the types of this
+//! module. Use the account handles directly for what is specific to one market.
+//! # Examples
+//! ```rust,no_run
+//! use binance::{account::*, api::*, config::*, exchange::*, rest_model::{Number, OrderSide}};
+//! async fn buy_dip(exchange: &impl Exchange, price: Number) -> binance::errors::Result<ExchangeOrder> {
+//!     let quantity: Number = "0.001".parse().unwrap();
+//!     exchange.place_order(NewOrder::limit("BTCUSDT", OrderSide::Buy, quantity, price)).await
+//! }
+//! let account: Account = Binance::new_with_env(&Config::testnet());
+//! let order = tokio_test::block_on(buy_dip(&account, "20000".parse().unwrap()));
+//! ```
+
+use std::future::Future;
+
+use crate::account::{Account, OrderCancellation, OrderRequest, OrderStatusRequest};
+use crate::errors::*;
+pub use crate::rest_model::Fill;
+use crate::rest_model::{Number, OrderResponse, OrderSide, OrderStatus, OrderType, TimeInForce};
+
+/// Order types available on every market
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderKind {
+    Market,
+    Limit,
+    /// Market order triggered when the stop price is reached
+    StopMarket,
+    /// Limit order triggered when the stop price is reached
+    StopLimit,
+    /// Market order triggered when the stop price is reached, on the other side of the market than stops
+    TakeProfitMarket,
+    /// Limit order triggered when the stop price is reached, on the other side of the market than stops
+    TakeProfitLimit,
+}
+
+impl OrderKind {
+    fn is_limit(self) -> bool { matches!(self, Self::Limit | Self::StopLimit | Self::TakeProfitLimit) }
+}
+
+impl From<OrderKind> for OrderType {
+    fn from(kind: OrderKind) -> Self {
+        match kind {
+            OrderKind::Market => Self::Market,
+            OrderKind::Limit => Self::Limit,
+            OrderKind::StopMarket => Self::StopLoss,
+            OrderKind::StopLimit => Self::StopLossLimit,
+            OrderKind::TakeProfitMarket => Self::TakeProfit,
+            OrderKind::TakeProfitLimit => Self::TakeProfitLimit,
+        }
+    }
+}
+
+fn spot_kind(order_type: &OrderType) -> Option<OrderKind> {
+    match order_type {
+        OrderType::Market => Some(OrderKind::Market),
+        OrderType::Limit | OrderType::LimitMaker => Some(OrderKind::Limit),
+        OrderType::StopLoss => Some(OrderKind::StopMarket),
+        OrderType::StopLossLimit => Some(OrderKind::StopLimit),
+        OrderType::TakeProfit => Some(OrderKind::TakeProfitMarket),
+        OrderType::TakeProfitLimit => Some(OrderKind::TakeProfitLimit),
+        OrderType::Other => None,
+    }
+}
+
+/// An order to place with [`Exchange::place_order`]
+#[derive(Debug, Clone)]
+pub struct NewOrder {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub kind: OrderKind,
+    pub quantity: Number,
+    /// Required by limit kinds
+    pub price: Option<Number>,
+    /// Required by stop and take profit kinds
+    pub stop_price: Option<Number>,
+    /// Defaults to GTC for limit kinds
+    pub time_in_force: Option<TimeInForce>,
+    /// Only reduce the position, futures only
+    pub reduce_only: bool,
+    pub client_order_id: Option<String>,
+}
+
+impl NewOrder {
+    pub fn market(symbol: impl Into<String>, side: OrderSide, quantity: impl Into<Number>) -> Self {
+        NewOrder {
+            symbol: symbol.into(),
+            side,
+            kind: OrderKind::Market,
+            quantity: quantity.into(),
+            price: None,
+            stop_price: None,
+            time_in_force: None,
+            reduce_only: false,
+            client_order_id: None,
+        }
+    }
+
+    pub fn limit(
+        symbol: impl Into<String>,
+        side: OrderSide,
+        quantity: impl Into<Number>,
+        price: impl Into<Number>,
+    ) -> Self {
+        NewOrder {
+            kind: OrderKind::Limit,
+            price: Some(price.into()),
+            ..Self::market(symbol, side, quantity)
+        }
+    }
+
+    fn time_in_force(&self) -> Option<TimeInForce> {
+        self.time_in_force
+            .clone()
+            .or_else(|| self.kind.is_limit().then_some(TimeInForce::GTC))
+    }
+}
+
+/// An order as known by the exchange
+#[derive(Debug, Clone)]
+pub struct ExchangeOrder {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+    pub side: OrderSide,
+    /// `None` for order types without an equivalent on every market, ex: trailing stops
+    pub kind: Option<OrderKind>,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    /// Zero for market orders
+    pub price: Number,
+    pub stop_price: Option<Number>,
+    pub quantity: Number,
+    pub executed_qty: Number,
+    /// Value of the executed quantity in the quote asset
+    pub executed_quote_qty: Number,
+    /// Only known for spot and margin orders, when they are placed
+    pub fills: Vec<Fill>,
+    pub update_time: u64,
+}
+
+/// Balance of an asset, futures balances include the realized profit but not the unrealized profit
+#[derive(Debug, Clone)]
+pub struct ExchangeBalance {
+    pub asset: String,
+    /// Available for new orders
+    pub free: Number,
+    pub total: Number,
+}
+
+/// An open futures position
+#[derive(Debug, Clone)]
+pub struct ExchangePosition {
+    pub symbol: String,
+    /// Negative for short positions
+    pub quantity: Number,
+    pub entry_price: Number,
+    pub unrealized_profit: Number,
+    pub leverage: u64,
+}
+
+/// Trading on a market, implemented by the spot, margin and futures accounts
+pub trait Exchange: Send + Sync {
+    fn place_order(&self, order: NewOrder) -> impl Future<Output = Result<ExchangeOrder>> + Send;
+
+    fn cancel_order(&self, symbol: &str, order_id: u64) -> impl Future<Output = Result<()>> + Send;
+
+    fn order_status(&self, symbol: &str, order_id: u64) -> impl Future<Output = Result<ExchangeOrder>> + Send;
+
+    fn open_orders(&self, symbol: &str) -> impl Future<Output = Result<Vec<ExchangeOrder>>> + Send;
+
+    /// Balances of the assets held
+    fn balances(&self) -> impl Future<Output = Result<Vec<ExchangeBalance>>> + Send;
+
+    /// Open positions, always empty for spot and margin accounts whose holdings are balances
+    fn positions(&self) -> impl Future<Output = Result<Vec<ExchangePosition>>> + Send;
+}
+
+fn non_zero(n: Number) -> Option<Number> { (n != Number::default()).then_some(n) }
+
+impl From<crate::rest_model::Transaction> for ExchangeOrder {
+    fn from(t: crate::rest_model::Transaction) -> Self {
+        ExchangeOrder {
+            symbol: t.symbol,
+            order_id: t.order_id,
+            client_order_id: t.client_order_id,
+            side: t.side,
+            kind: spot_kind(&t.order_type),
+            status: t.status,
+            time_in_force: t.time_in_force,
+            price: t.price,
+            stop_price: None,
+            quantity: t.orig_qty,
+            executed_qty: t.executed_qty,
+            executed_quote_qty: t.cummulative_quote_qty,
+            fills: t.fills,
+            update_time: t.transact_time,
+        }
+    }
+}
+
+impl From<crate::rest_model::Order> for ExchangeOrder {
+    fn from(o: crate::rest_model::Order) -> Self {
+        ExchangeOrder {
+            symbol: o.symbol,
+            order_id: o.order_id,
+            client_order_id: o.client_order_id,
+            side: o.side,
+            kind: spot_kind(&o.order_type),
+            status: o.status,
+            time_in_force: o.time_in_force,
+            price: o.price,
+            stop_price: non_zero(o.stop_price),
+            quantity: o.orig_qty,
+            executed_qty: o.executed_qty,
+            executed_quote_qty: o.cummulative_quote_qty,
+            fills: vec![],
+            update_time: o.update_time,
+        }
+    }
+}
+
+impl Exchange for Account {
+    async fn place_order(&self, order: NewOrder) -> Result<ExchangeOrder> {
+        let request = OrderRequest {
+            time_in_force: order.time_in_force(),
+            symbol: order.symbol,
+            side: order.side,
+            order_type: order.kind.into(),
+            quantity: Some(order.quantity),
+            price: order.price,
+            stop_price: order.stop_price,
+            new_client_order_id: order.client_order_id,
+            // other order types only return the order id by default
+            new_order_resp_type: Some(OrderResponse::Full),
+            ..OrderRequest::default()
+        };
+        // the response has no stop price
+        let stop_price = request.stop_price;
+        let placed = Account::place_order(self, request).await?;
+        Ok(ExchangeOrder {
+            stop_price,
+            ..placed.into()
+        })
+    }
+
+    async fn cancel_order(&self, symbol: &str, order_id: u64) -> Result<()> {
+        let request = OrderCancellation {
+            symbol: symbol.to_string(),
+            order_id: Some(order_id),
+            ..OrderCancellation::default()
+        };
+        Account::cancel_order(self, request).await?;
+        Ok(())
+    }
+
+    async fn order_status(&self, symbol: &str, order_id: u64) -> Result<ExchangeOrder> {
+        let request = OrderStatusRequest {
+            symbol: symbol.to_string(),
+            order_id: Some(order_id),
+            ..OrderStatusRequest::default()
+        };
+        Ok(Account::order_status(self, request).await?.into())
+    }
+
+    async fn open_orders(&self, symbol: &str) -> Result<Vec<ExchangeOrder>> {
+        Ok(self.get_open_orders(symbol).await?.into_iter().map(Into::into).collect())
+    }
+
+    async fn balances(&self) -> Result<Vec<ExchangeBalance>> {
+        let balances = self.get_account().await?.balances.into_iter().map(|b| ExchangeBalance {
+            total: b.free + b.locked,
+            asset: b.asset,
+            free: b.free,
+        });
+        Ok(balances.filter(|b| b.total != Number::default()).collect())
+    }
+
+    async fn positions(&self) -> Result<Vec<ExchangePosition>> { Ok(vec![]) }
+}
+
+#[cfg(feature = "margin_api")]
+mod margin {
+    use super::*;
+    use crate::margin::Margin;
+    use crate::rest_model::{
+        MarginOrder, MarginOrderCancellationResult, MarginOrderQuery, MarginOrderResult, MarginOrderState,
+        SideEffectType,
+    };
+
+    impl From<MarginOrderResult> for ExchangeOrder {
+        fn from(o: MarginOrderResult) -> Self {
+            ExchangeOrder {
+                symbol: o.symbol,
+                order_id: o.order_id,
+                client_order_id: o.client_order_id,
+                side: o.side,
+                kind: spot_kind(&o.order_type),
+                status: o.status,
+                time_in_force: o.time_in_force,
+                price: o.price,
+                stop_price: None,
+                quantity: o.orig_qty,
+                executed_qty: o.executed_qty,
+                executed_quote_qty: o.cummulative_quote_qty,
+                fills: o.fills,
+                update_time: o.transact_time as u64,
+            }
+        }
+    }
+
+    impl From<MarginOrderState> for ExchangeOrder {
+        fn from(o: MarginOrderState) -> Self {
+            ExchangeOrder {
+                symbol: o.symbol,
+                order_id: o.order_id,
+                client_order_id: o.client_order_id,
+                side: o.side,
+                kind: spot_kind(&o.order_type),
+                status: o.status,
+                time_in_force: o.time_in_force,
+                price: o.price,
+                stop_price: non_zero(o.stop_price),
+                quantity: o.orig_qty,
+                executed_qty: o.executed_qty,
+                executed_quote_qty: o.cummulative_quote_qty,
+                fills: vec![],
+                update_time: o.update_time,
+            }
+        }
+    }
+
+    /// Cross margin orders, without automatic borrowing or repayment
+    impl Exchange for Margin {
+        async fn place_order(&self, order: NewOrder) -> Result<ExchangeOrder> {
+            let request = MarginOrder {
+                time_in_force: order.time_in_force(),
+                symbol: order.symbol,
+                side: order.side,
+                order_type: order.kind.into(),
+                quantity: Some(order.quantity),
+                quote_order_qty: None,
+                price: order.price,
+                stop_price: order.stop_price,
+                new_client_order_id: order.client_order_id,
+                iceberg_qty: None,
+                new_order_resp_type: OrderResponse::Full,
+                is_isolated: None,
+                side_effect_type: SideEffectType::NoSideEffect,
+            };
+            let stop_price = request.stop_price;
+            let placed = self.new_order(request).await?;
+            Ok(ExchangeOrder {
+                stop_price,
+                ..placed.into()
+            })
+        }
+
+        async fn cancel_order(&self, symbol: &str, order_id: u64) -> Result<()> {
+            let request = OrderCancellation {
+                symbol: symbol.to_string(),
+                order_id: Some(order_id),
+                ..OrderCancellation::default()
+            };
+            let _: MarginOrderCancellationResult = self
+                .client
+                .delete_signed_p("/sapi/v1/margin/order", request, self.recv_window)
+                .await?;
+            Ok(())
+        }
+
+        async fn order_status(&self, symbol: &str, order_id: u64) -> Result<ExchangeOrder> {
+            let query = MarginOrderQuery {
+                symbol: symbol.to_string(),
+                is_isolated: None,
+                order_id: Some(order_id.to_string()),
+                orig_client_order_id: None,
+            };
+            Ok(self.order(query).await?.into())
+        }
+
+        async fn open_orders(&self, symbol: &str) -> Result<Vec<ExchangeOrder>> {
+            let orders = Margin::open_orders(self, symbol, None).await?;
+            Ok(orders.into_iter().map(Into::into).collect())
+        }
+
+        async fn balances(&self) -> Result<Vec<ExchangeBalance>> {
+            let assets = self.details().await?.user_assets.into_iter().map(|a| ExchangeBalance {
+                total: a.free + a.locked,
+                asset: a.asset,
+                free: a.free,
+            });
+            Ok(assets.filter(|b| b.total != Number::default()).collect())
+        }
+
+        async fn positions(&self) -> Result<Vec<ExchangePosition>> { Ok(vec![]) }
+    }
+}
+
+#[cfg(feature = "futures_api")]
+mod futures {
+    use super::*;
+    use crate::futures::account::{FuturesAccount, OrderRequest};
+    use crate::futures::rest_model::{Order, OrderType, Transaction};
+
+    impl From<OrderKind> for OrderType {
+        fn from(kind: OrderKind) -> Self {
+            match kind {
+                OrderKind::Market => Self::Market,
+                OrderKind::Limit => Self::Limit,
+                OrderKind::StopMarket => Self::StopMarket,
+                OrderKind::StopLimit => Self::Stop,
+                OrderKind::TakeProfitMarket => Self::TakeProfitMarket,
+                OrderKind::TakeProfitLimit => Self::TakeProfit,
+            }
+        }
+    }
+
+    fn futures_kind(order_type: &OrderType) -> Option<OrderKind> {
+        match order_type {
+            OrderType::Market => Some(OrderKind::Market),
+            OrderType::Limit => Some(OrderKind::Limit),
+            OrderType::StopMarket => Some(OrderKind::StopMarket),
+            OrderType::Stop => Some(OrderKind::StopLimit),
+            OrderType::TakeProfitMarket => Some(OrderKind::TakeProfitMarket),
+            OrderType::TakeProfit => Some(OrderKind::TakeProfitLimit),
+            OrderType::TrailingStopMarket => None,
+        }
+    }
+
+    impl From<Transaction> for ExchangeOrder {
+        fn from(t: Transaction) -> Self {
+            ExchangeOrder {
+                kind: futures_kind(&t.type_name),
+                symbol: t.symbol,
+                order_id: t.order_id,
+                client_order_id: t.client_order_id,
+                side: t.side,
+                status: t.status,
+                time_in_force: t.time_in_force,
+                price: t.price,
+                stop_price: non_zero(t.stop_price),
+                quantity: t.orig_qty,
+                executed_qty: t.executed_qty,
+                executed_quote_qty: t.cum_quote,
+                fills: vec![],
+                update_time: t.update_time,
+            }
+        }
+    }
+
+    impl From<Order> for ExchangeOrder {
+        fn from(o: Order) -> Self {
+            ExchangeOrder {
+                kind: futures_kind(&o.order_type),
+                symbol: o.symbol,
+                order_id: o.order_id,
+                client_order_id: o.client_order_id,
+                side: o.side,
+                status: o.status,
+                time_in_force: o.time_in_force,
+                price: o.price,
+                stop_price: non_zero(o.stop_price),
+                quantity: o.orig_qty,
+                executed_qty: o.executed_qty,
+                executed_quote_qty: o.cum_quote,
+                fills: vec![],
+                update_time: o.update_time,
+            }
+        }
+    }
+
+    /// USD-M futures in one-way position mode
+    impl Exchange for FuturesAccount {
+        async fn place_order(&self, order: NewOrder) -> Result<ExchangeOrder> {
+            let request = OrderRequest {
+                time_in_force: order.time_in_force(),
+                symbol: order.symbol,
+                side: order.side,
+                order_type: order.kind.into(),
+                quantity: Some(order.quantity),
+                reduce_only: order.reduce_only.then_some(true),
+                price: order.price,
+                stop_price: order.stop_price,
+                new_client_order_id: order.client_order_id,
+                ..OrderRequest::default()
+            };
+            Ok(FuturesAccount::place_order(self, request).await?.into())
+        }
+
+        async fn cancel_order(&self, symbol: &str, order_id: u64) -> Result<()> {
+            let request = OrderCancellation {
+                symbol: symbol.to_string(),
+                order_id: Some(order_id),
+                ..OrderCancellation::default()
+            };
+            FuturesAccount::cancel_order(self, request).await?;
+            Ok(())
+        }
+
+        async fn order_status(&self, symbol: &str, order_id: u64) -> Result<ExchangeOrder> {
+            let request = OrderStatusRequest {
+                symbol: symbol.to_string(),
+                order_id: Some(order_id),
+                ..OrderStatusRequest::default()
+            };
+            Ok(FuturesAccount::order_status(self, request).await?.into())
+        }
+
+        async fn open_orders(&self, symbol: &str) -> Result<Vec<ExchangeOrder>> {
+            Ok(self.get_open_orders(symbol).await?.into_iter().map(Into::into).collect())
+        }
+
+        async fn balances(&self) -> Result<Vec<ExchangeBalance>> {
+            let balances = self.account_balance().await?.into_iter().map(|b| ExchangeBalance {
+                asset: b.asset,
+                free: b.available_balance,
+                total: b.balance,
+            });
+            Ok(balances.filter(|b| b.total != Number::default()).collect())
+        }
+
+        async fn positions(&self) -> Result<Vec<ExchangePosition>> {
+            let positions = self.account_information().await?.positions.into_iter();
+            Ok(positions
+                .filter(|p| p.position_amount != Number::default())
+                .map(|p| ExchangePosition {
+                    symbol: p.symbol,
+                    quantity: p.position_amount,
+                    entry_price: p.entry_price,
+                    unrealized_profit: p.unrealized_profit,
+                    leverage: p.leverage,
+                })
+                .collect())
+        }
+    }
+}
@@ -0,0 +1,276 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use tracing::warn;
+
+use crate::errors::*;
+use crate::general::General;
+use crate::rest_model::{Symbol, SymbolStatus};
+
+/// Capacity of the change notification channel, slow receivers miss older changes
+const CHANGES_CAPACITY: usize = 1024;
+
+/// The fields of a symbol indexed by [`ExchangeInfoCache`]
+pub trait SymbolInfo: Clone + Send + Sync + 'static {
+    fn name(&self) -> &str;
+
+    fn base_asset(&self) -> &str;
+
+    fn quote_asset(&self) -> &str;
+
+    fn status(&self) -> &SymbolStatus;
+}
+
+impl SymbolInfo for Symbol {
+    fn name(&self) -> &str { &self.symbol }
+
+    fn base_asset(&self) -> &str { &self.base_asset }
+
+    fn quote_asset(&self) -> &str { &self.quote_asset }
+
+    fn status(&self) -> &SymbolStatus { &self.status }
+}
+
+/// An API serving exchange information
+pub trait ExchangeInfoSource: Send + Sync + 'static {
+    type Symbol: SymbolInfo;
+
+    fn symbols(&self) -> impl Future<Output = Result<Vec<Self::Symbol>>> + Send;
+}
+
+impl ExchangeInfoSource for General {
+    type Symbol = Symbol;
+
+    async fn symbols(&self) -> Result<Vec<Symbol>> { Ok(self.exchange_info().await?.symbols) }
+}
+
+#[cfg(feature = "futures_api")]
+impl SymbolInfo for crate::futures::rest_model::Symbol {
+    fn name(&self) -> &str { &self.symbol }
+
+    fn base_asset(&self) -> &str { &self.base_asset }
+
+    fn quote_asset(&self) -> &str { &self.quote_asset }
+
+    fn status(&self) -> &SymbolStatus { &self.status }
+}
+
+#[cfg(feature = "futures_api")]
+impl ExchangeInfoSource for crate::futures::general::FuturesGeneral {
+    type Symbol = crate::futures::rest_model::Symbol;
+
+    async fn symbols(&self) -> Result<Vec<Self::Symbol>> { Ok(self.exchange_info().await?.symbols) }
+}
+
+/// A change between two versions of the exchange information
+#[derive(Debug, Clone)]
+pub enum SymbolChange<S> {
+    Listed(S),
+    Delisted(S),
+    StatusChanged { symbol: S, previous: SymbolStatus },
+}
+
+#[derive(Debug)]
+struct Index<S> {
+    symbols: HashMap<String, S>,
+    by_base_asset: HashMap<String, Vec<String>>,
+    by_quote_asset: HashMap<String, Vec<String>>,
+}
+
+impl<S: SymbolInfo> Index<S> {
+    fn new(symbols: Vec<S>) -> Self {
+        let mut index = Index {
+            symbols: HashMap::new(),
+            by_base_asset: HashMap::new(),
+            by_quote_asset: HashMap::new(),
+        };
+        for symbol in symbols {
+            let name = symbol.name().to_string();
+            index
+                .by_base_asset
+                .entry(symbol.base_asset().to_string())
+                .or_default()
+                .push(name.clone());
+            index
+                .by_quote_asset
+                .entry(symbol.quote_asset().to_string())
+                .or_default()
+                .push(name.clone());
+            index.symbols.insert(name, symbol);
+        }
+        index
+    }
+
+    fn lookup(&self, names: Option<&Vec<String>>) -> Vec<S> {
+        names
+            .map(|names| names.iter().filter_map(|name| self.symbols.get(name).cloned()).collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Exchange information loaded once and indexed by symbol, base asset and quote asset
+///
+/// Clones share the same index, refreshes replace it and broadcast the listings, delistings
+/// and status changes found since the previous version.
+/// # Examples
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use binance::{api::*, exchange_info::*, general::*};
+/// tokio_test::block_on(async {
+///     let general: General = Binance::new(None, None);
+///     let cache = ExchangeInfoCache::load(&general).await?;
+///     println!("{:?}", cache.symbol("BTCUSDT"));
+///     println!("{}", cache.by_quote_asset("USDT").len());
+///     let mut changes = cache.subscribe();
+///     let _refresh = cache.spawn_refresh(general, Duration::from_secs(300));
+///     while let Ok(change) = changes.recv().await {
+///         println!("{change:?}");
+///     }
+///     binance::errors::Result::Ok(())
+/// });
+/// ```
+#[derive(Clone)]
+pub struct ExchangeInfoCache<S> {
+    index: Arc<RwLock<Index<S>>>,
+    changes: broadcast::Sender<SymbolChange<S>>,
+}
+
+impl<S: SymbolInfo> ExchangeInfoCache<S> {
+    pub fn new(symbols: Vec<S>) -> Self {
+        ExchangeInfoCache {
+            index: Arc::new(RwLock::new(Index::new(symbols))),
+            changes: broadcast::channel(CHANGES_CAPACITY).0,
+        }
+    }
+
+    /// Load the cache from an API
+    pub async fn load<L: ExchangeInfoSource<Symbol = S>>(source: &L) -> Result<Self> {
+        Ok(Self::new(source.symbols().await?))
+    }
+
+    pub fn symbol(&self, name: &str) -> Option<S> { self.index.read().unwrap().symbols.get(name).cloned() }
+
+    pub fn symbols(&self) -> Vec<S> { self.index.read().unwrap().symbols.values().cloned().collect() }
+
+    /// Symbols trading `asset` as their base asset
+    pub fn by_base_asset(&self, asset: &str) -> Vec<S> {
+        let index = self.index.read().unwrap();
+        index.lookup(index.by_base_asset.get(asset))
+    }
+
+    /// Symbols quoted in `asset`
+    pub fn by_quote_asset(&self, asset: &str) -> Vec<S> {
+        let index = self.index.read().unwrap();
+        index.lookup(index.by_quote_asset.get(asset))
+    }
+
+    /// Receive the changes found by later updates
+    pub fn subscribe(&self) -> broadcast::Receiver<SymbolChange<S>> { self.changes.subscribe() }
+
+    /// Replace the symbols, returns and broadcasts the changes from the previous version
+    pub fn update(&self, symbols: Vec<S>) -> Vec<SymbolChange<S>> {
+        let new_index = Index::new(symbols);
+        let mut index = self.index.write().unwrap();
+        let mut changes = vec![];
+        for (name, symbol) in &new_index.symbols {
+            match index.symbols.get(name) {
+                None => changes.push(SymbolChange::Listed(symbol.clone())),
+                Some(previous) if previous.status() != symbol.status() => changes.push(SymbolChange::StatusChanged {
+                    symbol: symbol.clone(),
+                    previous: previous.status().clone(),
+                }),
+                _ => {}
+            }
+        }
+        for (name, symbol) in &index.symbols {
+            if !new_index.symbols.contains_key(name) {
+                changes.push(SymbolChange::Delisted(symbol.clone()));
+            }
+        }
+        *index = new_index;
+        drop(index);
+        for change in &changes {
+            // no receivers is not an error
+            let _ = self.changes.send(change.clone());
+        }
+        changes
+    }
+
+    /// Fetch the exchange information and update the cache
+    pub async fn refresh<L: ExchangeInfoSource<Symbol = S>>(&self, source: &L) -> Result<Vec<SymbolChange<S>>> {
+        Ok(self.update(source.symbols().await?))
+    }
+
+    /// Refresh the cache every `interval` until the task is aborted, failed refreshes are retried at the next tick
+    pub fn spawn_refresh<L: ExchangeInfoSource<Symbol = S>>(&self, source: L, interval: Duration) -> JoinHandle<()> {
+        let cache = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            // the first tick completes immediately and the cache is already loaded
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                if let Err(e) = cache.refresh(&source).await {
+                    warn!("Failed to refresh exchange information: {e}");
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Read;
+    use std::path::PathBuf;
+
+    use flate2::read::GzDecoder;
+
+    use super::*;
+    use crate::rest_model::ExchangeInformation;
+
+    fn exchange_info() -> ExchangeInformation {
+        let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        d.push("test_data/exchangeInfo.json.gz");
+        let mut fc = String::new();
+        GzDecoder::new(std::fs::File::open(d).unwrap())
+            .read_to_string(&mut fc)
+            .unwrap();
+        serde_json::from_str(&fc).unwrap()
+    }
+
+    #[test]
+    fn exchange_info_cache() {
+        let mut symbols = exchange_info().symbols;
+        let cache = ExchangeInfoCache::new(symbols.clone());
+        assert_eq!(cache.symbols().len(), symbols.len());
+        assert_eq!(cache.symbol("BTCUSDT").unwrap().base_asset, "BTC");
+        assert!(cache.by_base_asset("BTC").iter().all(|s| s.base_asset == "BTC"));
+        assert!(cache.by_quote_asset("USDT").iter().any(|s| s.symbol == "ETHUSDT"));
+
+        let mut changes = cache.subscribe();
+        let delisted = symbols.pop().unwrap();
+        let position = symbols.iter().position(|s| s.symbol == "BTCUSDT").unwrap();
+        symbols[position].status = SymbolStatus::Break;
+        let mut listed = symbols[0].clone();
+        listed.symbol = "NEWUSDT".to_string();
+        symbols.push(listed);
+        assert_eq!(cache.update(symbols).len(), 3);
+        let mut received = vec![];
+        while let Ok(change) = changes.try_recv() {
+            received.push(change);
+        }
+        assert!(received
+            .iter()
+            .any(|c| matches!(c, SymbolChange::Delisted(s) if s.symbol == delisted.symbol)));
+        assert!(received.iter().any(|c| matches!(c, SymbolChange::Listed(s) if s.symbol == "NEWUSDT")));
+        assert!(received.iter().any(|c| matches!(
+            c,
+            SymbolChange::StatusChanged { symbol, previous: SymbolStatus::Trading } if symbol.status == SymbolStatus::Break
+        )));
+        assert!(cache.symbol(&delisted.symbol).is_none());
+    }
+}
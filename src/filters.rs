@@ -0,0 +1,619 @@
+use std::collections::HashMap;
+
+use crate::account::OrderRequest;
+use crate::errors::*;
+use crate::rest_model::{ExchangeInformation, Filters, Number, OrderType, Symbol};
+
+/// How prices and quantities are brought to a multiple of the tick or step size
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RoundingMode {
+    /// Towards zero, never increases the amount of an order
+    #[default]
+    Down,
+    Up,
+    Nearest,
+}
+
+/// Bounds of a price or quantity filter, a tick or step of zero disables rounding
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bounds {
+    pub min: Number,
+    pub max: Number,
+    pub step: Number,
+}
+
+/// The trading rules of a symbol, shared by spot and futures filters
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SymbolFilters {
+    pub price: Option<Bounds>,
+    pub lot_size: Option<Bounds>,
+    pub market_lot_size: Option<Bounds>,
+    /// Minimum notional and whether it applies to market orders
+    pub min_notional: Option<(Number, bool)>,
+    /// Maximum notional and whether it applies to market orders
+    pub max_notional: Option<(Number, bool)>,
+    /// Multipliers of the average price bounding the price of an order
+    pub percent_price: Option<(Number, Number)>,
+    pub iceberg_parts: Option<u16>,
+}
+
+impl SymbolFilters {
+    /// Round a price to the tick size
+    pub fn round_price(&self, price: Number, rounding: RoundingMode) -> Number {
+        match &self.price {
+            Some(bounds) => round_to_step(price, bounds.step, rounding),
+            None => price,
+        }
+    }
+
+    /// Round a quantity to the step size, market orders also to the step of the market lot size
+    pub fn round_qty(&self, qty: Number, market: bool, rounding: RoundingMode) -> Number {
+        self.lot_sizes(market)
+            .fold(qty, |qty, bounds| round_to_step(qty, bounds.step, rounding))
+    }
+
+    /// The lot sizes a quantity must satisfy, the market lot size applies to market orders on top of the lot size
+    fn lot_sizes(&self, market: bool) -> impl Iterator<Item = &Bounds> {
+        self.lot_size
+            .iter()
+            .chain(self.market_lot_size.iter().filter(move |_| market))
+    }
+
+    /// Check a price against the price filter and the average price when it is known
+    pub fn check_price(&self, price: Number, avg_price: Option<Number>) -> std::result::Result<(), FilterError> {
+        if let Some(Bounds { min, max, .. }) = &self.price {
+            // a max of 0 disables the bound
+            if price < *min || (*max > Number::default() && price > *max) {
+                return Err(FilterError::Price {
+                    price,
+                    min: *min,
+                    max: *max,
+                });
+            }
+        }
+        if let (Some((up, down)), Some(avg_price)) = (self.percent_price, avg_price) {
+            let (min, max) = (avg_price * down, avg_price * up);
+            if price < min || price > max {
+                return Err(FilterError::PercentPrice { price, min, max });
+            }
+        }
+        Ok(())
+    }
+
+    /// Check a quantity against the lot size, and the market lot size for market orders
+    pub fn check_qty(&self, qty: Number, market: bool) -> std::result::Result<(), FilterError> {
+        for Bounds { min, max, .. } in self.lot_sizes(market) {
+            // a max of 0 disables the bound
+            if qty < *min || (*max > Number::default() && qty > *max) {
+                return Err(FilterError::Quantity {
+                    qty,
+                    min: *min,
+                    max: *max,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Check the notional of an order, market orders are only checked when the filter applies to them
+    pub fn check_notional(&self, notional: Number, market: bool) -> std::result::Result<(), FilterError> {
+        match self.min_notional {
+            Some((min, apply_to_market)) if (!market || apply_to_market) && notional < min => {
+                return Err(FilterError::MinNotional { notional, min });
+            }
+            _ => {}
+        }
+        match self.max_notional {
+            Some((max, apply_to_market)) if (!market || apply_to_market) && notional > max => {
+                Err(FilterError::MaxNotional { notional, max })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Check the number of parts of an iceberg order
+    pub fn check_iceberg(&self, qty: Number, iceberg_qty: Number) -> std::result::Result<(), FilterError> {
+        match self.iceberg_parts {
+            Some(limit) if iceberg_qty > Number::default() => {
+                let parts = (qty / iceberg_qty).ceil();
+                if parts > Number::from(limit) {
+                    return Err(FilterError::IcebergParts { parts, limit });
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Round a price field of an order and check it, percent price bounds are checked when `avg_price` is set
+    fn apply_price(
+        &self,
+        price: &mut Option<Number>,
+        rounding: RoundingMode,
+        avg_price: Option<Number>,
+    ) -> std::result::Result<(), FilterError> {
+        match price.as_mut() {
+            Some(p) => {
+                *p = self.round_price(*p, rounding);
+                self.check_price(*p, avg_price)
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Round the quantity of an order and check it with its notional,
+    /// valued at the order price or at `avg_price` for market orders
+    fn apply_qty(
+        &self,
+        qty: &mut Option<Number>,
+        price: Option<Number>,
+        market: bool,
+        rounding: RoundingMode,
+        avg_price: Option<Number>,
+    ) -> std::result::Result<(), FilterError> {
+        let Some(q) = qty.as_mut() else {
+            return Ok(());
+        };
+        *q = self.round_qty(*q, market, rounding);
+        self.check_qty(*q, market)?;
+        match if market { avg_price } else { price.or(avg_price) } {
+            Some(reference) => self.check_notional(*q * reference, market),
+            None => Ok(()),
+        }
+    }
+}
+
+impl From<&Symbol> for SymbolFilters {
+    fn from(symbol: &Symbol) -> Self {
+        let mut filters = SymbolFilters::default();
+        for filter in &symbol.filters {
+            match filter {
+                Filters::PriceFilter {
+                    min_price,
+                    max_price,
+                    tick_size,
+                } => {
+                    filters.price = Some(Bounds {
+                        min: *min_price,
+                        max: *max_price,
+                        step: *tick_size,
+                    })
+                }
+                Filters::LotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => {
+                    filters.lot_size = Some(Bounds {
+                        min: *min_qty,
+                        max: *max_qty,
+                        step: *step_size,
+                    })
+                }
+                Filters::MarketLotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => {
+                    filters.market_lot_size = Some(Bounds {
+                        min: *min_qty,
+                        max: *max_qty,
+                        step: *step_size,
+                    })
+                }
+                Filters::MinNotional {
+                    min_notional,
+                    apply_to_market,
+                    ..
+                } => filters.min_notional = Some((*min_notional, *apply_to_market)),
+                Filters::Notional {
+                    min_notional,
+                    apply_min_to_market,
+                    max_notional,
+                    apply_max_to_market,
+                    ..
+                } => {
+                    filters.min_notional = Some((*min_notional, *apply_min_to_market));
+                    filters.max_notional = Some((*max_notional, *apply_max_to_market));
+                }
+                Filters::PercentPrice {
+                    multiplier_up,
+                    multiplier_down,
+                    ..
+                } => filters.percent_price = Some((*multiplier_up, *multiplier_down)),
+                Filters::IcebergParts { limit } => filters.iceberg_parts = Some(*limit),
+                _ => {}
+            }
+        }
+        filters
+    }
+}
+
+#[cfg(feature = "futures_api")]
+impl From<&crate::futures::rest_model::Symbol> for SymbolFilters {
+    fn from(symbol: &crate::futures::rest_model::Symbol) -> Self {
+        use crate::futures::rest_model::Filters;
+
+        let mut filters = SymbolFilters::default();
+        for filter in &symbol.filters {
+            match filter {
+                Filters::PriceFilter {
+                    min_price,
+                    max_price,
+                    tick_size,
+                } => {
+                    filters.price = Some(Bounds {
+                        min: *min_price,
+                        max: *max_price,
+                        step: *tick_size,
+                    })
+                }
+                Filters::LotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => {
+                    filters.lot_size = Some(Bounds {
+                        min: *min_qty,
+                        max: *max_qty,
+                        step: *step_size,
+                    })
+                }
+                Filters::MarketLotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => {
+                    filters.market_lot_size = Some(Bounds {
+                        min: *min_qty,
+                        max: *max_qty,
+                        step: *step_size,
+                    })
+                }
+                // futures notional applies to market orders as well
+                Filters::MinNotional { notional } => filters.min_notional = Some((*notional, true)),
+                Filters::PercentPrice {
+                    multiplier_up,
+                    multiplier_down,
+                    ..
+                } => filters.percent_price = Some((*multiplier_up, *multiplier_down)),
+                _ => {}
+            }
+        }
+        filters
+    }
+}
+
+/// Validates orders against the filters of their symbol before they are sent
+///
+/// Prices are rounded to the tick size and quantities to the step size with the rounding mode,
+/// then checked against price, lot size, notional, percent price and iceberg filters.
+/// # Examples
+/// ```rust,no_run
+/// use binance::{account::*, api::*, config::*, filters::*, general::*, market::*};
+/// tokio_test::block_on(async {
+///     let general: General = Binance::new(None, None);
+///     let market: Market = Binance::new(None, None);
+///     let validator = OrderValidator::new(&general.exchange_info().await?, RoundingMode::Down);
+///     let mut order = OrderRequest {
+///         symbol: "BTCUSDT".to_string(),
+///         price: Some("30000.123456".parse().unwrap()),
+///         quantity: Some("0.0012345".parse().unwrap()),
+///         ..OrderRequest::default()
+///     };
+///     let avg_price = market.get_average_price("BTCUSDT").await?.price;
+///     validator.validate(&mut order, Some(avg_price))?;
+///     binance::errors::Result::Ok(())
+/// });
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct OrderValidator {
+    rounding: RoundingMode,
+    symbols: HashMap<String, SymbolFilters>,
+}
+
+impl OrderValidator {
+    pub fn new(info: &ExchangeInformation, rounding: RoundingMode) -> Self {
+        OrderValidator {
+            rounding,
+            symbols: info.symbols.iter().map(|s| (s.symbol.clone(), s.into())).collect(),
+        }
+    }
+
+    #[cfg(feature = "futures_api")]
+    pub fn new_futures(info: &crate::futures::rest_model::ExchangeInformation, rounding: RoundingMode) -> Self {
+        OrderValidator {
+            rounding,
+            symbols: info.symbols.iter().map(|s| (s.symbol.clone(), s.into())).collect(),
+        }
+    }
+
+    /// The filters of a symbol
+    pub fn symbol(&self, symbol: &str) -> Result<&SymbolFilters> {
+        self.symbols
+            .get(symbol)
+            .ok_or_else(|| Error::UnknownSymbol(symbol.to_string()))
+    }
+
+    /// Round and check a spot order, `avg_price` is the current average price of the symbol
+    pub fn validate(&self, order: &mut OrderRequest, avg_price: Option<Number>) -> Result<()> {
+        let filters = self.symbol(&order.symbol)?;
+        let market = matches!(
+            order.order_type,
+            OrderType::Market | OrderType::StopLoss | OrderType::TakeProfit
+        );
+        filters.apply_price(&mut order.price, self.rounding, avg_price)?;
+        filters.apply_price(&mut order.stop_price, self.rounding, None)?;
+        filters.apply_qty(&mut order.quantity, order.price, market, self.rounding, avg_price)?;
+        if let (Some(qty), Some(iceberg_qty)) = (order.quantity, order.iceberg_qty.as_mut()) {
+            *iceberg_qty = filters.round_qty(*iceberg_qty, false, self.rounding);
+            filters.check_iceberg(qty, *iceberg_qty)?;
+        }
+        if let Some(quote_qty) = order.quote_order_qty {
+            filters.check_notional(quote_qty, market)?;
+            // the bought quantity is unknown without a positive average price
+            if let Some(avg_price) = avg_price.filter(|p| *p > Number::default()) {
+                filters.check_qty(quote_qty / avg_price, market)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Round and check a futures order, `mark_price` is the current mark price of the symbol
+    #[cfg(feature = "futures_api")]
+    pub fn validate_futures(
+        &self,
+        order: &mut crate::futures::account::OrderRequest,
+        mark_price: Option<Number>,
+    ) -> Result<()> {
+        use crate::futures::rest_model::OrderType;
+
+        let filters = self.symbol(&order.symbol)?;
+        let market = matches!(
+            order.order_type,
+            OrderType::Market | OrderType::StopMarket | OrderType::TakeProfitMarket | OrderType::TrailingStopMarket
+        );
+        filters.apply_price(&mut order.price, self.rounding, mark_price)?;
+        filters.apply_price(&mut order.stop_price, self.rounding, None)?;
+        filters.apply_price(&mut order.activation_price, self.rounding, None)?;
+        filters.apply_qty(&mut order.quantity, order.price, market, self.rounding, mark_price)?;
+        Ok(())
+    }
+}
+
+/// Round `value` to a multiple of `step`, keeping only the decimals of `step`
+#[cfg(not(feature = "decimal"))]
+fn round_to_step(value: Number, step: Number, rounding: RoundingMode) -> Number {
+    if step <= 0.0 {
+        return value;
+    }
+    // absorb representation errors such as 0.3 / 0.1 = 2.9999999999999996, with a tolerance of a
+    // fraction of the step, or of the precision of `value` when it is large relative to the step
+    let steps = value / step;
+    let tolerance = (value.abs() * f64::EPSILON * 4.0 / step).max(1e-9);
+    let steps = match rounding {
+        RoundingMode::Down => (steps + tolerance).floor(),
+        RoundingMode::Up => (steps - tolerance).ceil(),
+        RoundingMode::Nearest => (steps + tolerance).round(),
+    };
+    let decimals = step.to_string().split_once('.').map_or(0, |(_, d)| d.len());
+    format!("{:.*}", decimals, steps * step).parse().unwrap_or(value)
+}
+
+/// Round `value` to a multiple of `step`
+#[cfg(feature = "decimal")]
+fn round_to_step(value: Number, step: Number, rounding: RoundingMode) -> Number {
+    if step <= Number::ZERO {
+        return value;
+    }
+    let steps = value / step;
+    let steps = match rounding {
+        RoundingMode::Down => steps.floor(),
+        RoundingMode::Up => steps.ceil(),
+        RoundingMode::Nearest => steps.round(),
+    };
+    (steps * step).normalize()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::num;
+
+    fn filters() -> SymbolFilters {
+        SymbolFilters {
+            price: Some(Bounds {
+                min: num(0.01),
+                max: num(1000.0),
+                step: num(0.01),
+            }),
+            lot_size: Some(Bounds {
+                min: num(0.1),
+                max: num(100.0),
+                step: num(0.1),
+            }),
+            min_notional: Some((num(5.0), false)),
+            percent_price: Some((num(1.2), num(0.8))),
+            ..SymbolFilters::default()
+        }
+    }
+
+    #[test]
+    fn round_and_check() {
+        let filters = filters();
+        assert_eq!(filters.round_price(num(0.3), RoundingMode::Down), num(0.3));
+        assert_eq!(filters.round_price(num(10.129), RoundingMode::Down), num(10.12));
+        assert_eq!(filters.round_price(num(10.121), RoundingMode::Up), num(10.13));
+        assert_eq!(filters.round_qty(num(0.35), false, RoundingMode::Nearest), num(0.4));
+
+        let (mut price, mut qty) = (Some(num(10.129)), Some(num(0.37)));
+        assert_eq!(
+            filters.apply_qty(&mut qty, price, false, RoundingMode::Down, None),
+            Err(FilterError::MinNotional {
+                notional: num(0.3) * num(10.129),
+                min: num(5.0)
+            })
+        );
+        assert!(filters.apply_price(&mut price, RoundingMode::Down, Some(num(10.0))).is_ok());
+        assert_eq!(price, Some(num(10.12)));
+        assert!(matches!(
+            filters.check_price(num(13.0), Some(num(10.0))),
+            Err(FilterError::PercentPrice { .. })
+        ));
+        assert!(matches!(
+            filters.check_qty(num(100.1), false),
+            Err(FilterError::Quantity { .. })
+        ));
+    }
+
+    #[test]
+    fn market_lot_size() {
+        // as in the exchange information, the market lot size has no step
+        let filters = SymbolFilters {
+            market_lot_size: Some(Bounds {
+                min: num(0.0),
+                max: num(50.0),
+                step: num(0.0),
+            }),
+            ..filters()
+        };
+        assert_eq!(filters.round_qty(num(0.37), true, RoundingMode::Down), num(0.3));
+        assert!(filters.check_qty(num(60.0), false).is_ok());
+        for qty in [num(0.05), num(60.0), num(100.1)] {
+            assert!(matches!(filters.check_qty(qty, true), Err(FilterError::Quantity { .. })));
+        }
+        assert!(filters.check_qty(num(0.3), true).is_ok());
+    }
+
+    #[test]
+    fn round_large_values() {
+        assert_eq!(round_to_step(num(1234567.89), num(0.01), RoundingMode::Down), num(1234567.89));
+        assert_eq!(round_to_step(num(98765.4321), num(0.0001), RoundingMode::Down), num(98765.4321));
+        assert_eq!(round_to_step(num(0.00012345), num(0.00000001), RoundingMode::Up), num(0.00012345));
+        assert_eq!(round_to_step(num(12345.678), num(0.01), RoundingMode::Down), num(12345.67));
+    }
+
+    fn validator() -> OrderValidator {
+        OrderValidator {
+            rounding: RoundingMode::Down,
+            symbols: HashMap::from([("BTCUSDT".to_string(), filters())]),
+        }
+    }
+
+    fn order(order_type: OrderType, qty: f64, price: Option<f64>) -> OrderRequest {
+        OrderRequest {
+            symbol: "BTCUSDT".to_string(),
+            order_type,
+            quantity: Some(num(qty)),
+            price: price.map(num),
+            ..OrderRequest::default()
+        }
+    }
+
+    #[test]
+    fn validate() {
+        let validator = validator();
+        let mut limit = order(OrderType::Limit, 1.234, Some(10.129));
+        assert!(validator.validate(&mut limit, Some(num(10.0))).is_ok());
+        assert_eq!((limit.quantity, limit.price), (Some(num(1.2)), Some(num(10.12))));
+
+        let mut unknown = OrderRequest {
+            symbol: "ETHUSDT".to_string(),
+            ..order(OrderType::Limit, 1.0, Some(10.0))
+        };
+        assert!(matches!(
+            validator.validate(&mut unknown, None),
+            Err(Error::UnknownSymbol(_))
+        ));
+        assert!(matches!(
+            validator.validate(&mut order(OrderType::Limit, 0.05, Some(100.0)), None),
+            Err(Error::OrderFilter(FilterError::Quantity { .. }))
+        ));
+        assert!(matches!(
+            validator.validate(&mut order(OrderType::Limit, 120.0, Some(1.0)), None),
+            Err(Error::OrderFilter(FilterError::Quantity { .. }))
+        ));
+        assert!(matches!(
+            validator.validate(&mut order(OrderType::Limit, 0.4, Some(10.0)), None),
+            Err(Error::OrderFilter(FilterError::MinNotional { .. }))
+        ));
+        // the min notional does not apply to market orders, valued at the average price
+        assert!(validator.validate(&mut order(OrderType::Market, 0.4, None), Some(num(10.0))).is_ok());
+        assert!(matches!(
+            validator.validate(&mut order(OrderType::Limit, 1.0, Some(12.5)), Some(num(10.0))),
+            Err(Error::OrderFilter(FilterError::PercentPrice { .. }))
+        ));
+        assert!(matches!(
+            validator.validate(&mut order(OrderType::Limit, 1.0, Some(7.9)), Some(num(10.0))),
+            Err(Error::OrderFilter(FilterError::PercentPrice { .. }))
+        ));
+    }
+
+    #[test]
+    fn validate_notional_bounds() {
+        let mut filters = filters();
+        filters.max_notional = Some((num(500.0), true));
+        let validator = OrderValidator {
+            rounding: RoundingMode::Down,
+            symbols: HashMap::from([("BTCUSDT".to_string(), filters)]),
+        };
+        assert!(matches!(
+            validator.validate(&mut order(OrderType::Market, 60.0, None), Some(num(10.0))),
+            Err(Error::OrderFilter(FilterError::MaxNotional { .. }))
+        ));
+        assert!(validator.validate(&mut order(OrderType::Limit, 40.0, Some(10.0)), None).is_ok());
+    }
+
+    #[test]
+    fn validate_quote_qty() {
+        let validator = validator();
+        let quote_order = |quote_qty: f64| OrderRequest {
+            symbol: "BTCUSDT".to_string(),
+            order_type: OrderType::Market,
+            quote_order_qty: Some(num(quote_qty)),
+            ..OrderRequest::default()
+        };
+        assert!(validator.validate(&mut quote_order(20.0), Some(num(10.0))).is_ok());
+        assert!(matches!(
+            validator.validate(&mut quote_order(2000.0), Some(num(10.0))),
+            Err(Error::OrderFilter(FilterError::Quantity { .. }))
+        ));
+        // the quantity cannot be derived from a zero or unknown average price
+        assert!(validator.validate(&mut quote_order(2000.0), Some(Number::default())).is_ok());
+        assert!(validator.validate(&mut quote_order(2000.0), None).is_ok());
+    }
+
+    #[cfg(feature = "futures_api")]
+    #[test]
+    fn validate_futures() {
+        use crate::futures::{account::OrderRequest, rest_model::OrderType};
+
+        let validator = validator();
+        let futures_order = |order_type: OrderType, qty: f64, price: Option<f64>| OrderRequest {
+            symbol: "BTCUSDT".to_string(),
+            order_type,
+            quantity: Some(num(qty)),
+            price: price.map(num),
+            stop_price: Some(num(9.999)),
+            ..OrderRequest::default()
+        };
+        let mut limit = futures_order(OrderType::Limit, 1.234, Some(10.129));
+        assert!(validator.validate_futures(&mut limit, Some(num(10.0))).is_ok());
+        assert_eq!(
+            (limit.quantity, limit.price, limit.stop_price),
+            (Some(num(1.2)), Some(num(10.12)), Some(num(9.99)))
+        );
+        assert!(matches!(
+            validator.validate_futures(&mut futures_order(OrderType::Limit, 200.0, Some(10.0)), None),
+            Err(Error::OrderFilter(FilterError::Quantity { .. }))
+        ));
+        assert!(matches!(
+            validator.validate_futures(&mut futures_order(OrderType::Limit, 1.0, Some(12.5)), Some(num(10.0))),
+            Err(Error::OrderFilter(FilterError::PercentPrice { .. }))
+        ));
+        assert!(matches!(
+            validator.validate_futures(&mut futures_order(OrderType::Limit, 0.4, Some(10.0)), None),
+            Err(Error::OrderFilter(FilterError::MinNotional { .. }))
+        ));
+    }
+}
@@ -1,13 +1,15 @@
 use std::collections::BTreeMap;
 
 use super::rest_model::{
-    AccountBalance, AccountInformation, CanceledOrder, ChangeLeverageResponse, Order, OrderType, Position,
-    PositionSide, Transaction, WorkingType,
+    AccountBalance, AccountInformation, AdlQuantile, CanceledOrder, ChangeLeverageResponse, CommissionRate,
+    CountdownCancelAll, Income, IncomeType, MarginType, MultiAssetsMode, Order, OrderType, Position,
+    PositionMarginChange, PositionMarginResponse, PositionMarginType, PositionSide, Transaction, UserTrade,
+    WorkingType,
 };
-use crate::account::OrderCancellation;
+use crate::account::{OrderCancellation, OrderStatusRequest, OrdersQuery};
 use crate::client::Client;
 use crate::errors::*;
-use crate::rest_model::{OrderSide, TimeInForce};
+use crate::rest_model::{Number, OrderSide, Success, TimeInForce};
 use crate::rest_model::{PairAndWindowQuery, PairQuery};
 use crate::util::*;
 use serde::Serializer;
@@ -50,19 +52,131 @@ pub struct OrderRequest {
     pub order_type: OrderType,
     pub time_in_force: Option<TimeInForce>,
     #[serde(rename = "quantity")]
-    pub quantity: Option<f64>,
+    pub quantity: Option<Number>,
     pub reduce_only: Option<bool>,
-    pub price: Option<f64>,
-    pub stop_price: Option<f64>,
+    pub price: Option<Number>,
+    pub stop_price: Option<Number>,
     pub close_position: Option<bool>,
-    pub activation_price: Option<f64>,
-    pub callback_rate: Option<f64>,
+    pub activation_price: Option<Number>,
+    pub callback_rate: Option<Number>,
     pub working_type: Option<WorkingType>,
     #[serde(serialize_with = "serialize_opt_as_uppercase")]
     pub price_protect: Option<bool>,
     pub new_client_order_id: Option<String>,
 }
 
+/// Modify the price and quantity of an open limit order
+/// either order_id or orig_client_order_id must be set
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ModifyOrderRequest {
+    pub symbol: String,
+    pub order_id: Option<u64>,
+    pub orig_client_order_id: Option<String>,
+    pub side: OrderSide,
+    pub quantity: Number,
+    pub price: Number,
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UserTradesQuery {
+    pub symbol: String,
+    /// Can only be used with symbol
+    pub order_id: Option<u64>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Trade id to fetch from, the most recent trades are returned by default
+    pub from_id: Option<u64>,
+    /// Default 500 max 1000
+    pub limit: Option<u16>,
+}
+
+/// Maximum number of orders of [`FuturesAccount::place_batch_orders`]
+pub const MAX_BATCH_ORDERS: usize = 5;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BatchOrdersRequest {
+    batch_orders: String,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BatchOrderResult {
+    Order(Box<Transaction>),
+    Error(BinanceContentError),
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CountdownCancelAllRequest {
+    symbol: String,
+    countdown_time: u64,
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionMarginHistoryQuery {
+    pub symbol: String,
+    #[serde(rename = "type")]
+    pub margin_type: Option<PositionMarginType>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Default 500
+    pub limit: Option<u16>,
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct IncomeQuery {
+    pub symbol: Option<String>,
+    pub income_type: Option<IncomeType>,
+    /// The last 7 days are returned when neither start_time or end_time are set
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub page: Option<u32>,
+    /// Default 100 max 1000
+    pub limit: Option<u16>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ChangeMarginTypeRequest {
+    symbol: String,
+    margin_type: &'static str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PositionMarginRequest {
+    symbol: String,
+    position_side: Option<PositionSide>,
+    amount: Number,
+    #[serde(rename = "type")]
+    margin_type: PositionMarginType,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ChangeMultiAssetsModeRequest {
+    #[serde(serialize_with = "serialize_as_str")]
+    multi_assets_margin: bool,
+}
+
+#[derive(Serialize)]
+struct OptionalPairQuery {
+    symbol: Option<String>,
+}
+
+/// The ADL quantile of a single symbol is not wrapped in a list
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum AdlQuantiles {
+    One(AdlQuantile),
+    Many(Vec<AdlQuantile>),
+}
+
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 struct ChangePositionModeRequest {
@@ -77,16 +191,65 @@ impl FuturesAccount {
             .await
     }
 
+    /// Place up to 5 orders at once, each order succeeds or fails on its own
+    pub async fn place_batch_orders(&self, orders: Vec<OrderRequest>) -> Result<Vec<Result<Transaction>>> {
+        if orders.len() > MAX_BATCH_ORDERS {
+            return Err(Error::InvalidOrderError {
+                msg: format!("At most {MAX_BATCH_ORDERS} orders can be placed in a batch"),
+            });
+        }
+        // the orders are sent as a json list of the same string parameters as single orders
+        let orders = orders
+            .iter()
+            .map(|order| Ok(qs::from_str::<BTreeMap<String, String>>(&qs::to_string(order)?)?))
+            .collect::<Result<Vec<_>>>()?;
+        let request = BatchOrdersRequest {
+            batch_orders: serde_json::to_string(&orders)?,
+        };
+        let results: Vec<BatchOrderResult> = self
+            .client
+            .post_signed_p("/fapi/v1/batchOrders", request, self.recv_window)
+            .await?;
+        Ok(results
+            .into_iter()
+            .map(|result| match result {
+                BatchOrderResult::Order(transaction) => Ok(*transaction),
+                BatchOrderResult::Error(response) => Err(Error::BinanceError { response }),
+            })
+            .collect())
+    }
+
+    /// Modify the price and quantity of an open limit order, keeping its place in the queue
+    /// when only the quantity decreases
+    pub async fn modify_order(&self, order: ModifyOrderRequest) -> Result<Order> {
+        self.client
+            .put_signed_p("/fapi/v1/order", order, self.recv_window)
+            .await
+    }
+
+    pub async fn order_status(&self, osr: OrderStatusRequest) -> Result<Order> {
+        let recv_window = osr.recv_window.unwrap_or(self.recv_window);
+        let request = build_signed_request_p(osr, recv_window)?;
+        self.client.get_signed("/fapi/v1/order", &request).await
+    }
+
+    /// All orders of a symbol, canceled or expired orders without fills are only kept for 3 days
+    pub async fn get_all_orders(&self, query: OrdersQuery) -> Result<Vec<Order>> {
+        let recv_window = query.recv_window.unwrap_or(self.recv_window);
+        let request = build_signed_request_p(query, recv_window)?;
+        self.client.get_signed("/fapi/v1/allOrders", &request).await
+    }
+
     pub async fn get_open_orders(&self, symbol: impl Into<String>) -> Result<Vec<Order>> {
-        let payload = build_signed_request_p([("symbol", symbol.into())], self.recv_window)?;
+        let payload = build_signed_request([("symbol", symbol.into())], self.recv_window)?;
         self.client.get_signed("/fapi/v1/openOrders", &payload).await
     }
 
     pub async fn limit_buy(
         &self,
         symbol: impl Into<String>,
-        qty: impl Into<f64>,
-        price: f64,
+        qty: impl Into<Number>,
+        price: Number,
         time_in_force: TimeInForce,
     ) -> Result<Transaction> {
         let order = OrderRequest {
@@ -112,8 +275,8 @@ impl FuturesAccount {
     pub async fn limit_sell(
         &self,
         symbol: impl Into<String>,
-        qty: impl Into<f64>,
-        price: f64,
+        qty: impl Into<Number>,
+        price: Number,
         time_in_force: TimeInForce,
     ) -> Result<Transaction> {
         let order = OrderRequest {
@@ -140,7 +303,7 @@ impl FuturesAccount {
     pub async fn market_buy<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let order = OrderRequest {
             symbol: symbol.into(),
@@ -166,7 +329,7 @@ impl FuturesAccount {
     pub async fn market_sell<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let order: OrderRequest = OrderRequest {
             symbol: symbol.into(),
@@ -258,4 +421,131 @@ impl FuturesAccount {
             .await?;
         Ok(())
     }
+
+    /// Cancel all open orders of a symbol once `countdown_time` milliseconds have passed
+    /// without another call, a countdown of 0 stops the timer
+    pub async fn auto_cancel_all_open_orders<S>(&self, symbol: S, countdown_time: u64) -> Result<CountdownCancelAll>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .post_signed_p(
+                "/fapi/v1/countdownCancelAll",
+                CountdownCancelAllRequest {
+                    symbol: symbol.into(),
+                    countdown_time,
+                },
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Trades of the account for a symbol
+    pub async fn trade_history(&self, query: UserTradesQuery) -> Result<Vec<UserTrade>> {
+        self.client
+            .get_signed_p("/fapi/v1/userTrades", Some(query), self.recv_window)
+            .await
+    }
+
+    /// Switch a symbol between isolated and cross margin, fails with open orders or positions
+    pub async fn change_margin_type<S>(&self, symbol: S, margin_type: MarginType) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let request = ChangeMarginTypeRequest {
+            symbol: symbol.into(),
+            margin_type: match margin_type {
+                MarginType::Isolated => "ISOLATED",
+                MarginType::Cross => "CROSSED",
+            },
+        };
+        let _: Success = self
+            .client
+            .post_signed_p("/fapi/v1/marginType", request, self.recv_window)
+            .await?;
+        Ok(())
+    }
+
+    /// Add margin to or remove margin from an isolated position,
+    /// position_side is only required in hedge mode
+    pub async fn modify_position_margin<S>(
+        &self,
+        symbol: S,
+        position_side: Option<PositionSide>,
+        amount: Number,
+        margin_type: PositionMarginType,
+    ) -> Result<PositionMarginResponse>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .post_signed_p(
+                "/fapi/v1/positionMargin",
+                PositionMarginRequest {
+                    symbol: symbol.into(),
+                    position_side,
+                    amount,
+                    margin_type,
+                },
+                self.recv_window,
+            )
+            .await
+    }
+
+    pub async fn position_margin_history(&self, query: PositionMarginHistoryQuery) -> Result<Vec<PositionMarginChange>> {
+        self.client
+            .get_signed_p("/fapi/v1/positionMargin/history", Some(query), self.recv_window)
+            .await
+    }
+
+    /// Realized pnl, funding fees, commissions, transfers and other incomes of the account
+    pub async fn income_history(&self, query: IncomeQuery) -> Result<Vec<Income>> {
+        self.client
+            .get_signed_p("/fapi/v1/income", Some(query), self.recv_window)
+            .await
+    }
+
+    pub async fn commission_rate<S>(&self, symbol: S) -> Result<CommissionRate>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_signed_p(
+                "/fapi/v1/commissionRate",
+                Some(PairQuery { symbol: symbol.into() }),
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Enable or disable the multi-assets mode, where margin is shared between assets, on every symbol
+    pub async fn change_multi_assets_mode(&self, multi_assets_margin: bool) -> Result<()> {
+        let _: Success = self
+            .client
+            .post_signed_p(
+                "/fapi/v1/multiAssetsMargin",
+                ChangeMultiAssetsModeRequest { multi_assets_margin },
+                self.recv_window,
+            )
+            .await?;
+        Ok(())
+    }
+
+    pub async fn multi_assets_mode(&self) -> Result<MultiAssetsMode> {
+        self.client
+            .get_signed_p("/fapi/v1/multiAssetsMargin", None::<()>, self.recv_window)
+            .await
+    }
+
+    /// ADL quantiles of the positions of a symbol, or of every symbol with a position
+    pub async fn adl_quantile(&self, symbol: Option<String>) -> Result<Vec<AdlQuantile>> {
+        let quantiles: AdlQuantiles = self
+            .client
+            .get_signed_p("/fapi/v1/adlQuantile", Some(OptionalPairQuery { symbol }), self.recv_window)
+            .await?;
+        Ok(match quantiles {
+            AdlQuantiles::One(quantile) => vec![quantile],
+            AdlQuantiles::Many(quantiles) => quantiles,
+        })
+    }
 }
@@ -20,6 +20,12 @@ impl FuturesGeneral {
         self.client.get_p("/fapi/v1/time", None).await
     }
 
+    // Measure the offset between the server clock and the local clock
+    // and apply it to signed requests, returns the offset in milliseconds
+    pub async fn sync_time(&self) -> Result<i64> {
+        self.client.sync_time("/fapi/v1/time").await
+    }
+
     // Obtain exchange information
     // - Current exchange trading rules and symbol information
     pub async fn exchange_info(&self) -> Result<ExchangeInformation> {
@@ -1,8 +1,10 @@
 pub mod account;
 pub mod general;
 pub mod market;
+pub mod orderbook;
 pub mod rest_model;
 pub mod ws_model;
 pub mod websockets;
 pub mod userstream;
+pub mod ws_api;
 
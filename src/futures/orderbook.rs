@@ -0,0 +1,139 @@
+use crate::errors::*;
+use crate::futures::market::FuturesMarket;
+use crate::futures::rest_model::{Asks, Bids};
+use crate::futures::ws_model::FutureDepthOrderBookEvent;
+use crate::orderbook::{DepthSynchronizer, DepthUpdate, LocalOrderBook};
+use crate::rest_model::OrderBook;
+
+impl DepthUpdate for FutureDepthOrderBookEvent {
+    fn first_update_id(&self) -> u64 {
+        self.first_update_id
+    }
+
+    fn final_update_id(&self) -> u64 {
+        self.final_update_id
+    }
+
+    fn bids(&self) -> &[Bids] {
+        &self.bids
+    }
+
+    fn asks(&self) -> &[Asks] {
+        &self.asks
+    }
+
+    fn is_stale(&self, last_update_id: u64) -> bool {
+        self.final_update_id < last_update_id
+    }
+
+    /// The first event must contain the snapshot's update id, the following ones must chain with `pu`
+    fn follows(&self, last_update_id: u64, first: bool) -> bool {
+        if first {
+            self.first_update_id <= last_update_id && self.final_update_id >= last_update_id
+        } else {
+            self.final_update_id_last_stream == last_update_id
+        }
+    }
+}
+
+/// Maintains a local order book for a futures symbol from a diff depth stream,
+/// see [`crate::orderbook::OrderBookManager`]
+pub struct FuturesOrderBookManager {
+    market: FuturesMarket,
+    symbol: String,
+    limit: u16,
+    sync: DepthSynchronizer<FutureDepthOrderBookEvent>,
+}
+
+impl FuturesOrderBookManager {
+    /// # Arguments
+    ///
+    /// * `market`: used to fetch depth snapshots
+    /// * `symbol`: the market symbol
+    /// * `limit`: depth of the snapshot, 1000 at most
+    pub fn new<S: Into<String>>(market: FuturesMarket, symbol: S, limit: u16) -> Self {
+        FuturesOrderBookManager {
+            market,
+            symbol: symbol.into(),
+            limit,
+            sync: DepthSynchronizer::new(),
+        }
+    }
+
+    /// The local book, `None` until the snapshot and the diff stream are in sync
+    pub fn book(&self) -> Option<&LocalOrderBook> {
+        self.sync.book()
+    }
+
+    /// Apply a diff event, fetching a new snapshot when the book is not in sync
+    pub async fn update(&mut self, event: FutureDepthOrderBookEvent) -> Result<()> {
+        if self.sync.update(event) {
+            let snapshot = self.market.get_custom_depth(&self.symbol, self.limit).await?;
+            self.sync.load_snapshot(&OrderBook {
+                last_update_id: snapshot.last_update_id,
+                bids: snapshot.bids,
+                asks: snapshot.asks,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::orderbook::DepthSynchronizer;
+    use crate::util::num;
+
+    fn event(
+        first_update_id: u64,
+        final_update_id: u64,
+        previous: u64,
+        bids: &[(f64, f64)],
+    ) -> FutureDepthOrderBookEvent {
+        FutureDepthOrderBookEvent {
+            event_time: 0,
+            transaction_time: 0,
+            symbol: "BTCUSDT".to_string(),
+            first_update_id,
+            final_update_id,
+            final_update_id_last_stream: previous,
+            bids: bids
+                .iter()
+                .map(|(price, qty)| Bids {
+                    price: num(*price),
+                    qty: num(*qty),
+                })
+                .collect(),
+            asks: vec![],
+        }
+    }
+
+    #[test]
+    fn depth_sync() {
+        let mut sync = DepthSynchronizer::new();
+        assert!(sync.update(event(95, 100, 94, &[(99.0, 5.0)])));
+        assert!(sync.update(event(101, 110, 100, &[(99.5, 1.0)])));
+        let snapshot = OrderBook {
+            last_update_id: 105,
+            bids: vec![Bids {
+                price: num(99.0),
+                qty: num(1.0),
+            }],
+            asks: vec![],
+        };
+        // the first event must contain the snapshot's update id
+        assert!(sync.load_snapshot(&snapshot));
+        let book = sync.book().unwrap();
+        assert_eq!(book.last_update_id, 110);
+        assert_eq!(book.best_bid().unwrap().price, num(99.5));
+        // following events chain on `pu` rather than on consecutive ids
+        assert!(!sync.update(event(115, 120, 110, &[(99.5, 0.0)])));
+        assert_eq!(sync.book().unwrap().last_update_id, 120);
+        assert_eq!(sync.book().unwrap().best_bid().unwrap().price, num(99.0));
+        assert!(!sync.update(event(119, 119, 118, &[])));
+        // `pu` not matching the last update id is a gap
+        assert!(sync.update(event(125, 130, 121, &[])));
+        assert!(sync.book().is_none());
+    }
+}
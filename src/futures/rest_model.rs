@@ -2,8 +2,8 @@ use serde::{Deserialize, Serialize};
 
 use crate::rest_model::{string_or_bool, string_or_float, string_or_float_opt, string_or_u64};
 pub use crate::rest_model::{
-    Asks, Bids, BookTickers, KlineSummaries, KlineSummary, OrderSide, OrderStatus, RateLimit, ServerTime, SymbolPrice,
-    SymbolStatus, Tickers, TimeInForce, Success
+    Asks, Bids, BookTickers, KlineSummaries, KlineSummary, Number, OrderSide, OrderStatus, RateLimit,
+    SelfTradePreventionMode, ServerTime, SymbolPrice, SymbolStatus, Tickers, TimeInForce, Success
 };
 
 
@@ -31,7 +31,7 @@ pub struct AssetDetail {
     pub asset: String,
     pub margin_available: bool,
     #[serde(with = "string_or_float")]
-    pub auto_asset_exchange: f64,
+    pub auto_asset_exchange: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -44,9 +44,9 @@ pub struct Symbol {
     pub onboard_date: u64,
     pub status: SymbolStatus,
     #[serde(with = "string_or_float")]
-    pub maint_margin_percent: f64,
+    pub maint_margin_percent: Number,
     #[serde(with = "string_or_float")]
-    pub required_margin_percent: f64,
+    pub required_margin_percent: Number,
     pub base_asset: String,
     pub quote_asset: String,
     pub price_precision: u16,
@@ -57,7 +57,7 @@ pub struct Symbol {
     pub underlying_sub_type: Vec<String>,
     pub settle_plan: u64,
     #[serde(with = "string_or_float")]
-    pub trigger_protect: f64,
+    pub trigger_protect: Number,
     pub filters: Vec<Filters>,
     pub order_types: Vec<OrderType>,
     pub time_in_force: Vec<TimeInForce>,
@@ -109,6 +109,29 @@ pub enum WorkingType {
     ContractPrice,
 }
 
+/// Price of an order taken from the order book instead of its price
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PriceMatch {
+    None,
+    Opponent,
+    #[serde(rename = "OPPONENT_5")]
+    Opponent5,
+    #[serde(rename = "OPPONENT_10")]
+    Opponent10,
+    #[serde(rename = "OPPONENT_20")]
+    Opponent20,
+    Queue,
+    #[serde(rename = "QUEUE_5")]
+    Queue5,
+    #[serde(rename = "QUEUE_10")]
+    Queue10,
+    #[serde(rename = "QUEUE_20")]
+    Queue20,
+    #[serde(other)]
+    Other,
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum MarginType {
@@ -123,28 +146,31 @@ pub enum Filters {
     #[serde(rename_all = "camelCase")]
     PriceFilter {
         #[serde(with = "string_or_float")]
-        min_price: f64,
+        min_price: Number,
         #[serde(with = "string_or_float")]
-        max_price: f64,
+        max_price: Number,
         #[serde(with = "string_or_float")]
-        tick_size: f64,
+        tick_size: Number,
     },
     #[serde(rename = "LOT_SIZE")]
     #[serde(rename_all = "camelCase")]
     LotSize {
         #[serde(with = "string_or_float")]
-        min_qty: f64,
+        min_qty: Number,
         #[serde(with = "string_or_float")]
-        max_qty: f64,
+        max_qty: Number,
         #[serde(with = "string_or_float")]
-        step_size: f64,
+        step_size: Number,
     },
     #[serde(rename = "MARKET_LOT_SIZE")]
     #[serde(rename_all = "camelCase")]
     MarketLotSize {
-        min_qty: String,
-        max_qty: String,
-        step_size: String,
+        #[serde(with = "string_or_float")]
+        min_qty: Number,
+        #[serde(with = "string_or_float")]
+        max_qty: Number,
+        #[serde(with = "string_or_float")]
+        step_size: Number,
     },
     #[serde(rename = "MAX_NUM_ORDERS")]
     #[serde(rename_all = "camelCase")]
@@ -156,17 +182,17 @@ pub enum Filters {
     #[serde(rename_all = "camelCase")]
     MinNotional {
         #[serde(with = "string_or_float")]
-        notional: f64,
+        notional: Number,
     },
     #[serde(rename = "PERCENT_PRICE")]
     #[serde(rename_all = "camelCase")]
     PercentPrice {
         #[serde(with = "string_or_float")]
-        multiplier_up: f64,
+        multiplier_up: Number,
         #[serde(with = "string_or_float")]
-        multiplier_down: f64,
+        multiplier_down: Number,
         #[serde(with = "string_or_float")]
-        multiplier_decimal: f64,
+        multiplier_decimal: Number,
     },
     #[serde(other)]
     Others,
@@ -203,19 +229,19 @@ pub struct PriceStats {
     pub price_change_percent: String,
     pub weighted_avg_price: String,
     #[serde(with = "string_or_float")]
-    pub last_price: f64,
+    pub last_price: Number,
     #[serde(with = "string_or_float")]
-    pub open_price: f64,
+    pub open_price: Number,
     #[serde(with = "string_or_float")]
-    pub high_price: f64,
+    pub high_price: Number,
     #[serde(with = "string_or_float")]
-    pub low_price: f64,
+    pub low_price: Number,
     #[serde(with = "string_or_float")]
-    pub volume: f64,
+    pub volume: Number,
     #[serde(with = "string_or_float")]
-    pub quote_volume: f64,
+    pub quote_volume: Number,
     #[serde(with = "string_or_float")]
-    pub last_qty: f64,
+    pub last_qty: Number,
     pub open_time: u64,
     pub close_time: u64,
     pub first_id: u64,
@@ -235,11 +261,11 @@ pub struct Trade {
     pub id: u64,
     pub is_buyer_maker: bool,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
     #[serde(with = "string_or_float")]
-    pub quote_qty: f64,
+    pub quote_qty: Number,
     pub time: u64,
 }
 
@@ -263,9 +289,9 @@ pub struct AggTrade {
     #[serde(rename = "m")]
     pub maker: bool,
     #[serde(rename = "p", with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(rename = "q", with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
 }
 
 // #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -279,16 +305,16 @@ pub struct AggTrade {
 pub struct MarkPrice {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub mark_price: f64,
+    pub mark_price: Number,
     #[serde(with = "string_or_float")]
-    pub index_price: f64,
+    pub index_price: Number,
     #[serde(with = "string_or_float")]
-    pub estimated_settle_price: f64,
+    pub estimated_settle_price: Number,
     #[serde(with = "string_or_float")]
-    pub last_funding_rate: f64,
+    pub last_funding_rate: Number,
     pub next_funding_time: u64,
     #[serde(with = "string_or_float")]
-    pub interest_rate: f64,
+    pub interest_rate: Number,
     pub time: u64,
 }
 
@@ -302,13 +328,13 @@ pub enum LiquidationOrders {
 #[serde(rename_all = "camelCase")]
 pub struct LiquidationOrder {
     #[serde(with = "string_or_float")]
-    pub average_price: f64,
+    pub average_price: Number,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Number,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Number,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     pub side: String,
     pub status: String,
     pub symbol: String,
@@ -321,31 +347,32 @@ pub struct LiquidationOrder {
 #[serde(rename_all = "camelCase")]
 pub struct OpenInterest {
     #[serde(with = "string_or_float")]
-    pub open_interest: f64,
+    pub open_interest: Number,
     pub symbol: String,
+    pub time: Option<u64>,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Order {
     pub client_order_id: String,
     #[serde(with = "string_or_float")]
-    pub cum_quote: f64,
+    pub cum_quote: Number,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Number,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub avg_price: f64,
+    pub avg_price: Number,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Number,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     pub side: OrderSide,
     pub reduce_only: bool,
     pub position_side: PositionSide,
     pub status: OrderStatus,
     #[serde(with = "string_or_float", default = "default_stop_price")]
-    pub stop_price: f64,
+    pub stop_price: Number,
     pub close_position: bool,
     pub symbol: String,
     pub time_in_force: TimeInForce,
@@ -353,12 +380,19 @@ pub struct Order {
     pub order_type: OrderType,
     pub orig_type: OrderType,
     #[serde(with = "string_or_float", default = "default_activation_price")]
-    pub activate_price: f64,
+    pub activate_price: Number,
     #[serde(with = "string_or_float", default = "default_price_rate")]
-    pub price_rate: f64,
+    pub price_rate: Number,
+    /// Not returned when modifying an order
+    #[serde(default)]
+    pub time: u64,
     pub update_time: u64,
     pub working_type: WorkingType,
     pub price_protect: bool,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    pub price_match: Option<PriceMatch>,
+    /// Expiry of GTD orders, 0 otherwise
+    pub good_till_date: Option<u64>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -366,22 +400,24 @@ pub struct Order {
 pub struct Transaction {
     pub client_order_id: String,
     #[serde(with = "string_or_float")]
-    pub cum_qty: f64,
+    pub cum_qty: Number,
     #[serde(with = "string_or_float")]
-    pub cum_quote: f64,
+    pub cum_quote: Number,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Number,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub avg_price: f64,
+    pub avg_price: Number,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: Number,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub price: Number,
     pub reduce_only: bool,
     pub side: OrderSide,
     pub position_side: PositionSide,
     pub status: OrderStatus,
     #[serde(with = "string_or_float")]
-    pub stop_price: f64,
+    pub stop_price: Number,
     pub close_position: bool,
     pub symbol: String,
     pub time_in_force: TimeInForce,
@@ -390,13 +426,17 @@ pub struct Transaction {
     pub orig_type: OrderType,
     #[serde(default)]
     #[serde(with = "string_or_float_opt")]
-    pub activate_price: Option<f64>,
+    pub activate_price: Option<Number>,
     #[serde(default)]
     #[serde(with = "string_or_float_opt")]
-    pub price_rate: Option<f64>,
+    pub price_rate: Option<Number>,
     pub update_time: u64,
     pub working_type: WorkingType,
     price_protect: bool,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    pub price_match: Option<PriceMatch>,
+    /// Expiry of GTD orders, 0 otherwise
+    pub good_till_date: Option<u64>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -404,23 +444,23 @@ pub struct Transaction {
 pub struct CanceledOrder {
     pub client_order_id: String,
     #[serde(with = "string_or_float")]
-    pub cum_qty: f64,
+    pub cum_qty: Number,
     #[serde(with = "string_or_float")]
-    pub cum_quote: f64,
+    pub cum_quote: Number,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Number,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Number,
     pub orig_type: String,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     pub reduce_only: bool,
     pub side: String,
     pub position_side: String,
     pub status: String,
     #[serde(with = "string_or_float")]
-    pub stop_price: f64,
+    pub stop_price: Number,
     pub close_position: bool,
     pub symbol: String,
     pub time_in_force: String,
@@ -428,44 +468,83 @@ pub struct CanceledOrder {
     pub type_name: String,
     #[serde(default)]
     #[serde(with = "string_or_float_opt")]
-    pub activate_price: Option<f64>,
+    pub activate_price: Option<Number>,
     #[serde(default)]
     #[serde(with = "string_or_float_opt")]
-    pub price_rate: Option<f64>,
+    pub price_rate: Option<Number>,
     pub update_time: u64,
     pub working_type: String,
     price_protect: bool,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    pub price_match: Option<PriceMatch>,
+    /// Expiry of GTD orders, 0 otherwise
+    pub good_till_date: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserTrade {
+    pub id: u64,
+    pub order_id: u64,
+    pub symbol: String,
+    pub side: OrderSide,
+    pub position_side: PositionSide,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    #[serde(with = "string_or_float")]
+    pub qty: Number,
+    #[serde(with = "string_or_float")]
+    pub quote_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub realized_pnl: Number,
+    #[serde(with = "string_or_float")]
+    pub commission: Number,
+    pub commission_asset: String,
+    pub buyer: bool,
+    pub maker: bool,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CountdownCancelAll {
+    pub symbol: String,
+    /// Countdown in milliseconds, 0 when the countdown was cancelled
+    #[serde(with = "string_or_u64")]
+    pub countdown_time: u64,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Position {
     #[serde(with = "string_or_float")]
-    pub entry_price: f64,
+    pub entry_price: Number,
+    #[serde(default, with = "string_or_float_opt")]
+    pub break_even_price: Option<Number>,
     pub margin_type: MarginType,
     #[serde(with = "string_or_bool")]
     pub is_auto_add_margin: bool,
     #[serde(with = "string_or_float")]
-    pub isolated_margin: f64,
+    pub isolated_margin: Number,
     #[serde(with = "string_or_u64")]
     pub leverage: u64,
     #[serde(with = "string_or_float")]
-    pub liquidation_price: f64,
+    pub liquidation_price: Number,
     #[serde(with = "string_or_float")]
-    pub mark_price: f64,
+    pub mark_price: Number,
     #[serde(with = "string_or_float")]
-    pub max_notional_value: f64,
+    pub max_notional_value: Number,
     #[serde(with = "string_or_float", rename = "positionAmt")]
-    pub position_amount: f64,
+    pub position_amount: Number,
     pub symbol: String,
     #[serde(with = "string_or_float", rename = "unRealizedProfit")]
-    pub unrealized_profit: f64,
+    pub unrealized_profit: Number,
     pub position_side: PositionSide,
     pub update_time: u64,
     #[serde(with = "string_or_float")]
-    pub notional: f64,
+    pub notional: Number,
     #[serde(with = "string_or_float")]
-    pub isolated_wallet: f64,
+    pub isolated_wallet: Number,
 }
 
 // https://binance-docs.github.io/apidocs/futures/en/#account-information-v2-user_data
@@ -475,29 +554,31 @@ pub struct Position {
 pub struct AccountPosition {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub initial_margin: f64,
+    pub initial_margin: Number,
     #[serde(with = "string_or_float", rename = "maintMargin")]
-    pub maintenance_margin: f64,
+    pub maintenance_margin: Number,
     #[serde(with = "string_or_float")]
-    pub unrealized_profit: f64,
+    pub unrealized_profit: Number,
     #[serde(with = "string_or_float")]
-    pub position_initial_margin: f64,
+    pub position_initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub open_order_initial_margin: f64,
+    pub open_order_initial_margin: Number,
     #[serde(with = "string_or_u64")]
     pub leverage: u64,
     pub isolated: bool,
     #[serde(with = "string_or_float")]
-    pub entry_price: f64,
+    pub entry_price: Number,
+    #[serde(default, with = "string_or_float_opt")]
+    pub break_even_price: Option<Number>,
     #[serde(with = "string_or_float")]
-    pub max_notional: f64,
+    pub max_notional: Number,
     #[serde(with = "string_or_float")]
-    pub bid_notional: f64,
+    pub bid_notional: Number,
     #[serde(with = "string_or_float")]
-    pub ask_notional: f64,
+    pub ask_notional: Number,
     pub position_side: PositionSide,
     #[serde(with = "string_or_float", rename = "positionAmt")]
-    pub position_amount: f64,
+    pub position_amount: Number,
     pub update_time: u64,
 }
 
@@ -506,27 +587,27 @@ pub struct AccountPosition {
 pub struct AccountAsset {
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub wallet_balance: f64,
+    pub wallet_balance: Number,
     #[serde(with = "string_or_float")]
-    pub unrealized_profit: f64,
+    pub unrealized_profit: Number,
     #[serde(with = "string_or_float")]
-    pub margin_balance: f64,
+    pub margin_balance: Number,
     #[serde(with = "string_or_float")]
-    pub maint_margin: f64,
+    pub maint_margin: Number,
     #[serde(with = "string_or_float")]
-    pub initial_margin: f64,
+    pub initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub position_initial_margin: f64,
+    pub position_initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub open_order_initial_margin: f64,
+    pub open_order_initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub cross_wallet_balance: f64,
+    pub cross_wallet_balance: Number,
     #[serde(with = "string_or_float", rename = "crossUnPnl")]
-    pub cross_unrealized_pnl: f64,
+    pub cross_unrealized_pnl: Number,
     #[serde(with = "string_or_float")]
-    pub available_balance: f64,
+    pub available_balance: Number,
     #[serde(with = "string_or_float")]
-    pub max_withdraw_amount: f64,
+    pub max_withdraw_amount: Number,
     pub margin_available: bool,
     pub update_time: u64,
 }
@@ -541,29 +622,31 @@ pub struct AccountInformation {
     pub update_time: u64,
     pub multi_assets_margin: bool,
     #[serde(with = "string_or_float")]
-    pub total_initial_margin: f64,
+    pub total_initial_margin: Number,
     #[serde(with = "string_or_float", rename = "totalMaintMargin")]
-    pub total_maintenance_margin: f64,
+    pub total_maintenance_margin: Number,
     #[serde(with = "string_or_float")]
-    pub total_wallet_balance: f64,
+    pub total_wallet_balance: Number,
     #[serde(with = "string_or_float")]
-    pub total_unrealized_profit: f64,
+    pub total_unrealized_profit: Number,
     #[serde(with = "string_or_float")]
-    pub total_margin_balance: f64,
+    pub total_margin_balance: Number,
     #[serde(with = "string_or_float")]
-    pub total_position_initial_margin: f64,
+    pub total_position_initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub total_open_order_initial_margin: f64,
+    pub total_open_order_initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub total_cross_wallet_balance: f64,
+    pub total_cross_wallet_balance: Number,
     #[serde(with = "string_or_float", rename = "totalCrossUnPnl")]
-    pub total_cross_unrealized_pnl: f64,
+    pub total_cross_unrealized_pnl: Number,
     #[serde(with = "string_or_float")]
-    pub available_balance: f64,
+    pub available_balance: Number,
     #[serde(with = "string_or_float")]
-    pub max_withdraw_amount: f64,
+    pub max_withdraw_amount: Number,
     pub assets: Vec<AccountAsset>,
     pub positions: Vec<AccountPosition>,
+    /// -1 when the account is not part of a trade group
+    pub trade_group_id: Option<i64>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -572,15 +655,15 @@ pub struct AccountBalance {
     pub account_alias: String,
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub balance: f64,
+    pub balance: Number,
     #[serde(with = "string_or_float")]
-    pub cross_wallet_balance: f64,
+    pub cross_wallet_balance: Number,
     #[serde(with = "string_or_float", rename = "crossUnPnl")]
-    pub cross_unrealized_pnl: f64,
+    pub cross_unrealized_pnl: Number,
     #[serde(with = "string_or_float")]
-    pub available_balance: f64,
+    pub available_balance: Number,
     #[serde(with = "string_or_float")]
-    pub max_withdraw_amount: f64,
+    pub max_withdraw_amount: Number,
     pub margin_available: bool,
     pub update_time: u64,
 }
@@ -590,18 +673,156 @@ pub struct AccountBalance {
 pub struct ChangeLeverageResponse {
     pub leverage: u8,
     #[serde(with = "string_or_float")]
-    pub max_notional_value: f64,
+    pub max_notional_value: Number,
+    pub symbol: String,
+}
+
+/// Direction of an isolated position margin change
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(into = "u8", try_from = "u8")]
+pub enum PositionMarginType {
+    Add,
+    Reduce,
+}
+
+impl From<PositionMarginType> for u8 {
+    fn from(margin_type: PositionMarginType) -> u8 {
+        match margin_type {
+            PositionMarginType::Add => 1,
+            PositionMarginType::Reduce => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for PositionMarginType {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(PositionMarginType::Add),
+            2 => Ok(PositionMarginType::Reduce),
+            _ => Err(format!("unknown position margin type {value}")),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionMarginResponse {
+    #[serde(with = "string_or_float")]
+    pub amount: Number,
+    #[serde(rename = "type")]
+    pub margin_type: PositionMarginType,
+    pub code: Option<i32>,
+    pub msg: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionMarginChange {
+    pub symbol: String,
+    #[serde(rename = "type")]
+    pub margin_type: PositionMarginType,
+    pub delta_type: String,
+    #[serde(with = "string_or_float")]
+    pub amount: Number,
+    pub asset: String,
+    pub time: u64,
+    pub position_side: PositionSide,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum IncomeType {
+    Transfer,
+    WelcomeBonus,
+    RealizedPnl,
+    FundingFee,
+    Commission,
+    InsuranceClear,
+    ReferralKickback,
+    CommissionRebate,
+    ApiRebate,
+    ContestReward,
+    CrossCollateralTransfer,
+    OptionsPremiumFee,
+    OptionsSettleProfit,
+    InternalTransfer,
+    AutoExchange,
+    #[serde(rename = "DELIVERED_SETTELMENT")]
+    DeliveredSettlement,
+    CoinSwapDeposit,
+    CoinSwapWithdraw,
+    PositionLimitIncreaseFee,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Income {
+    /// Empty for incomes not related to a symbol
+    pub symbol: String,
+    pub income_type: IncomeType,
+    #[serde(with = "string_or_float")]
+    pub income: Number,
+    pub asset: String,
+    pub info: String,
+    pub time: u64,
+    #[serde(with = "string_or_u64")]
+    pub tran_id: u64,
+    /// Empty for incomes not related to a trade
+    pub trade_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CommissionRate {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub maker_commission_rate: Number,
+    #[serde(with = "string_or_float")]
+    pub taker_commission_rate: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MultiAssetsMode {
+    pub multi_assets_margin: bool,
+}
+
+/// Auto-deleveraging queue position of a symbol's positions, from 0 to 4 where 4 is the
+/// most likely to be deleveraged
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AdlQuantile {
     pub symbol: String,
+    pub adl_quantile: AdlQuantileValues,
 }
 
-fn default_stop_price() -> f64 {
-    0.0
+/// One-way mode positions only set `both`, hedge mode positions set `long`, `short` and
+/// `hedge`, the latter being a placeholder to ignore
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub struct AdlQuantileValues {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub long: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub short: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub both: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hedge: Option<u8>,
+}
+
+fn default_stop_price() -> Number {
+    Number::default()
 }
-fn default_activation_price() -> f64 {
-    0.0
+fn default_activation_price() -> Number {
+    Number::default()
 }
-fn default_price_rate() -> f64 {
-    0.0
+fn default_price_rate() -> Number {
+    Number::default()
 }
 
 #[derive(Serialize)]
@@ -627,55 +848,62 @@ impl HistoryQuery {
     }
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct FundingRate {
     pub symbol: String,
     pub funding_time: u64,
     #[serde(with = "string_or_float")]
-    pub funding_rate: f64,
+    pub funding_rate: Number,
+    #[serde(default, with = "string_or_float_opt")]
+    pub mark_price: Option<Number>,
 }
 
 pub static PERIODS: &[&str] = &["5m", "15m", "30m", "1h", "2h", "4h", "6h", "12h", "1d"];
 
-#[derive(Deserialize)]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OpenInterestHistory {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub sum_open_interest: f64,
+    pub sum_open_interest: Number,
     #[serde(with = "string_or_float")]
-    pub sum_open_interest_value: f64,
+    pub sum_open_interest_value: Number,
+    #[serde(with = "string_or_u64")]
     pub timestamp: u64,
 }
 
-#[derive(Deserialize)]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LongShortRatio {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub long_account: f64,
+    pub long_account: Number,
     #[serde(with = "string_or_float")]
-    pub long_short_ratio: f64,
+    pub long_short_ratio: Number,
     #[serde(with = "string_or_float")]
-    pub short_account: f64,
+    pub short_account: Number,
+    #[serde(with = "string_or_u64")]
     pub timestamp: u64,
 }
 
-#[derive(Deserialize)]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LeverageBracket {
     pub bracket: u8,
     pub initial_leverage: u8,
     pub notional_cap: u64,
     pub notional_floor: u64,
-    pub maint_margin_ratio: f64,
+    pub maint_margin_ratio: Number,
     pub cum: u64,
 }
 
-#[derive(Deserialize)]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SymbolBrackets {
     pub symbol: String,
+    /// Multiplier of the notional limits when set by the user
+    #[serde(default, with = "string_or_float_opt")]
+    pub notional_coef: Option<Number>,
     pub brackets: Vec<LeverageBracket>,
 }
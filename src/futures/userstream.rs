@@ -1,6 +1,8 @@
 use crate::client::*;
 use crate::errors::*;
 use crate::futures::rest_model::*;
+use crate::futures::ws_model::FuturesWebsocketEvent;
+use crate::userstream::UserDataEvent;
 
 static FUTURE_USER_DATA_STREAM: &str = "/fapi/v1/listenKey";
 
@@ -22,3 +24,9 @@ impl FuturesUserStream {
         self.client.delete(FUTURE_USER_DATA_STREAM, listen_key, None).await
     }
 }
+
+impl UserDataEvent for FuturesWebsocketEvent {
+    fn is_listen_key_expired(&self) -> bool {
+        matches!(self, FuturesWebsocketEvent::ListenKeyExpired(_))
+    }
+}
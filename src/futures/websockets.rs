@@ -1,18 +1,8 @@
-use std::sync::atomic::{AtomicBool, Ordering};
-
-use futures::StreamExt;
-use serde_json::from_str;
-use tokio::net::TcpStream;
-use tokio_tungstenite::tungstenite::handshake::client::Response;
-use tokio_tungstenite::tungstenite::Message;
-use tokio_tungstenite::WebSocketStream;
-use tokio_tungstenite::{connect_async, MaybeTlsStream};
-use url::Url;
-
-use tracing::debug;
+use std::ops::{Deref, DerefMut};
 
 use crate::config::Config;
 use crate::errors::*;
+use crate::websockets::{combined_url, connect_split, endpoint_url, ControlSink, EventStream, WebSockets};
 
 pub static STREAM_ENDPOINT: &str = "stream";
 pub static WS_ENDPOINT: &str = "ws";
@@ -70,16 +60,26 @@ pub fn diff_book_depth_stream(symbol: &str, update_speed: u16) -> String {
     format!("{symbol}@depth@{update_speed}ms")
 }
 
-fn combined_stream(streams: Vec<String>) -> String {
-    streams.join("/")
+/// Connect to a futures websocket endpoint and return its events as a [`futures::Stream`]
+pub async fn connect_stream<WE: serde::de::DeserializeOwned>(
+    conf: &Config,
+    endpoint: &str,
+) -> Result<(ControlSink, EventStream<WE>)> {
+    connect_split(endpoint_url(&conf.futures_ws_endpoint, endpoint)?, conf).await
 }
 
-pub struct FuturesWebSockets<'a, WE> {
-    pub socket: Option<(WebSocketStream<MaybeTlsStream<TcpStream>>, Response)>,
-    handler: Box<dyn FnMut(WE) -> Result<()> + 'a + Send>,
-    conf: Config,
+/// Connect to multiple futures websocket endpoints and return their events as a [`futures::Stream`]
+/// N.B: WE has to be CombinedStreamEvent
+pub async fn connect_multiple_streams<WE: serde::de::DeserializeOwned>(
+    conf: &Config,
+    endpoints: Vec<String>,
+) -> Result<(ControlSink, EventStream<WE>)> {
+    connect_split(combined_url(&conf.futures_ws_endpoint, endpoints)?, conf).await
 }
 
+/// [`WebSockets`] connected to the USD-M futures streams
+pub struct FuturesWebSockets<'a, WE>(WebSockets<'a, WE>);
+
 impl<'a, WE: serde::de::DeserializeOwned> FuturesWebSockets<'a, WE> {
     /// New websocket holder with default configuration
     /// # Examples
@@ -98,90 +98,26 @@ impl<'a, WE: serde::de::DeserializeOwned> FuturesWebSockets<'a, WE> {
     where
         Callback: FnMut(WE) -> Result<()> + 'a + Send,
     {
-        FuturesWebSockets {
-            socket: None,
-            handler: Box::new(handler),
-            conf,
-        }
+        let ws_endpoint = conf.futures_ws_endpoint.clone();
+        FuturesWebSockets(WebSockets::new_with_options(handler, conf).with_ws_endpoint(ws_endpoint))
     }
 
-    /// Connect to multiple websocket endpoints
-    /// N.B: WE has to be CombinedStreamEvent
-    pub async fn connect_multiple(&mut self, endpoints: Vec<String>) -> Result<()> {
-        let mut url = Url::parse(&self.conf.futures_ws_endpoint)?;
-        url.path_segments_mut()
-            .map_err(|_| Error::UrlParserError(url::ParseError::RelativeUrlWithoutBase))?
-            .push(STREAM_ENDPOINT);
-        url.set_query(Some(&format!("streams={}", combined_stream(endpoints))));
-
-        self.handle_connect(url).await
-    }
-
-    /// Connect to a websocket endpoint
-    pub async fn connect(&mut self, endpoint: &str) -> Result<()> {
-        let wss: String = format!("{}/{}/{}", self.conf.futures_ws_endpoint, WS_ENDPOINT, endpoint);
-        let url = Url::parse(&wss)?;
-
-        self.handle_connect(url).await
-    }
-
-    async fn handle_connect(&mut self, url: Url) -> Result<()> {
-        debug!("{url}");
-        match connect_async(url).await {
-            Ok(answer) => {
-                self.socket = Some(answer);
-                Ok(())
-            }
-            Err(e) => Err(Error::Msg(format!("Error during handshake {e}"))),
-        }
+    /// Connect to another websocket endpoint than the USD-M one
+    pub(crate) fn with_ws_endpoint(self, ws_endpoint: String) -> Self {
+        FuturesWebSockets(self.0.with_ws_endpoint(ws_endpoint))
     }
+}
 
-    /// Disconnect from the endpoint
-    pub async fn disconnect(&mut self) -> Result<()> {
-        if let Some(ref mut socket) = self.socket {
-            socket.0.close(None).await?;
-            Ok(())
-        } else {
-            Err(Error::Msg("Not able to close the connection".to_string()))
-        }
-    }
+impl<'a, WE> Deref for FuturesWebSockets<'a, WE> {
+    type Target = WebSockets<'a, WE>;
 
-    pub fn socket(&self) -> &Option<(WebSocketStream<MaybeTlsStream<TcpStream>>, Response)> {
-        &self.socket
+    fn deref(&self) -> &Self::Target {
+        &self.0
     }
+}
 
-    pub async fn event_loop(&mut self, running: &AtomicBool) -> Result<()> {
-        while running.load(Ordering::Relaxed) {
-            if let Some((ref mut socket, _)) = self.socket {
-                
-                if let Some(Ok(message)) = socket.next().await {
-                    debug!("ws msg received: {message:?}");
-                    match message {
-                        Message::Text(msg) => {
-                            if msg.is_empty() {
-                                return Ok(());
-                            }
-                            match from_str(msg.as_str()) {
-                                Ok(event) => {
-                                    (self.handler)(event)?;
-                                }
-                                Err(e) => {
-                                    return Err(Error::Msg(format!("failed to serde {e:?}")));
-                                }
-                            }
-                        }
-                        Message::Ping(_) | Message::Pong(_) | Message::Binary(_) | Message::Frame(_) => {}
-                        Message::Close(e) => {
-                            return Err(Error::Msg(format!("Disconnected {e:?}")));
-                        }
-                    }
-                } else {
-                    return Err(Error::Msg(format!("Disconnected")));
-                }
-                
-
-            }
-        }
-        Ok(())
+impl<WE> DerefMut for FuturesWebSockets<'_, WE> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
     }
 }
@@ -0,0 +1,60 @@
+use std::collections::BTreeMap;
+
+use super::account::{ModifyOrderRequest, OrderRequest};
+use super::rest_model::{AccountBalance, AccountInformation, CanceledOrder, Order, Position, Transaction};
+use crate::account::{OrderCancellation, OrderStatusRequest};
+use crate::config::Config;
+use crate::errors::*;
+use crate::ws_api::WsApiConnection;
+
+/// USD-M futures trading over the WebSocket API
+#[derive(Clone)]
+pub struct FuturesWsApi {
+    pub connection: WsApiConnection,
+}
+
+impl FuturesWsApi {
+    /// Connect to the futures WebSocket API endpoint of `conf`
+    pub async fn connect(api_key: Option<String>, secret_key: Option<String>, conf: &Config) -> Result<Self> {
+        Ok(FuturesWsApi {
+            connection: WsApiConnection::connect(&conf.futures_ws_api_endpoint, api_key, secret_key, conf).await?,
+        })
+    }
+
+    pub async fn place_order(&self, order: OrderRequest) -> Result<Transaction> {
+        self.connection.signed_request("order.place", order).await
+    }
+
+    /// Modify the price and quantity of an open limit order
+    pub async fn modify_order(&self, order: ModifyOrderRequest) -> Result<Order> {
+        self.connection.signed_request("order.modify", order).await
+    }
+
+    pub async fn order_status(&self, osr: OrderStatusRequest) -> Result<Order> {
+        self.connection.signed_request("order.status", osr).await
+    }
+
+    pub async fn cancel_order(&self, o: OrderCancellation) -> Result<CanceledOrder> {
+        self.connection.signed_request("order.cancel", o).await
+    }
+
+    pub async fn position_information<S>(&self, symbol: S) -> Result<Vec<Position>>
+    where
+        S: Into<String>,
+    {
+        let params = BTreeMap::from([("symbol", symbol.into())]);
+        self.connection.signed_request("v2/account.position", params).await
+    }
+
+    pub async fn account_information(&self) -> Result<AccountInformation> {
+        self.connection
+            .signed_request("v2/account.status", BTreeMap::<String, String>::new())
+            .await
+    }
+
+    pub async fn account_balance(&self) -> Result<Vec<AccountBalance>> {
+        self.connection
+            .signed_request("v2/account.balance", BTreeMap::<String, String>::new())
+            .await
+    }
+}
@@ -1,15 +1,17 @@
 #![allow(unused)]
 
-use crate::rest_model::{string_or_bool, string_or_float, string_or_float_opt, string_or_u64};
+use crate::rest_model::{string_or_bool, string_or_float, string_or_float_opt, string_or_u64, Number};
 
 pub use crate::futures::rest_model::{
-    Asks, Bids, OrderBook, OrderSide, OrderStatus, OrderType, PositionSide, Success, TimeInForce, WorkingType,
+    Asks, Bids, OrderBook, OrderSide, OrderStatus, OrderType, PositionSide, PriceMatch, SelfTradePreventionMode,
+    Success, TimeInForce, WorkingType,
 };
 
 pub use crate::ws_model::{
     AccountPositionUpdate, BalanceUpdate, BookTickerEvent, CombinedStreamEvent, DayTickerEvent,
-    EventBalance, Kline, KlineEvent, MiniDayTickerEvent, OrderListTransaction, OrderListUpdate,
-    OrderUpdate, QueryResult, TradeEvent, TradesEvent, WebsocketEvent, WebsocketEventUntag,
+    EventBalance, Kline, KlineEvent, ListenKeyExpired, MiniDayTickerEvent, OrderListTransaction,
+    OrderListUpdate, OrderUpdate, QueryError, QueryResult, SubscriptionMethod, SubscriptionRequest,
+    TradeEvent, TradesEvent, WebsocketEvent, WebsocketEventUntag,
 };
 
 ///
@@ -83,6 +85,9 @@ pub enum FuturesWebsocketEvent {
     
     #[serde(alias = "accountUpdate")]
     AccountUpdate(Box<AccountUpdate>),
+
+    #[serde(alias = "listenKeyExpired")]
+    ListenKeyExpired(Box<ListenKeyExpired>),
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -93,6 +98,10 @@ pub struct OrderTradeUpdate {
     #[serde(rename = "E")]
     pub event_time: u64,
 
+    /// Account alias, only sent on COIN-M streams
+    #[serde(rename = "i")]
+    pub account_alias: Option<String>,
+
     #[serde(rename = "o")]
     pub order_trade: OrderTradeUpdateInner,
 }
@@ -116,19 +125,19 @@ pub struct OrderTradeUpdateInner {
 
     #[serde(rename = "q")]
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
 
     #[serde(rename = "p")]
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
 
     #[serde(rename = "ap")]
     #[serde(with = "string_or_float")]
-    pub avg_price: f64,
+    pub avg_price: Number,
 
     #[serde(rename = "sp")]
     #[serde(with = "string_or_float")]
-    pub stop_price: f64,
+    pub stop_price: Number,
 
     #[serde(rename = "x")]
     pub execution_type: OrderStatus,
@@ -141,23 +150,27 @@ pub struct OrderTradeUpdateInner {
 
     #[serde(rename = "l")]
     #[serde(with = "string_or_float")]
-    pub qty_last_executed: f64,
+    pub qty_last_executed: Number,
 
     #[serde(rename = "z")]
     #[serde(with = "string_or_float")]
-    pub cumulative_filled_qty: f64,
+    pub cumulative_filled_qty: Number,
 
     #[serde(rename = "L")]
     #[serde(with = "string_or_float")]
-    pub last_executed_price: f64,
+    pub last_executed_price: Number,
 
     #[serde(rename = "n")]
     #[serde(with = "string_or_float")]
-    pub commission: f64,
+    pub commission: Number,
 
     #[serde(rename = "N")]
     pub commission_asset: Option<String>,
 
+    /// Margin asset, only sent on COIN-M streams
+    #[serde(rename = "ma")]
+    pub margin_asset: Option<String>,
+
     #[serde(rename = "T")]
     pub trade_order_time: u64,
 
@@ -166,11 +179,11 @@ pub struct OrderTradeUpdateInner {
 
     #[serde(rename = "b")]
     #[serde(with = "string_or_float")]
-    pub bids_notional: f64,
+    pub bids_notional: Number,
 
     #[serde(rename = "a")]
     #[serde(with = "string_or_float")]
-    pub asks_notional: f64,
+    pub asks_notional: Number,
 
     #[serde(rename = "m")]
     pub is_maker: bool,
@@ -190,26 +203,36 @@ pub struct OrderTradeUpdateInner {
     #[serde(rename = "cp")]
     pub is_push_conditional: bool,
 
-    // #[serde(rename = "AP")]
-    // #[serde(with = "string_or_float")]
-    // pub activation_price: f64,
+    /// Only sent for trailing stop orders
+    #[serde(rename = "AP", default, with = "string_or_float_opt")]
+    pub activation_price: Option<Number>,
 
     #[serde(rename = "rp")]
     #[serde(with = "string_or_float")]
-    pub realized_profit_ignore: f64,
+    pub realized_profit_ignore: Number,
 
-    // #[serde(rename = "cr")]
-    // #[serde(with = "string_or_float")]
-    // pub callback_rate: f64,
+    /// Only sent for trailing stop orders
+    #[serde(rename = "cr", default, with = "string_or_float_opt")]
+    pub callback_rate: Option<Number>,
 
     #[serde(rename = "pP")]
     pub pp_ignore: bool,
 
-    #[serde(rename = "si")]
+    #[serde(rename = "si", default)]
     pub si_ignore: i64,
 
-    #[serde(rename = "ss")]
+    #[serde(rename = "ss", default)]
     pub ss_ignore: i64,
+
+    #[serde(rename = "V")]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+
+    #[serde(rename = "pm")]
+    pub price_match: Option<PriceMatch>,
+
+    /// Expiry of GTD orders, 0 otherwise
+    #[serde(rename = "gtd")]
+    pub good_till_date: Option<u64>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -220,6 +243,10 @@ pub struct AccountUpdate {
     #[serde(rename = "E")]
     pub event_time: u64,
 
+    /// Account alias, only sent on COIN-M streams
+    #[serde(rename = "i")]
+    pub account_alias: Option<String>,
+
     #[serde(rename = "a")]
     pub update_data: AccountUpdateDataInner,
 }
@@ -250,15 +277,15 @@ pub struct AccountUpdateBalancesInner {
 
     #[serde(rename = "wb")]
     #[serde(with = "string_or_float")]
-    pub wallet_balance: f64,
+    pub wallet_balance: Number,
 
     #[serde(rename = "cw")]
     #[serde(with = "string_or_float")]
-    pub cross_wallet_balance: f64,
+    pub cross_wallet_balance: Number,
 
     #[serde(rename = "bc")]
     #[serde(with = "string_or_float")]
-    pub balance_change_ex: f64,
+    pub balance_change_ex: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -268,26 +295,29 @@ pub struct AccountUpdatePositionsInner {
 
     #[serde(rename = "pa")]
     #[serde(with = "string_or_float")]
-    pub position_amount: f64,
+    pub position_amount: Number,
 
     #[serde(rename = "ep")]
     #[serde(with = "string_or_float")]
-    pub early_price: f64,
+    pub early_price: Number,
+
+    #[serde(rename = "bep", default, with = "string_or_float_opt")]
+    pub break_even_price: Option<Number>,
 
     #[serde(rename = "cr")]
     #[serde(with = "string_or_float")]
-    pub accumulated_realized: f64,
+    pub accumulated_realized: Number,
 
     #[serde(rename = "up")]
     #[serde(with = "string_or_float")]
-    pub unrealized_pnl: f64,
+    pub unrealized_pnl: Number,
 
     #[serde(rename = "mt")]
     pub margin_type: String,
 
     #[serde(rename = "iw")]
     #[serde(with = "string_or_float")]
-    pub isolated_wallet: f64,
+    pub isolated_wallet: Number,
 
     #[serde(rename = "ps")]
     pub position_side: PositionSide,
@@ -37,6 +37,19 @@ impl General {
     /// ```
     pub async fn get_server_time(&self) -> Result<ServerTime> { self.client.get("/api/v3/time", None).await }
 
+    /// Measure the offset between the server clock and the local clock and apply it to signed requests,
+    /// returns the offset in milliseconds.
+    /// The offset is kept per API host by the configuration, every handle built from this configuration or its clones
+    /// applies it, whether built with [`crate::api::Binance`] or a [`crate::api::BinanceClient`]
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, general::*, config::*};
+    /// let general: General = Binance::new_with_config(None, None, &Config::testnet());
+    /// let offset = tokio_test::block_on(general.sync_time());
+    /// assert!(offset.is_ok(), "{:?}", offset);
+    /// ```
+    pub async fn sync_time(&self) -> Result<i64> { self.client.sync_time("/api/v3/time").await }
+
     /// Obtain exchange information (rate limits, symbol metadata etc)
     /// # Examples
     /// ```rust
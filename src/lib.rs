@@ -51,6 +51,9 @@
 //!
 //! # Details
 //!
+//! - `api::BinanceClient` hands out handles to every API sharing one connection pool, prefer it to creating
+//!   each handle with `Binance::new_with_config` when using several APIs
+//!
 //! - Credentials are not enforced, you will get authentication errors if you don't provide
 //! credentials and they are required by an endpoint
 //!
@@ -59,6 +62,12 @@
 //!
 //! - Errors are implemented using [![thiserror]](https://docs.rs/thiserror/1.0.25/thiserror/)
 //!
+//! - Prices and quantities are `f64` by default, the `decimal` feature switches them to
+//!   `rust_decimal::Decimal` through the `rest_model::Number` alias so they round-trip losslessly
+//!
+//! - The `testkit` feature provides `testkit::MockServer`, a local mock of the REST and websocket APIs
+//!   to test against without a network
+//!
 
 #![deny(unstable_features, unused_must_use, unused_mut, unused_imports, unused_import_braces)]
 
@@ -72,23 +81,40 @@ pub use util::bool_to_string;
 pub use util::bool_to_string_some;
 
 mod client;
+mod net;
 pub mod errors;
 pub mod util;
 
 pub mod account;
 pub mod api;
+#[cfg(feature = "futures_api")]
+pub mod coin_futures;
 pub mod config;
+pub mod exchange;
+pub mod exchange_info;
+pub mod filters;
 #[cfg(feature = "futures_api")]
 pub mod futures;
 pub mod general;
 #[cfg(feature = "margin_api")]
 pub mod margin;
 pub mod market;
+#[cfg(feature = "options")]
+pub mod options;
+pub mod orderbook;
+#[cfg(feature = "portfolio_margin")]
+pub mod portfolio_margin;
+pub mod rate_limit;
 pub mod rest_model;
 #[cfg(feature = "savings_api")]
 pub mod savings;
+pub mod signer;
+#[cfg(feature = "testkit")]
+pub mod testkit;
+pub mod transport;
 pub mod userstream;
 #[cfg(feature = "wallet_api")]
 pub mod wallet;
 pub mod websockets;
+pub mod ws_api;
 pub mod ws_model;
@@ -0,0 +1,196 @@
+//! Websocket connections applying the network options of the configuration, the REST client applies them
+//! through reqwest
+
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{lookup_host, TcpSocket, TcpStream};
+use tokio_tungstenite::tungstenite::handshake::client::Response;
+use url::Url;
+
+use crate::config::Config;
+use crate::errors::*;
+use crate::websockets::WsStream;
+
+/// Longest proxy response to a CONNECT request
+const MAX_PROXY_RESPONSE: usize = 8192;
+
+/// Open a websocket to `url`, through the proxy of `conf` if any
+pub(crate) async fn connect_websocket(url: Url, conf: &Config) -> Result<(WsStream, Response)> {
+    let host = url
+        .host_str()
+        .ok_or_else(|| Error::Msg(format!("No host in {url}")))?
+        .to_string();
+    let port = url.port_or_known_default().unwrap_or(443);
+    let stream = match &conf.proxy {
+        Some(proxy) => {
+            let proxy = Url::parse(proxy)?;
+            let proxy_host = proxy
+                .host_str()
+                .ok_or_else(|| Error::Msg(format!("No host in proxy {proxy}")))?;
+            // socks has no known default port
+            let stream = tcp_connect(proxy_host, proxy.port_or_known_default().unwrap_or(1080), conf).await?;
+            match proxy.scheme() {
+                "http" => http_tunnel(stream, &proxy, &host, port).await?,
+                "socks5" | "socks5h" => socks_tunnel(stream, &proxy, &host, port).await?,
+                scheme => return Err(Error::Msg(format!("Unsupported websocket proxy scheme {scheme}"))),
+            }
+        }
+        None => tcp_connect(&host, port, conf).await?,
+    };
+    handshake(url, stream, conf)
+        .await
+        .map_err(|e| Error::Msg(format!("Error during handshake {e}")))
+}
+
+#[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+async fn handshake(url: Url, stream: TcpStream, conf: &Config) -> Result<(WsStream, Response)> {
+    Ok(tokio_tungstenite::client_async_tls_with_config(url, stream, None, tls_connector(conf)?).await?)
+}
+
+#[cfg(not(any(feature = "rustls-tls", feature = "native-tls")))]
+async fn handshake(url: Url, stream: TcpStream, _conf: &Config) -> Result<(WsStream, Response)> {
+    if url.scheme() == "wss" {
+        return Err(Error::Msg("wss requires a TLS feature".to_string()));
+    }
+    let stream = tokio_tungstenite::MaybeTlsStream::Plain(stream);
+    Ok(tokio_tungstenite::client_async(url, stream).await?)
+}
+
+/// Connect to the first reachable address of `host`, from the local address of `conf` if any
+async fn tcp_connect(host: &str, port: u16, conf: &Config) -> Result<TcpStream> {
+    let mut last_error = None;
+    for addr in lookup_host((host, port)).await? {
+        // a local address can only reach addresses of the same family
+        if conf.local_address.is_some_and(|local| local.is_ipv4() != addr.is_ipv4()) {
+            continue;
+        }
+        match tcp_connect_addr(addr, conf).await {
+            Ok(stream) => return Ok(stream),
+            Err(e) => last_error = Some(e),
+        }
+    }
+    Err(last_error.unwrap_or_else(|| Error::Msg(format!("No address to connect to {host}"))))
+}
+
+async fn tcp_connect_addr(addr: SocketAddr, conf: &Config) -> Result<TcpStream> {
+    let socket = if addr.is_ipv4() {
+        TcpSocket::new_v4()?
+    } else {
+        TcpSocket::new_v6()?
+    };
+    if let Some(local_address) = conf.local_address {
+        socket.bind(SocketAddr::new(local_address, 0))?;
+    }
+    let stream = match conf.connect_timeout_ms {
+        Some(timeout) => tokio::time::timeout(Duration::from_millis(timeout), socket.connect(addr))
+            .await
+            .map_err(|_| Error::Msg(format!("Connection to {addr} timed out")))??,
+        None => socket.connect(addr).await?,
+    };
+    stream.set_nodelay(conf.tcp_nodelay)?;
+    if let Some(keepalive) = conf.tcp_keepalive {
+        let keepalive = socket2::TcpKeepalive::new().with_time(Duration::from_secs(keepalive));
+        socket2::SockRef::from(&stream).set_tcp_keepalive(&keepalive)?;
+    }
+    Ok(stream)
+}
+
+/// Tunnel through an HTTP proxy with a CONNECT request
+async fn http_tunnel(mut stream: TcpStream, proxy: &Url, host: &str, port: u16) -> Result<TcpStream> {
+    let mut request = format!("CONNECT {host}:{port} HTTP/1.1\r\nHost: {host}:{port}\r\n");
+    if !proxy.username().is_empty() {
+        let credentials = format!("{}:{}", proxy.username(), proxy.password().unwrap_or_default());
+        request.push_str(&format!("Proxy-Authorization: Basic {}\r\n", BASE64.encode(credentials)));
+    }
+    request.push_str("\r\n");
+    stream.write_all(request.as_bytes()).await?;
+    // read byte by byte to leave what follows the response to the websocket handshake
+    let mut response = Vec::new();
+    while !response.ends_with(b"\r\n\r\n") {
+        if response.len() > MAX_PROXY_RESPONSE {
+            return Err(Error::Msg("Proxy response too long".to_string()));
+        }
+        response.push(stream.read_u8().await?);
+    }
+    let response = String::from_utf8_lossy(&response);
+    let status_line = response.lines().next().unwrap_or_default();
+    match status_line.split_whitespace().nth(1) {
+        Some("200") => Ok(stream),
+        _ => Err(Error::Msg(format!("Proxy refused to connect to {host}: {status_line}"))),
+    }
+}
+
+/// Tunnel through a SOCKS5 proxy, `socks5h` proxies resolve the host themselves
+#[cfg(feature = "socks")]
+async fn socks_tunnel(stream: TcpStream, proxy: &Url, host: &str, port: u16) -> Result<TcpStream> {
+    use tokio_socks::tcp::Socks5Stream;
+    use tokio_socks::TargetAddr;
+
+    let target = if proxy.scheme() == "socks5h" {
+        TargetAddr::Domain(host.into(), port)
+    } else {
+        let addr = lookup_host((host, port)).await?.next();
+        TargetAddr::Ip(addr.ok_or_else(|| Error::Msg(format!("No address to connect to {host}")))?)
+    };
+    let stream = if proxy.username().is_empty() {
+        Socks5Stream::connect_with_socket(stream, target).await
+    } else {
+        let password = proxy.password().unwrap_or_default();
+        Socks5Stream::connect_with_password_and_socket(stream, target, proxy.username(), password).await
+    };
+    stream
+        .map(Socks5Stream::into_inner)
+        .map_err(|e| Error::Msg(format!("Proxy refused to connect to {host}: {e}")))
+}
+
+#[cfg(not(feature = "socks"))]
+async fn socks_tunnel(_stream: TcpStream, _proxy: &Url, _host: &str, _port: u16) -> Result<TcpStream> {
+    Err(Error::Msg("SOCKS proxies require the socks feature".to_string()))
+}
+
+/// The default connector, unless custom root certificates are configured
+#[cfg(feature = "native-tls")]
+fn tls_connector(conf: &Config) -> Result<Option<tokio_tungstenite::Connector>> {
+    if conf.root_certificates.is_empty() {
+        return Ok(None);
+    }
+    let mut builder = native_tls_crate::TlsConnector::builder();
+    for pem in &conf.root_certificates {
+        let certificate = native_tls_crate::Certificate::from_pem(pem).map_err(|e| Error::Msg(e.to_string()))?;
+        builder.add_root_certificate(certificate);
+    }
+    let connector = builder.build().map_err(|e| Error::Msg(e.to_string()))?;
+    Ok(Some(tokio_tungstenite::Connector::NativeTls(connector)))
+}
+
+/// The default connector, unless custom root certificates are configured
+#[cfg(all(feature = "rustls-tls", not(feature = "native-tls")))]
+fn tls_connector(conf: &Config) -> Result<Option<tokio_tungstenite::Connector>> {
+    if conf.root_certificates.is_empty() {
+        return Ok(None);
+    }
+    let mut roots = rustls::RootCertStore::empty();
+    roots.add_server_trust_anchors(webpki_roots::TLS_SERVER_ROOTS.0.iter().map(|anchor| {
+        rustls::OwnedTrustAnchor::from_subject_spki_name_constraints(
+            anchor.subject,
+            anchor.spki,
+            anchor.name_constraints,
+        )
+    }));
+    for pem in &conf.root_certificates {
+        for certificate in rustls_pemfile::certs(&mut pem.as_slice())? {
+            roots
+                .add(&rustls::Certificate(certificate))
+                .map_err(|e| Error::Msg(e.to_string()))?;
+        }
+    }
+    let config = rustls::ClientConfig::builder()
+        .with_safe_defaults()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+    Ok(Some(tokio_tungstenite::Connector::Rustls(std::sync::Arc::new(config))))
+}
@@ -0,0 +1,148 @@
+use super::rest_model::{
+    AccountInformation, Order, OrderResponse, OrderSide, OrderType, Position, Success, TimeInForce, UserTrade,
+};
+use crate::client::Client;
+use crate::errors::*;
+use crate::rest_model::{Number, PairQuery};
+use crate::util::*;
+
+/// Options account, orders are limit orders sized in contracts
+#[derive(Clone)]
+pub struct OptionsAccount {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub quantity: Number,
+    pub price: Number,
+    /// Defaults to GTC
+    pub time_in_force: Option<TimeInForce>,
+    pub reduce_only: Option<bool>,
+    pub post_only: Option<bool>,
+    pub client_order_id: Option<String>,
+    /// Market maker protection order
+    pub is_mmp: Option<bool>,
+}
+
+/// Find an order by either order_id or client_order_id
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderQuery {
+    pub symbol: String,
+    pub order_id: Option<u64>,
+    pub client_order_id: Option<String>,
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UserTradesQuery {
+    pub symbol: Option<String>,
+    pub from_id: Option<u64>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Default 100 max 1000
+    pub limit: Option<u16>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PlaceOrderRequest<'a> {
+    #[serde(flatten)]
+    order: &'a OrderRequest,
+    #[serde(rename = "type")]
+    order_type: OrderType,
+    new_order_resp_type: OrderResponse,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OptionalSymbolQuery {
+    symbol: Option<String>,
+}
+
+impl OptionsAccount {
+    pub async fn place_order(&self, order: OrderRequest) -> Result<Order> {
+        // options only trade limit orders, and the full order is only returned with RESULT
+        let request = PlaceOrderRequest {
+            order: &order,
+            order_type: OrderType::Limit,
+            new_order_resp_type: OrderResponse::Result,
+        };
+        self.client
+            .post_signed_p("/eapi/v1/order", request, self.recv_window)
+            .await
+    }
+
+    pub async fn order_status(&self, query: OrderQuery) -> Result<Order> {
+        self.client
+            .get_signed_p("/eapi/v1/order", Some(query), self.recv_window)
+            .await
+    }
+
+    /// Open orders of a symbol, or of all symbols
+    pub async fn get_open_orders(&self, symbol: Option<String>) -> Result<Vec<Order>> {
+        self.client
+            .get_signed_p("/eapi/v1/openOrders", Some(OptionalSymbolQuery { symbol }), self.recv_window)
+            .await
+    }
+
+    pub async fn cancel_order(&self, query: OrderQuery) -> Result<Order> {
+        self.client
+            .delete_signed_p("/eapi/v1/order", query, self.recv_window)
+            .await
+    }
+
+    pub async fn cancel_all_open_orders<S>(&self, symbol: S) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let _: Success = self
+            .client
+            .delete_signed_p(
+                "/eapi/v1/allOpenOrders",
+                PairQuery { symbol: symbol.into() },
+                self.recv_window,
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Cancel the open orders of every option of an underlying, ex: BTCUSDT
+    pub async fn cancel_all_open_orders_by_underlying<S>(&self, underlying: S) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let request = build_signed_request([("underlying", underlying.into())], self.recv_window)?;
+        let _: Success = self
+            .client
+            .delete_signed("/eapi/v1/allOpenOrdersByUnderlying", &request)
+            .await?;
+        Ok(())
+    }
+
+    /// Positions of a symbol, or of all symbols
+    pub async fn position_information(&self, symbol: Option<String>) -> Result<Vec<Position>> {
+        self.client
+            .get_signed_p("/eapi/v1/position", Some(OptionalSymbolQuery { symbol }), self.recv_window)
+            .await
+    }
+
+    /// Balances and greeks of the options account
+    pub async fn account_information(&self) -> Result<AccountInformation> {
+        self.client
+            .get_signed_p("/eapi/v1/account", None::<()>, self.recv_window)
+            .await
+    }
+
+    /// Trades of the account
+    pub async fn trade_history(&self, query: UserTradesQuery) -> Result<Vec<UserTrade>> {
+        self.client
+            .get_signed_p("/eapi/v1/userTrades", Some(query), self.recv_window)
+            .await
+    }
+}
@@ -0,0 +1,132 @@
+use crate::client::*;
+use crate::errors::*;
+use crate::options::rest_model::*;
+use crate::rest_model::PairQuery;
+use crate::util::*;
+
+/// Market data of options, symbols look like BTC-240628-60000-C
+#[derive(Clone)]
+pub struct OptionsMarket {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct KlineQuery {
+    symbol: String,
+    interval: String,
+    limit: u16,
+    start_time: Option<u64>,
+    end_time: Option<u64>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OpenInterestQuery {
+    underlying_asset: String,
+    expiration: String,
+}
+
+impl OptionsMarket {
+    // Order book (Default 100; max 1000)
+    pub async fn get_depth<S>(&self, symbol: S) -> Result<OrderBook>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_d("/eapi/v1/depth", Some(PairQuery { symbol: symbol.into() }))
+            .await
+    }
+
+    // Order book with a custom depth limit
+    // Supported limits are: 10, 20, 50, 100, 500, 1000
+    pub async fn get_custom_depth<S>(&self, symbol: S, limit: u16) -> Result<OrderBook>
+    where
+        S: Into<String>,
+    {
+        let request = build_request([("symbol", symbol.into()), ("limit", limit.to_string())]);
+        self.client.get("/eapi/v1/depth", Some(&request)).await
+    }
+
+    /// Get recent trades
+    pub async fn get_trades<S>(&self, symbol: S) -> Result<Vec<Trade>>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_d("/eapi/v1/trades", Some(PairQuery { symbol: symbol.into() }))
+            .await
+    }
+
+    /// Returns up to 'limit' klines for given symbol and interval ("1m", "5m", ...)
+    pub async fn get_klines<S1, S2, S3, S4, S5>(
+        &self,
+        symbol: S1,
+        interval: S2,
+        limit: S3,
+        start_time: S4,
+        end_time: S5,
+    ) -> Result<Vec<Kline>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<u16>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let query = KlineQuery {
+            symbol: symbol.into(),
+            interval: interval.into(),
+            limit: limit.into(),
+            start_time: start_time.into(),
+            end_time: end_time.into(),
+        };
+        self.client.get_d("/eapi/v1/klines", Some(query)).await
+    }
+
+    /// 24hr ticker price change statistics of a symbol
+    pub async fn get_24h_price_stats<S>(&self, symbol: S) -> Result<Vec<Ticker>>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_d("/eapi/v1/ticker", Some(PairQuery { symbol: symbol.into() }))
+            .await
+    }
+
+    /// 24hr ticker price change statistics for all symbols
+    pub async fn get_all_24h_price_stats(&self) -> Result<Vec<Ticker>> {
+        self.client.get_p("/eapi/v1/ticker", None).await
+    }
+
+    /// Mark price, implied volatility and greeks of a symbol, or of all symbols
+    pub async fn get_mark_prices(&self, symbol: Option<String>) -> Result<Vec<MarkPrice>> {
+        match symbol {
+            Some(symbol) => self.client.get_d("/eapi/v1/mark", Some(PairQuery { symbol })).await,
+            None => self.client.get_p("/eapi/v1/mark", None).await,
+        }
+    }
+
+    /// Spot index price of an underlying, ex: BTCUSDT
+    pub async fn get_index_price<S>(&self, underlying: S) -> Result<IndexPrice>
+    where
+        S: Into<String>,
+    {
+        let request = build_request([("underlying", underlying.into())]);
+        self.client.get("/eapi/v1/index", Some(&request)).await
+    }
+
+    /// Open interest of every option of an underlying asset (ex: BTC) expiring at `expiration` (ex: 240628)
+    pub async fn open_interest<S1, S2>(&self, underlying_asset: S1, expiration: S2) -> Result<Vec<OpenInterest>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let query = OpenInterestQuery {
+            underlying_asset: underlying_asset.into(),
+            expiration: expiration.into(),
+        };
+        self.client.get_d("/eapi/v1/openInterest", Some(query)).await
+    }
+}
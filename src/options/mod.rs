@@ -0,0 +1,8 @@
+//! European options on BTC, ETH and other majors, quoted and settled in USDT
+
+pub mod account;
+pub mod general;
+pub mod market;
+pub mod rest_model;
+pub mod websockets;
+pub mod ws_model;
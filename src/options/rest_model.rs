@@ -0,0 +1,371 @@
+use serde::{Deserialize, Serialize};
+
+use crate::rest_model::{string_or_float, string_or_u64};
+pub use crate::rest_model::{
+    Asks, Bids, Filters, Number, OrderResponse, OrderSide, OrderType, RateLimit, ServerTime, Success, TimeInForce,
+};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeInformation {
+    pub timezone: String,
+    pub server_time: u64,
+    pub option_contracts: Vec<OptionContract>,
+    pub option_assets: Vec<OptionAsset>,
+    pub option_symbols: Vec<Symbol>,
+    pub rate_limits: Vec<RateLimit>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionContract {
+    pub base_asset: String,
+    pub quote_asset: String,
+    pub underlying: String,
+    pub settle_asset: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionAsset {
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OptionSide {
+    Call,
+    Put,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Symbol {
+    pub symbol: String,
+    pub side: OptionSide,
+    #[serde(with = "string_or_float")]
+    pub strike_price: Number,
+    pub underlying: String,
+    pub expiry_date: u64,
+    /// Quantity of the underlying asset per contract
+    pub unit: u64,
+    #[serde(with = "string_or_float")]
+    pub maker_fee_rate: Number,
+    #[serde(with = "string_or_float")]
+    pub taker_fee_rate: Number,
+    #[serde(with = "string_or_float")]
+    pub min_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub max_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub initial_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub maintenance_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub min_initial_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub min_maintenance_margin: Number,
+    pub price_scale: u16,
+    pub quantity_scale: u16,
+    pub quote_asset: String,
+    pub filters: Vec<Filters>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OrderBook {
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    #[serde(rename = "u")]
+    pub update_id: u64,
+    pub bids: Vec<Bids>,
+    pub asks: Vec<Asks>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    #[serde(with = "string_or_u64")]
+    pub id: u64,
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    #[serde(with = "string_or_float")]
+    pub qty: Number,
+    #[serde(with = "string_or_float")]
+    pub quote_qty: Number,
+    /// 1 when the taker bought, -1 when the taker sold
+    pub side: i8,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Kline {
+    pub interval: String,
+    pub open_time: u64,
+    pub close_time: u64,
+    #[serde(with = "string_or_float")]
+    pub open: Number,
+    #[serde(with = "string_or_float")]
+    pub high: Number,
+    #[serde(with = "string_or_float")]
+    pub low: Number,
+    #[serde(with = "string_or_float")]
+    pub close: Number,
+    #[serde(with = "string_or_float")]
+    pub volume: Number,
+    /// Volume in the quote asset
+    #[serde(with = "string_or_float")]
+    pub amount: Number,
+    #[serde(with = "string_or_float")]
+    pub taker_volume: Number,
+    #[serde(with = "string_or_float")]
+    pub taker_amount: Number,
+    pub trade_count: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Ticker {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub price_change: Number,
+    #[serde(with = "string_or_float")]
+    pub price_change_percent: Number,
+    #[serde(with = "string_or_float")]
+    pub last_price: Number,
+    #[serde(with = "string_or_float")]
+    pub last_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub open: Number,
+    #[serde(with = "string_or_float")]
+    pub high: Number,
+    #[serde(with = "string_or_float")]
+    pub low: Number,
+    #[serde(with = "string_or_float")]
+    pub volume: Number,
+    #[serde(with = "string_or_float")]
+    pub amount: Number,
+    #[serde(with = "string_or_float")]
+    pub bid_price: Number,
+    #[serde(with = "string_or_float")]
+    pub ask_price: Number,
+    pub open_time: u64,
+    pub close_time: u64,
+    pub first_trade_id: u64,
+    pub trade_count: u64,
+    #[serde(with = "string_or_float")]
+    pub strike_price: Number,
+    /// Settlement price of the underlying, an estimate until expiry
+    #[serde(with = "string_or_float")]
+    pub exercise_price: Number,
+}
+
+/// Mark price, implied volatilities and greeks of an option
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkPrice {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub mark_price: Number,
+    #[serde(with = "string_or_float", rename = "bidIV")]
+    pub bid_iv: Number,
+    #[serde(with = "string_or_float", rename = "askIV")]
+    pub ask_iv: Number,
+    #[serde(with = "string_or_float", rename = "markIV")]
+    pub mark_iv: Number,
+    #[serde(with = "string_or_float")]
+    pub delta: Number,
+    #[serde(with = "string_or_float")]
+    pub theta: Number,
+    #[serde(with = "string_or_float")]
+    pub gamma: Number,
+    #[serde(with = "string_or_float")]
+    pub vega: Number,
+    #[serde(with = "string_or_float")]
+    pub high_price_limit: Number,
+    #[serde(with = "string_or_float")]
+    pub low_price_limit: Number,
+    #[serde(with = "string_or_float")]
+    pub risk_free_interest: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexPrice {
+    pub time: u64,
+    #[serde(with = "string_or_float")]
+    pub index_price: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterest {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub sum_open_interest: Number,
+    #[serde(with = "string_or_float")]
+    pub sum_open_interest_usd: Number,
+    #[serde(with = "string_or_u64")]
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderStatus {
+    Accepted,
+    Rejected,
+    PartiallyFilled,
+    Filled,
+    Cancelled,
+    #[serde(other)]
+    Other,
+}
+
+/// An order as returned when placing, querying or canceling it
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
+    pub order_id: u64,
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    #[serde(with = "string_or_float")]
+    pub quantity: Number,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub fee: Number,
+    pub side: OrderSide,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    pub time_in_force: TimeInForce,
+    pub reduce_only: bool,
+    pub post_only: bool,
+    pub create_time: u64,
+    pub update_time: u64,
+    pub status: OrderStatus,
+    #[serde(with = "string_or_float")]
+    pub avg_price: Number,
+    pub client_order_id: String,
+    pub price_scale: u16,
+    pub quantity_scale: u16,
+    pub option_side: OptionSide,
+    pub quote_asset: String,
+    /// Market maker protection order
+    pub mmp: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Liquidity {
+    Maker,
+    Taker,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserTrade {
+    pub id: u64,
+    pub trade_id: u64,
+    pub order_id: u64,
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    #[serde(with = "string_or_float")]
+    pub quantity: Number,
+    #[serde(with = "string_or_float")]
+    pub fee: Number,
+    #[serde(with = "string_or_float")]
+    pub realized_profit: Number,
+    pub side: OrderSide,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    pub liquidity: Liquidity,
+    pub time: u64,
+    pub price_scale: u16,
+    pub quantity_scale: u16,
+    pub option_side: OptionSide,
+    pub quote_asset: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PositionSide {
+    Long,
+    Short,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Position {
+    pub symbol: String,
+    pub side: PositionSide,
+    /// Negative for short positions
+    #[serde(with = "string_or_float")]
+    pub quantity: Number,
+    #[serde(with = "string_or_float")]
+    pub reducible_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub entry_price: Number,
+    #[serde(with = "string_or_float")]
+    pub mark_price: Number,
+    #[serde(with = "string_or_float")]
+    pub mark_value: Number,
+    /// Return on cost
+    #[serde(with = "string_or_float")]
+    pub ror: Number,
+    #[serde(with = "string_or_float", rename = "unrealizedPNL")]
+    pub unrealized_pnl: Number,
+    #[serde(with = "string_or_float")]
+    pub strike_price: Number,
+    #[serde(with = "string_or_float")]
+    pub position_cost: Number,
+    pub expiry_date: u64,
+    pub price_scale: u16,
+    pub quantity_scale: u16,
+    pub option_side: OptionSide,
+    pub quote_asset: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountAsset {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub margin_balance: Number,
+    #[serde(with = "string_or_float")]
+    pub equity: Number,
+    #[serde(with = "string_or_float")]
+    pub available: Number,
+    #[serde(with = "string_or_float")]
+    pub locked: Number,
+    #[serde(with = "string_or_float", rename = "unrealizedPNL")]
+    pub unrealized_pnl: Number,
+}
+
+/// Greeks of all the positions on an underlying
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountGreeks {
+    pub underlying: String,
+    #[serde(with = "string_or_float")]
+    pub delta: Number,
+    #[serde(with = "string_or_float")]
+    pub gamma: Number,
+    #[serde(with = "string_or_float")]
+    pub theta: Number,
+    #[serde(with = "string_or_float")]
+    pub vega: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInformation {
+    #[serde(rename = "asset")]
+    pub assets: Vec<AccountAsset>,
+    #[serde(rename = "greek")]
+    pub greeks: Vec<AccountGreeks>,
+    pub time: u64,
+    pub risk_level: String,
+}
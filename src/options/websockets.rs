@@ -0,0 +1,87 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::config::Config;
+use crate::errors::*;
+use crate::websockets::{combined_url, connect_split, endpoint_url, ControlSink, EventStream, WebSockets};
+
+/// Trades of an option, ex: BTC-240628-60000-C, or of every option of an underlying, ex: BTC
+pub fn trade_stream(symbol: &str) -> String { format!("{symbol}@trade") }
+
+/// Spot index price of an underlying, ex: BTCUSDT
+pub fn index_price_stream(underlying: &str) -> String { format!("{underlying}@index") }
+
+/// Mark prices of every option of an underlying asset, ex: BTC
+pub fn mark_price_stream(underlying_asset: &str) -> String { format!("{underlying_asset}@markPrice") }
+
+pub fn kline_stream(symbol: &str, interval: &str) -> String { format!("{symbol}@kline_{interval}") }
+
+pub fn ticker_stream(symbol: &str) -> String { format!("{symbol}@ticker") }
+
+/// Tickers of every option of an underlying asset (ex: BTC) expiring at `expiration` (ex: 240628)
+pub fn expiry_ticker_stream(underlying_asset: &str, expiration: &str) -> String {
+    format!("{underlying_asset}@ticker@{expiration}")
+}
+
+/// Open interest of every option of an underlying asset (ex: BTC) expiring at `expiration` (ex: 240628)
+pub fn open_interest_stream(underlying_asset: &str, expiration: &str) -> String {
+    format!("{underlying_asset}@openInterest@{expiration}")
+}
+
+/// # Arguments
+///
+/// * `symbol`: the option symbol
+/// * `levels`: 10, 20, 50 or 100
+/// * `update_speed`: 100 or 1000
+pub fn partial_book_depth_stream(symbol: &str, levels: u16, update_speed: u16) -> String {
+    format!("{symbol}@depth{levels}@{update_speed}ms")
+}
+
+/// Connect to an options websocket endpoint and return its events as a [`futures::Stream`]
+pub async fn connect_stream<WE: serde::de::DeserializeOwned>(
+    conf: &Config,
+    endpoint: &str,
+) -> Result<(ControlSink, EventStream<WE>)> {
+    connect_split(endpoint_url(&conf.options_ws_endpoint, endpoint)?, conf).await
+}
+
+/// Connect to multiple options websocket endpoints and return their events as a [`futures::Stream`]
+/// N.B: WE has to be CombinedStreamEvent
+pub async fn connect_multiple_streams<WE: serde::de::DeserializeOwned>(
+    conf: &Config,
+    endpoints: Vec<String>,
+) -> Result<(ControlSink, EventStream<WE>)> {
+    connect_split(combined_url(&conf.options_ws_endpoint, endpoints)?, conf).await
+}
+
+/// [`WebSockets`] connected to the options streams,
+/// events deserialize into [`crate::options::ws_model::OptionsWebsocketEventUntag`]
+pub struct OptionsWebSockets<'a, WE>(WebSockets<'a, WE>);
+
+impl<'a, WE: serde::de::DeserializeOwned> OptionsWebSockets<'a, WE> {
+    /// New websocket holder with default configuration
+    pub fn new<Callback>(handler: Callback) -> OptionsWebSockets<'a, WE>
+    where
+        Callback: FnMut(WE) -> Result<()> + 'a + Send,
+    {
+        Self::new_with_options(handler, Config::default())
+    }
+
+    /// New websocket holder with provided configuration
+    pub fn new_with_options<Callback>(handler: Callback, conf: Config) -> OptionsWebSockets<'a, WE>
+    where
+        Callback: FnMut(WE) -> Result<()> + 'a + Send,
+    {
+        let ws_endpoint = conf.options_ws_endpoint.clone();
+        OptionsWebSockets(WebSockets::new_with_options(handler, conf).with_ws_endpoint(ws_endpoint))
+    }
+}
+
+impl<'a, WE> Deref for OptionsWebSockets<'a, WE> {
+    type Target = WebSockets<'a, WE>;
+
+    fn deref(&self) -> &Self::Target { &self.0 }
+}
+
+impl<WE> DerefMut for OptionsWebSockets<'_, WE> {
+    fn deref_mut(&mut self) -> &mut Self::Target { &mut self.0 }
+}
@@ -0,0 +1,219 @@
+use crate::rest_model::{string_or_float, string_or_u64, Asks, Bids, Number};
+
+pub use crate::ws_model::{CombinedStreamEvent, QueryResult, SubscriptionMethod, SubscriptionRequest};
+
+/// Mark price and open interest streams send every option of an underlying in one array
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum OptionsWebsocketEventUntag {
+    OptionsWebsocketEvent(OptionsWebsocketEvent),
+    OptionsWebsocketEvents(Vec<OptionsWebsocketEvent>),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "e")]
+pub enum OptionsWebsocketEvent {
+    #[serde(alias = "trade")]
+    Trade(Box<TradeEvent>),
+    #[serde(alias = "index")]
+    IndexPrice(Box<IndexPriceEvent>),
+    #[serde(alias = "markPrice")]
+    MarkPrice(Box<MarkPriceEvent>),
+    #[serde(alias = "kline")]
+    Kline(Box<KlineEvent>),
+    #[serde(alias = "24hrTicker")]
+    DayTicker(Box<DayTickerEvent>),
+    #[serde(alias = "depth")]
+    Depth(Box<DepthEvent>),
+    #[serde(alias = "openInterest")]
+    OpenInterest(Box<OpenInterestEvent>),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TradeEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "t", with = "string_or_u64")]
+    pub trade_id: u64,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub price: Number,
+    #[serde(rename = "q", with = "string_or_float")]
+    pub qty: Number,
+    #[serde(rename = "b")]
+    pub buyer_order_id: u64,
+    #[serde(rename = "a")]
+    pub seller_order_id: u64,
+    #[serde(rename = "T")]
+    pub trade_time: u64,
+    /// "1" when the taker bought, "-1" when the taker sold
+    #[serde(rename = "S")]
+    pub direction: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexPriceEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    /// The underlying, ex: BTCUSDT
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub index_price: Number,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarkPriceEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "mp", with = "string_or_float")]
+    pub mark_price: Number,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KlineEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "k")]
+    pub kline: Kline,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Kline {
+    #[serde(rename = "t")]
+    pub start_time: i64,
+    #[serde(rename = "T")]
+    pub end_time: i64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "i")]
+    pub interval: String,
+    #[serde(rename = "F")]
+    pub first_trade_id: i64,
+    #[serde(rename = "L")]
+    pub last_trade_id: i64,
+    #[serde(rename = "o", with = "string_or_float")]
+    pub open: Number,
+    #[serde(rename = "c", with = "string_or_float")]
+    pub close: Number,
+    #[serde(rename = "h", with = "string_or_float")]
+    pub high: Number,
+    #[serde(rename = "l", with = "string_or_float")]
+    pub low: Number,
+    #[serde(rename = "v", with = "string_or_float")]
+    pub volume: Number,
+    #[serde(rename = "n")]
+    pub number_of_trades: i64,
+    #[serde(rename = "x")]
+    pub is_final_bar: bool,
+    #[serde(rename = "q", with = "string_or_float")]
+    pub quote_volume: Number,
+    #[serde(rename = "V", with = "string_or_float")]
+    pub taker_buy_volume: Number,
+    #[serde(rename = "Q", with = "string_or_float")]
+    pub taker_buy_quote_volume: Number,
+}
+
+/// 24hr statistics with the best quotes, implied volatilities and greeks of an option
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DayTickerEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "o", with = "string_or_float")]
+    pub open: Number,
+    #[serde(rename = "h", with = "string_or_float")]
+    pub high: Number,
+    #[serde(rename = "l", with = "string_or_float")]
+    pub low: Number,
+    #[serde(rename = "c", with = "string_or_float")]
+    pub close: Number,
+    #[serde(rename = "V", with = "string_or_float")]
+    pub volume: Number,
+    /// Volume in the quote asset
+    #[serde(rename = "A", with = "string_or_float")]
+    pub amount: Number,
+    #[serde(rename = "P", with = "string_or_float")]
+    pub price_change_percent: Number,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub price_change: Number,
+    #[serde(rename = "Q", with = "string_or_float")]
+    pub last_qty: Number,
+    #[serde(rename = "F", with = "string_or_u64")]
+    pub first_trade_id: u64,
+    #[serde(rename = "L", with = "string_or_u64")]
+    pub last_trade_id: u64,
+    #[serde(rename = "n")]
+    pub num_trades: u64,
+    #[serde(rename = "bo", with = "string_or_float")]
+    pub best_bid: Number,
+    #[serde(rename = "ao", with = "string_or_float")]
+    pub best_ask: Number,
+    #[serde(rename = "bq", with = "string_or_float")]
+    pub best_bid_qty: Number,
+    #[serde(rename = "aq", with = "string_or_float")]
+    pub best_ask_qty: Number,
+    #[serde(rename = "b", with = "string_or_float")]
+    pub bid_iv: Number,
+    #[serde(rename = "a", with = "string_or_float")]
+    pub ask_iv: Number,
+    #[serde(rename = "d", with = "string_or_float")]
+    pub delta: Number,
+    #[serde(rename = "t", with = "string_or_float")]
+    pub theta: Number,
+    #[serde(rename = "g", with = "string_or_float")]
+    pub gamma: Number,
+    #[serde(rename = "v", with = "string_or_float")]
+    pub vega: Number,
+    #[serde(rename = "vo", with = "string_or_float")]
+    pub mark_iv: Number,
+    #[serde(rename = "mp", with = "string_or_float")]
+    pub mark_price: Number,
+    #[serde(rename = "hl", with = "string_or_float")]
+    pub high_price_limit: Number,
+    #[serde(rename = "ll", with = "string_or_float")]
+    pub low_price_limit: Number,
+    /// Estimated exercise price
+    #[serde(rename = "eep", with = "string_or_float")]
+    pub exercise_price: Number,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DepthEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "u")]
+    pub update_id: u64,
+    #[serde(rename = "pu")]
+    pub previous_update_id: u64,
+    #[serde(rename = "b")]
+    pub bids: Vec<Bids>,
+    #[serde(rename = "a")]
+    pub asks: Vec<Asks>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenInterestEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    /// Open interest in contracts
+    #[serde(rename = "o", with = "string_or_float")]
+    pub open_interest: Number,
+    /// Open interest in USDT
+    #[serde(rename = "h", with = "string_or_float")]
+    pub open_interest_usd: Number,
+}
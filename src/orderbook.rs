@@ -0,0 +1,352 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+use tracing::warn;
+
+use crate::errors::*;
+use crate::market::Market;
+use crate::rest_model::{Asks, Bids, Number, OrderBook, OrderSide};
+use crate::ws_model::DepthOrderBookEvent;
+
+/// Price key with a total order, prices sent by Binance are never NaN
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Price(Number);
+
+impl Eq for Price {}
+
+impl PartialOrd for Price {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl Ord for Price {
+    fn cmp(&self, other: &Self) -> Ordering { self.0.partial_cmp(&other.0).unwrap_or(Ordering::Equal) }
+}
+
+/// Order book maintained locally from a depth snapshot and diff events
+#[derive(Debug, Clone, Default)]
+pub struct LocalOrderBook {
+    pub last_update_id: u64,
+    bids: BTreeMap<Price, Number>,
+    asks: BTreeMap<Price, Number>,
+}
+
+impl LocalOrderBook {
+    pub fn from_snapshot(snapshot: &OrderBook) -> Self {
+        let mut book = LocalOrderBook {
+            last_update_id: snapshot.last_update_id,
+            ..Default::default()
+        };
+        book.apply(&snapshot.bids, &snapshot.asks);
+        book
+    }
+
+    /// Apply absolute quantities for each price level, a quantity of 0 removes the level
+    fn apply(&mut self, bids: &[Bids], asks: &[Asks]) {
+        for bid in bids {
+            update_level(&mut self.bids, bid.price, bid.qty);
+        }
+        for ask in asks {
+            update_level(&mut self.asks, ask.price, ask.qty);
+        }
+    }
+
+    pub fn best_bid(&self) -> Option<Bids> {
+        self.bids
+            .iter()
+            .next_back()
+            .map(|(price, qty)| Bids { price: price.0, qty: *qty })
+    }
+
+    pub fn best_ask(&self) -> Option<Asks> {
+        self.asks
+            .iter()
+            .next()
+            .map(|(price, qty)| Asks { price: price.0, qty: *qty })
+    }
+
+    /// Mid price between the best bid and the best ask
+    pub fn mid_price(&self) -> Option<Number> { Some((self.best_bid()?.price + self.best_ask()?.price) / Number::from(2u8)) }
+
+    /// The `levels` best bids and asks
+    pub fn depth(&self, levels: usize) -> OrderBook {
+        OrderBook {
+            last_update_id: self.last_update_id,
+            bids: self
+                .bids
+                .iter()
+                .rev()
+                .take(levels)
+                .map(|(price, qty)| Bids { price: price.0, qty: *qty })
+                .collect(),
+            asks: self
+                .asks
+                .iter()
+                .take(levels)
+                .map(|(price, qty)| Asks { price: price.0, qty: *qty })
+                .collect(),
+        }
+    }
+
+    /// Volume weighted average price to fill `qty` with a market order on `side`,
+    /// buying walks up the asks and selling walks down the bids.
+    /// Returns `None` if `qty` is not positive or the book is not deep enough
+    pub fn vwap(&self, side: OrderSide, qty: Number) -> Option<Number> {
+        if qty <= Number::default() {
+            return None;
+        }
+        let levels: Box<dyn Iterator<Item = (&Price, &Number)>> = match side {
+            OrderSide::Buy => Box::new(self.asks.iter()),
+            OrderSide::Sell => Box::new(self.bids.iter().rev()),
+        };
+        let mut remaining = qty;
+        let mut notional = Number::default();
+        for (price, level_qty) in levels {
+            let filled = remaining.min(*level_qty);
+            notional += filled * price.0;
+            remaining -= filled;
+            if remaining <= Number::default() {
+                return Some(notional / qty);
+            }
+        }
+        None
+    }
+}
+
+fn update_level(levels: &mut BTreeMap<Price, Number>, price: Number, qty: Number) {
+    if qty == Number::default() {
+        levels.remove(&Price(price));
+    } else {
+        levels.insert(Price(price), qty);
+    }
+}
+
+/// A diff depth event and the sequencing rules of its market
+pub(crate) trait DepthUpdate {
+    fn first_update_id(&self) -> u64;
+
+    fn final_update_id(&self) -> u64;
+
+    fn bids(&self) -> &[Bids];
+
+    fn asks(&self) -> &[Asks];
+
+    /// Whether the event is entirely contained in the snapshot
+    fn is_stale(&self, last_update_id: u64) -> bool;
+
+    /// Whether the event directly follows `last_update_id`, `first` is set for the first event after a snapshot
+    fn follows(&self, last_update_id: u64, first: bool) -> bool;
+}
+
+impl DepthUpdate for DepthOrderBookEvent {
+    fn first_update_id(&self) -> u64 { self.first_update_id }
+
+    fn final_update_id(&self) -> u64 { self.final_update_id }
+
+    fn bids(&self) -> &[Bids] { &self.bids }
+
+    fn asks(&self) -> &[Asks] { &self.asks }
+
+    fn is_stale(&self, last_update_id: u64) -> bool { self.final_update_id <= last_update_id }
+
+    fn follows(&self, last_update_id: u64, first: bool) -> bool {
+        if first {
+            self.first_update_id <= last_update_id + 1 && self.final_update_id > last_update_id
+        } else {
+            self.first_update_id == last_update_id + 1
+        }
+    }
+}
+
+/// Delay before fetching another snapshot when the previous one was older than the buffered events
+const SNAPSHOT_RETRY_DELAY: Duration = Duration::from_millis(250);
+/// Upper bound of the snapshot retry delay, which doubles with each outdated snapshot
+const MAX_SNAPSHOT_RETRY_DELAY: Duration = Duration::from_secs(10);
+
+/// Buffers diff events until a snapshot lines up with them, then keeps the book in sequence
+#[derive(Debug)]
+pub(crate) struct DepthSynchronizer<E> {
+    book: Option<LocalOrderBook>,
+    first: bool,
+    buffer: Vec<E>,
+    outdated_snapshots: u32,
+    next_snapshot: Option<Instant>,
+}
+
+impl<E: DepthUpdate> DepthSynchronizer<E> {
+    pub(crate) fn new() -> Self {
+        DepthSynchronizer {
+            book: None,
+            first: true,
+            buffer: vec![],
+            outdated_snapshots: 0,
+            next_snapshot: None,
+        }
+    }
+
+    pub(crate) fn book(&self) -> Option<&LocalOrderBook> { self.book.as_ref() }
+
+    /// Whether the book is out of sync and the snapshot retry delay has elapsed
+    pub(crate) fn needs_snapshot(&self) -> bool {
+        self.book.is_none() && self.next_snapshot.is_none_or(|next| Instant::now() >= next)
+    }
+
+    /// Apply the event if the book is in sync, returns true if a snapshot must be fetched
+    pub(crate) fn update(&mut self, event: E) -> bool {
+        if let Some(book) = self.book.as_mut() {
+            if event.is_stale(book.last_update_id) {
+                return false;
+            }
+            if event.follows(book.last_update_id, self.first) {
+                book.apply(event.bids(), event.asks());
+                book.last_update_id = event.final_update_id();
+                self.first = false;
+                return false;
+            }
+            warn!(
+                "Depth gap detected after update {}, resyncing from update {}",
+                book.last_update_id,
+                event.first_update_id()
+            );
+            self.book = None;
+            self.buffer.clear();
+        }
+        self.buffer.push(event);
+        self.needs_snapshot()
+    }
+
+    /// Initialize the book from a snapshot and replay buffered events on top of it,
+    /// returns false if the snapshot is older than the buffered events or a gap was found.
+    /// Outdated snapshots delay the next fetch with an exponential backoff
+    pub(crate) fn load_snapshot(&mut self, snapshot: &OrderBook) -> bool {
+        match self.buffer.first() {
+            Some(event) if snapshot.last_update_id + 1 < event.first_update_id() => {
+                let delay = SNAPSHOT_RETRY_DELAY
+                    .saturating_mul(2u32.saturating_pow(self.outdated_snapshots))
+                    .min(MAX_SNAPSHOT_RETRY_DELAY);
+                warn!(
+                    "Depth snapshot {} is older than update {}, fetching another one in {delay:?}",
+                    snapshot.last_update_id,
+                    event.first_update_id()
+                );
+                self.outdated_snapshots += 1;
+                self.next_snapshot = Some(Instant::now() + delay);
+                return false;
+            }
+            _ => {}
+        }
+        self.outdated_snapshots = 0;
+        self.next_snapshot = None;
+        self.book = Some(LocalOrderBook::from_snapshot(snapshot));
+        self.first = true;
+        for event in std::mem::take(&mut self.buffer) {
+            self.update(event);
+        }
+        self.book.is_some()
+    }
+}
+
+/// Maintains a local order book for a spot symbol from a diff depth stream
+///
+/// Events from [`crate::websockets::diff_book_depth_stream`] are fed with [`OrderBookManager::update`],
+/// the REST snapshot is fetched as needed and the book is resynced when a gap in update ids is detected.
+/// # Examples
+/// ```rust,no_run
+/// use binance::{api::*, config::*, market::*, orderbook::*, rest_model::OrderSide, websockets::*, ws_model::*};
+/// use futures::StreamExt;
+/// tokio_test::block_on(async {
+///     let market: Market = Binance::new(None, None);
+///     let mut manager = OrderBookManager::new(market, "BTCUSDT", 1000);
+///     let (_, mut events) =
+///         connect_stream::<DepthOrderBookEvent>(&Config::default(), &diff_book_depth_stream("btcusdt", 100)).await?;
+///     while let Some(event) = events.next().await {
+///         manager.update(event?).await?;
+///         if let Some(book) = manager.book() {
+///             let vwap = book.vwap(OrderSide::Buy, "1.0".parse().unwrap());
+///             println!("{:?} {:?} {:?}", book.best_bid(), book.best_ask(), vwap);
+///         }
+///     }
+///     binance::errors::Result::Ok(())
+/// });
+/// ```
+pub struct OrderBookManager {
+    market: Market,
+    symbol: String,
+    limit: u16,
+    sync: DepthSynchronizer<DepthOrderBookEvent>,
+}
+
+impl OrderBookManager {
+    /// # Arguments
+    ///
+    /// * `market`: used to fetch depth snapshots
+    /// * `symbol`: the market symbol
+    /// * `limit`: depth of the snapshot, 5000 at most
+    pub fn new<S: Into<String>>(market: Market, symbol: S, limit: u16) -> Self {
+        OrderBookManager {
+            market,
+            symbol: symbol.into(),
+            limit,
+            sync: DepthSynchronizer::new(),
+        }
+    }
+
+    /// The local book, `None` until the snapshot and the diff stream are in sync
+    pub fn book(&self) -> Option<&LocalOrderBook> { self.sync.book() }
+
+    /// Apply a diff event, fetching a new snapshot when the book is not in sync
+    pub async fn update(&mut self, event: DepthOrderBookEvent) -> Result<()> {
+        if self.sync.update(event) {
+            let snapshot = self.market.get_custom_depth(&self.symbol, self.limit).await?;
+            self.sync.load_snapshot(&snapshot);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::num;
+
+    fn event(first_update_id: u64, final_update_id: u64, bids: &[(f64, f64)], asks: &[(f64, f64)]) -> DepthOrderBookEvent {
+        DepthOrderBookEvent {
+            event_time: 0,
+            symbol: "BTCUSDT".to_string(),
+            first_update_id,
+            final_update_id,
+            bids: bids.iter().map(|(price, qty)| Bids { price: num(*price), qty: num(*qty) }).collect(),
+            asks: asks.iter().map(|(price, qty)| Asks { price: num(*price), qty: num(*qty) }).collect(),
+        }
+    }
+
+    fn snapshot(last_update_id: u64) -> OrderBook {
+        OrderBook {
+            last_update_id,
+            bids: vec![Bids { price: num(99.0), qty: num(1.0) }, Bids { price: num(98.0), qty: num(2.0) }],
+            asks: vec![Asks { price: num(101.0), qty: num(1.0) }, Asks { price: num(102.0), qty: num(3.0) }],
+        }
+    }
+
+    #[test]
+    fn depth_sync() {
+        let mut sync = DepthSynchronizer::new();
+        assert!(sync.update(event(95, 100, &[(99.0, 5.0)], &[])));
+        assert!(sync.update(event(101, 105, &[(99.5, 1.0)], &[(101.0, 0.0)])));
+        // snapshot older than the first buffered event must be refetched, after a delay
+        assert!(!sync.load_snapshot(&snapshot(90)));
+        assert!(!sync.needs_snapshot());
+        assert!(!sync.update(event(106, 106, &[], &[(101.5, 1.0)])));
+        assert!(sync.load_snapshot(&snapshot(102)));
+        let book = sync.book().unwrap();
+        assert_eq!(book.last_update_id, 106);
+        assert_eq!(book.best_bid().unwrap().price, num(99.5));
+        assert_eq!(book.best_ask().unwrap().price, num(101.5));
+        assert_eq!(sync.book().unwrap().vwap(OrderSide::Buy, num(2.0)), Some(num(101.75)));
+        assert_eq!(sync.book().unwrap().vwap(OrderSide::Sell, num(10.0)), None);
+        assert_eq!(sync.book().unwrap().vwap(OrderSide::Buy, num(0.0)), None);
+        // gap in update ids
+        assert!(sync.update(event(110, 111, &[], &[])));
+        assert!(sync.book().is_none());
+    }
+}
@@ -0,0 +1,167 @@
+use super::rest_model::{
+    AccountInformation, Balance, CmOrder, Number, OrderCanceled, OrderResponse, OrderSide, OrderType, PositionSide,
+    SideEffectType, Success, TimeInForce, Transaction, TransactionId, UmOrder,
+};
+use crate::account::OrderCancellation;
+use crate::client::Client;
+use crate::errors::*;
+use crate::util::*;
+
+/// Portfolio margin account, one margin balance shared by UM futures, CM futures and cross margin orders
+///
+/// The API has no time endpoint, the server time is measured with the spot API of the configuration
+#[derive(Clone)]
+pub struct PortfolioMarginAccount {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+/// A UM or CM futures order, CM quantities are in contracts
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesOrderRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    /// Required in hedge mode
+    pub position_side: Option<PositionSide>,
+    /// LIMIT or MARKET
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    pub time_in_force: Option<TimeInForce>,
+    pub quantity: Number,
+    pub reduce_only: Option<bool>,
+    pub price: Option<Number>,
+    pub new_client_order_id: Option<String>,
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginOrderRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    pub quantity: Option<Number>,
+    pub quote_order_qty: Option<Number>,
+    pub price: Option<Number>,
+    pub stop_price: Option<Number>,
+    pub time_in_force: Option<TimeInForce>,
+    pub new_client_order_id: Option<String>,
+    pub iceberg_qty: Option<Number>,
+    /// Borrow or repay automatically, defaults to NO_SIDE_EFFECT
+    pub side_effect_type: Option<SideEffectType>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PlaceMarginOrderRequest<'a> {
+    #[serde(flatten)]
+    order: &'a MarginOrderRequest,
+    new_order_resp_type: OrderResponse,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LoanRequest {
+    asset: String,
+    amount: Number,
+}
+
+impl PortfolioMarginAccount {
+    // Test connectivity
+    pub async fn ping(&self) -> Result<String> {
+        let _: Success = self.client.get("/papi/v1/ping", None).await?;
+        Ok("pong".into())
+    }
+
+    /// Balances of every asset
+    pub async fn account_balance(&self) -> Result<Vec<Balance>> {
+        self.client
+            .get_signed_p("/papi/v1/balance", None::<()>, self.recv_window)
+            .await
+    }
+
+    /// Balance of a single asset
+    pub async fn asset_balance<S>(&self, asset: S) -> Result<Balance>
+    where
+        S: Into<String>,
+    {
+        let request = build_signed_request([("asset", asset.into())], self.recv_window)?;
+        self.client.get_signed("/papi/v1/balance", &request).await
+    }
+
+    /// Equity, margin ratio and status of the account
+    pub async fn account_information(&self) -> Result<AccountInformation> {
+        self.client
+            .get_signed_p("/papi/v1/account", None::<()>, self.recv_window)
+            .await
+    }
+
+    pub async fn place_um_order(&self, order: FuturesOrderRequest) -> Result<UmOrder> {
+        self.client
+            .post_signed_p("/papi/v1/um/order", order, self.recv_window)
+            .await
+    }
+
+    pub async fn cancel_um_order(&self, o: OrderCancellation) -> Result<UmOrder> {
+        let recv_window = o.recv_window.unwrap_or(self.recv_window);
+        self.client.delete_signed_p("/papi/v1/um/order", &o, recv_window).await
+    }
+
+    pub async fn place_cm_order(&self, order: FuturesOrderRequest) -> Result<CmOrder> {
+        self.client
+            .post_signed_p("/papi/v1/cm/order", order, self.recv_window)
+            .await
+    }
+
+    pub async fn cancel_cm_order(&self, o: OrderCancellation) -> Result<CmOrder> {
+        let recv_window = o.recv_window.unwrap_or(self.recv_window);
+        self.client.delete_signed_p("/papi/v1/cm/order", &o, recv_window).await
+    }
+
+    pub async fn place_margin_order(&self, order: MarginOrderRequest) -> Result<Transaction> {
+        // request the FULL response which is otherwise only returned for LIMIT and MARKET orders
+        let request = PlaceMarginOrderRequest {
+            order: &order,
+            new_order_resp_type: OrderResponse::Full,
+        };
+        self.client
+            .post_signed_p("/papi/v1/margin/order", request, self.recv_window)
+            .await
+    }
+
+    pub async fn cancel_margin_order(&self, o: OrderCancellation) -> Result<OrderCanceled> {
+        let recv_window = o.recv_window.unwrap_or(self.recv_window);
+        self.client
+            .delete_signed_p("/papi/v1/margin/order", &o, recv_window)
+            .await
+    }
+
+    /// Borrow `amount` of `asset` on the cross margin account
+    pub async fn borrow<S>(&self, asset: S, amount: Number) -> Result<TransactionId>
+    where
+        S: Into<String>,
+    {
+        let request = LoanRequest {
+            asset: asset.into(),
+            amount,
+        };
+        self.client
+            .post_signed_p("/papi/v1/marginLoan", request, self.recv_window)
+            .await
+    }
+
+    /// Repay `amount` of a cross margin loan of `asset`
+    pub async fn repay<S>(&self, asset: S, amount: Number) -> Result<TransactionId>
+    where
+        S: Into<String>,
+    {
+        let request = LoanRequest {
+            asset: asset.into(),
+            amount,
+        };
+        self.client
+            .post_signed_p("/papi/v1/repayLoan", request, self.recv_window)
+            .await
+    }
+}
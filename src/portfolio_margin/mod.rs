@@ -0,0 +1,7 @@
+//! Portfolio margin accounts, UM futures, CM futures and cross margin trading on one unified margin
+
+pub mod account;
+pub mod rest_model;
+pub mod userstream;
+pub mod websockets;
+pub mod ws_model;
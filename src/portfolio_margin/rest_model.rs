@@ -0,0 +1,142 @@
+use serde::{Deserialize, Serialize};
+
+pub use crate::futures::rest_model::{PositionSide, PriceMatch};
+use crate::rest_model::{string_or_float, string_or_float_opt};
+pub use crate::rest_model::{
+    Number, OrderCanceled, OrderResponse, OrderSide, OrderStatus, OrderType, SelfTradePreventionMode, SideEffectType,
+    Success, TimeInForce, Transaction, TransactionId, UserDataStream,
+};
+
+/// Balance of an asset across the margin, UM and CM wallets
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Balance {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub total_wallet_balance: Number,
+    #[serde(with = "string_or_float")]
+    pub cross_margin_asset: Number,
+    #[serde(with = "string_or_float")]
+    pub cross_margin_borrowed: Number,
+    #[serde(with = "string_or_float")]
+    pub cross_margin_free: Number,
+    #[serde(with = "string_or_float")]
+    pub cross_margin_interest: Number,
+    #[serde(with = "string_or_float")]
+    pub cross_margin_locked: Number,
+    #[serde(with = "string_or_float")]
+    pub um_wallet_balance: Number,
+    #[serde(with = "string_or_float", rename = "umUnrealizedPNL")]
+    pub um_unrealized_pnl: Number,
+    #[serde(with = "string_or_float")]
+    pub cm_wallet_balance: Number,
+    #[serde(with = "string_or_float", rename = "cmUnrealizedPNL")]
+    pub cm_unrealized_pnl: Number,
+    #[serde(default, with = "string_or_float_opt")]
+    pub negative_balance: Option<Number>,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AccountStatus {
+    Normal,
+    MarginCall,
+    SupplyMargin,
+    ReduceOnly,
+    ActiveLiquidation,
+    ForceLiquidation,
+    Bankrupted,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInformation {
+    /// Unified maintenance margin ratio, the account is liquidated below 1.05
+    #[serde(with = "string_or_float", rename = "uniMMR")]
+    pub uni_mmr: Number,
+    /// Equity in USD
+    #[serde(with = "string_or_float")]
+    pub account_equity: Number,
+    /// Equity in USD without the collateral haircut
+    #[serde(with = "string_or_float")]
+    pub actual_equity: Number,
+    #[serde(with = "string_or_float")]
+    pub account_initial_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub account_maint_margin: Number,
+    pub account_status: AccountStatus,
+    #[serde(with = "string_or_float")]
+    pub virtual_max_withdraw_amount: Number,
+    #[serde(with = "string_or_float")]
+    pub total_available_balance: Number,
+    #[serde(with = "string_or_float")]
+    pub total_margin_open_loss: Number,
+    pub update_time: u64,
+}
+
+/// A UM futures order as returned when placing or canceling it
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UmOrder {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    #[serde(with = "string_or_float")]
+    pub avg_price: Number,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub cum_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub cum_quote: Number,
+    pub reduce_only: bool,
+    pub side: OrderSide,
+    pub position_side: PositionSide,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    pub update_time: u64,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    pub price_match: Option<PriceMatch>,
+    /// Expiry of GTD orders, 0 otherwise
+    pub good_till_date: Option<u64>,
+}
+
+/// A CM futures order as returned when placing or canceling it, quantities are in contracts
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CmOrder {
+    pub symbol: String,
+    pub pair: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    #[serde(with = "string_or_float")]
+    pub avg_price: Number,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub cum_qty: Number,
+    /// Filled value in the base asset
+    #[serde(with = "string_or_float")]
+    pub cum_base: Number,
+    pub reduce_only: bool,
+    pub side: OrderSide,
+    pub position_side: PositionSide,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    pub update_time: u64,
+}
@@ -0,0 +1,25 @@
+use crate::client::*;
+use crate::errors::*;
+use crate::portfolio_margin::rest_model::*;
+
+static PORTFOLIO_MARGIN_USER_DATA_STREAM: &str = "/papi/v1/listenKey";
+
+/// One user data stream carries the UM, CM and margin events of the account,
+/// see [`crate::portfolio_margin::ws_model::PortfolioMarginWebsocketEvent`]
+#[derive(Clone)]
+pub struct PortfolioMarginUserStream {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl PortfolioMarginUserStream {
+    pub async fn start(&self) -> Result<UserDataStream> { self.client.post(PORTFOLIO_MARGIN_USER_DATA_STREAM, None).await }
+
+    pub async fn keep_alive(&self, listen_key: &str) -> Result<Success> {
+        self.client.put(PORTFOLIO_MARGIN_USER_DATA_STREAM, listen_key, None).await
+    }
+
+    pub async fn close(&self, listen_key: &str) -> Result<Success> {
+        self.client.delete(PORTFOLIO_MARGIN_USER_DATA_STREAM, listen_key, None).await
+    }
+}
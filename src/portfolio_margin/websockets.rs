@@ -0,0 +1,47 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::config::Config;
+use crate::errors::*;
+use crate::futures::websockets::FuturesWebSockets;
+use crate::websockets::{connect_split, endpoint_url, ControlSink, EventStream};
+
+/// Connect to the user data stream of `listen_key` and return its events as a [`futures::Stream`]
+pub async fn connect_user_stream<WE: serde::de::DeserializeOwned>(
+    conf: &Config,
+    listen_key: &str,
+) -> Result<(ControlSink, EventStream<WE>)> {
+    connect_split(endpoint_url(&conf.portfolio_margin_ws_endpoint, listen_key)?, conf).await
+}
+
+/// [`FuturesWebSockets`] connected to the portfolio margin user data stream,
+/// events deserialize into [`crate::portfolio_margin::ws_model::PortfolioMarginWebsocketEvent`]
+pub struct PortfolioMarginWebSockets<'a, WE>(FuturesWebSockets<'a, WE>);
+
+impl<'a, WE: serde::de::DeserializeOwned> PortfolioMarginWebSockets<'a, WE> {
+    /// New websocket holder with default configuration
+    pub fn new<Callback>(handler: Callback) -> PortfolioMarginWebSockets<'a, WE>
+    where
+        Callback: FnMut(WE) -> Result<()> + 'a + Send,
+    {
+        Self::new_with_options(handler, Config::default())
+    }
+
+    /// New websocket holder with provided configuration
+    pub fn new_with_options<Callback>(handler: Callback, conf: Config) -> PortfolioMarginWebSockets<'a, WE>
+    where
+        Callback: FnMut(WE) -> Result<()> + 'a + Send,
+    {
+        let ws_endpoint = conf.portfolio_margin_ws_endpoint.clone();
+        PortfolioMarginWebSockets(FuturesWebSockets::new_with_options(handler, conf).with_ws_endpoint(ws_endpoint))
+    }
+}
+
+impl<'a, WE> Deref for PortfolioMarginWebSockets<'a, WE> {
+    type Target = FuturesWebSockets<'a, WE>;
+
+    fn deref(&self) -> &Self::Target { &self.0 }
+}
+
+impl<WE> DerefMut for PortfolioMarginWebSockets<'_, WE> {
+    fn deref_mut(&mut self) -> &mut Self::Target { &mut self.0 }
+}
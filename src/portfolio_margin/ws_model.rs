@@ -0,0 +1,196 @@
+use crate::rest_model::{string_or_float, Number};
+
+pub use crate::futures::rest_model::{
+    OrderSide, OrderStatus, OrderType, PositionSide, PriceMatch, SelfTradePreventionMode, TimeInForce,
+};
+pub use crate::futures::ws_model::AccountUpdateBalancesInner;
+pub use crate::portfolio_margin::rest_model::AccountStatus;
+pub use crate::ws_model::{AccountPositionUpdate, BalanceUpdate, ListenKeyExpired, OrderUpdate};
+
+/// Events of the unified user data stream, futures events carry the business unit they come from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "e")]
+pub enum PortfolioMarginWebsocketEvent {
+    #[serde(rename = "ORDER_TRADE_UPDATE")]
+    OrderTradeUpdate(Box<OrderTradeUpdate>),
+    #[serde(rename = "ACCOUNT_UPDATE")]
+    AccountUpdate(Box<AccountUpdate>),
+    /// Margin order updates
+    #[serde(rename = "executionReport")]
+    OrderUpdate(Box<OrderUpdate>),
+    #[serde(rename = "outboundAccountPosition")]
+    AccountPositionUpdate(Box<AccountPositionUpdate>),
+    #[serde(rename = "balanceUpdate")]
+    BalanceUpdate(Box<BalanceUpdate>),
+    #[serde(rename = "liabilityChange")]
+    LiabilityChange(Box<LiabilityChange>),
+    #[serde(rename = "riskLevelChange")]
+    RiskLevelChange(Box<RiskLevelChange>),
+    #[serde(rename = "listenKeyExpired")]
+    ListenKeyExpired(Box<ListenKeyExpired>),
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum BusinessUnit {
+    Um,
+    Cm,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OrderTradeUpdate {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "T")]
+    pub transact_time: u64,
+    #[serde(rename = "fs")]
+    pub business_unit: BusinessUnit,
+    #[serde(rename = "o")]
+    pub order_trade: OrderTradeUpdateInner,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OrderTradeUpdateInner {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+    #[serde(rename = "S")]
+    pub side: OrderSide,
+    #[serde(rename = "o")]
+    pub order_type: OrderType,
+    #[serde(rename = "f")]
+    pub time_in_force: TimeInForce,
+    #[serde(rename = "q", with = "string_or_float")]
+    pub qty: Number,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub price: Number,
+    #[serde(rename = "ap", with = "string_or_float")]
+    pub avg_price: Number,
+    #[serde(rename = "sp", with = "string_or_float")]
+    pub stop_price: Number,
+    #[serde(rename = "x")]
+    pub execution_type: OrderStatus,
+    #[serde(rename = "X")]
+    pub order_status: OrderStatus,
+    #[serde(rename = "i")]
+    pub order_id: u64,
+    #[serde(rename = "l", with = "string_or_float")]
+    pub qty_last_executed: Number,
+    #[serde(rename = "z", with = "string_or_float")]
+    pub cumulative_filled_qty: Number,
+    #[serde(rename = "L", with = "string_or_float")]
+    pub last_executed_price: Number,
+    #[serde(rename = "N")]
+    pub commission_asset: Option<String>,
+    #[serde(rename = "n", with = "string_or_float")]
+    pub commission: Number,
+    #[serde(rename = "T")]
+    pub trade_order_time: u64,
+    #[serde(rename = "t")]
+    pub trade_id: i64,
+    #[serde(rename = "b", with = "string_or_float")]
+    pub bids_notional: Number,
+    #[serde(rename = "a", with = "string_or_float")]
+    pub asks_notional: Number,
+    #[serde(rename = "m")]
+    pub is_maker: bool,
+    #[serde(rename = "R")]
+    pub is_reduce: bool,
+    #[serde(rename = "ps")]
+    pub position_side: PositionSide,
+    #[serde(rename = "rp", with = "string_or_float")]
+    pub realized_profit: Number,
+    /// Type of the conditional order that triggered this order, if any
+    #[serde(rename = "st", default)]
+    pub strategy_type: Option<String>,
+    #[serde(rename = "si", default)]
+    pub strategy_id: Option<u64>,
+    #[serde(rename = "V")]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    #[serde(rename = "pm")]
+    pub price_match: Option<PriceMatch>,
+    /// Expiry of GTD orders, 0 otherwise
+    #[serde(rename = "gtd")]
+    pub good_till_date: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AccountUpdate {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "T")]
+    pub transact_time: u64,
+    #[serde(rename = "fs")]
+    pub business_unit: BusinessUnit,
+    /// Account alias, empty for UM updates
+    #[serde(rename = "i")]
+    pub account_alias: Option<String>,
+    #[serde(rename = "a")]
+    pub update_data: AccountUpdateData,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AccountUpdateData {
+    /// What caused the update, ex: ORDER, FUNDING_FEE, ADMIN_DEPOSIT
+    #[serde(rename = "m")]
+    pub event_reason_type: String,
+    #[serde(rename = "B")]
+    pub balances: Vec<AccountUpdateBalancesInner>,
+    #[serde(rename = "P")]
+    pub positions: Vec<AccountUpdatePosition>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AccountUpdatePosition {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "pa", with = "string_or_float")]
+    pub position_amount: Number,
+    #[serde(rename = "ep", with = "string_or_float")]
+    pub entry_price: Number,
+    #[serde(rename = "cr", with = "string_or_float")]
+    pub accumulated_realized: Number,
+    #[serde(rename = "up", with = "string_or_float")]
+    pub unrealized_pnl: Number,
+    #[serde(rename = "ps")]
+    pub position_side: PositionSide,
+    #[serde(rename = "bep", with = "string_or_float")]
+    pub break_even_price: Number,
+}
+
+/// A margin loan was taken, repaid or charged interest
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LiabilityChange {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "a")]
+    pub asset: String,
+    /// ex: BORROW, REPAY, INTEREST
+    #[serde(rename = "t")]
+    pub liability_type: String,
+    #[serde(rename = "T")]
+    pub tran_id: u64,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub principal: Number,
+    #[serde(rename = "i", with = "string_or_float")]
+    pub interest: Number,
+    #[serde(rename = "l", with = "string_or_float")]
+    pub total_liability: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RiskLevelChange {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "u", with = "string_or_float")]
+    pub uni_mmr: Number,
+    #[serde(rename = "s")]
+    pub status: AccountStatus,
+    #[serde(rename = "eq", with = "string_or_float")]
+    pub account_equity: Number,
+    #[serde(rename = "ae", with = "string_or_float")]
+    pub actual_equity: Number,
+    #[serde(rename = "m", with = "string_or_float")]
+    pub maint_margin: Number,
+}
@@ -0,0 +1,378 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use reqwest::header::HeaderMap;
+use reqwest::Method;
+
+use crate::errors::*;
+use crate::rest_model::{RateLimit, RateLimitInterval, RateLimitType};
+
+/// What to do when a request would exceed a known limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitMode {
+    /// Wait until the limit's window resets before sending the request
+    Throttle,
+    /// Return [`Error::RateLimited`] without sending the request
+    FailFast,
+}
+
+/// Usage of a single limit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitUsage {
+    pub rate_limit_type: RateLimitType,
+    pub interval: RateLimitInterval,
+    pub interval_num: u32,
+    /// `None` when the limit is only known from response headers
+    pub limit: Option<u32>,
+    pub used: u32,
+}
+
+impl RateLimitUsage {
+    /// Remaining budget in the current window, `None` if the limit is unknown
+    pub fn remaining(&self) -> Option<u32> { self.limit.map(|limit| limit.saturating_sub(self.used)) }
+}
+
+/// Weight and order count of a request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestCost {
+    pub weight: u32,
+    pub orders: u32,
+}
+
+#[derive(Debug, Clone)]
+struct Window {
+    rate_limit_type: RateLimitType,
+    interval: RateLimitInterval,
+    interval_num: u32,
+    limit: Option<u32>,
+    used: u32,
+    window: u64,
+}
+
+impl Window {
+    fn length_ms(&self) -> u64 { interval_millis(&self.interval) * u64::from(self.interval_num.max(1)) }
+
+    /// Reset the counter when entering a new window, windows are aligned on the epoch like Binance's
+    fn roll(&mut self, now_ms: u64) {
+        let window = now_ms / self.length_ms();
+        if window != self.window {
+            self.window = window;
+            self.used = 0;
+        }
+    }
+
+    fn cost(&self, cost: RequestCost) -> u32 {
+        match self.rate_limit_type {
+            RateLimitType::RequestWeight => cost.weight,
+            RateLimitType::Orders => cost.orders,
+            RateLimitType::RawRequests => 1,
+            RateLimitType::Other => 0,
+        }
+    }
+
+    fn retry_after(&self, now_ms: u64) -> Duration {
+        Duration::from_millis((self.window + 1) * self.length_ms() - now_ms)
+    }
+}
+
+fn interval_millis(interval: &RateLimitInterval) -> u64 {
+    match interval {
+        RateLimitInterval::Second => 1000,
+        RateLimitInterval::Minute => 60 * 1000,
+        RateLimitInterval::Day => 24 * 60 * 60 * 1000,
+    }
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+/// Client side rate limiter, shared by every API handle built from the same [`crate::config::Config`]
+///
+/// Limits are tracked per REST host, since spot and futures APIs have separate budgets.
+/// Usage is counted locally from the weight of each endpoint and corrected with the
+/// `X-MBX-USED-WEIGHT-*` and `X-MBX-ORDER-COUNT-*` headers of every response.
+/// # Examples
+/// ```rust,no_run
+/// use binance::{api::*, config::*, general::*, rate_limit::*};
+/// let limiter = RateLimiter::new(RateLimitMode::Throttle);
+/// let conf = Config::default().set_rate_limiter(limiter.clone());
+/// let general: General = Binance::new_with_config(None, None, &conf);
+/// let info = tokio_test::block_on(general.exchange_info()).unwrap();
+/// limiter.set_limits(&conf.rest_api_endpoint, &info.rate_limits);
+/// println!("{:?}", limiter.usage(&conf.rest_api_endpoint));
+/// ```
+#[derive(Clone)]
+pub struct RateLimiter {
+    mode: RateLimitMode,
+    hosts: Arc<Mutex<HashMap<String, Vec<Window>>>>,
+}
+
+impl RateLimiter {
+    pub fn new(mode: RateLimitMode) -> Self {
+        RateLimiter {
+            mode,
+            hosts: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Set the limits of a host, usually the `rate_limits` of its exchange information
+    pub fn set_limits(&self, host: &str, limits: &[RateLimit]) {
+        let mut hosts = self.hosts.lock().unwrap();
+        let windows = hosts.entry(host.to_string()).or_default();
+        for limit in limits {
+            let interval_num = limit.interval_num.max(1) as u32;
+            let value = Some(limit.limit.max(0) as u32);
+            match windows.iter_mut().find(|w| {
+                w.rate_limit_type == limit.rate_limit_type && w.interval == limit.interval && w.interval_num == interval_num
+            }) {
+                Some(window) => window.limit = value,
+                None => windows.push(Window {
+                    rate_limit_type: limit.rate_limit_type.clone(),
+                    interval: limit.interval.clone(),
+                    interval_num,
+                    limit: value,
+                    used: 0,
+                    window: 0,
+                }),
+            }
+        }
+    }
+
+    /// Current usage of every known limit of a host
+    pub fn usage(&self, host: &str) -> Vec<RateLimitUsage> {
+        let now_ms = now_millis();
+        let mut hosts = self.hosts.lock().unwrap();
+        hosts
+            .get_mut(host)
+            .map(|windows| {
+                windows
+                    .iter_mut()
+                    .map(|w| {
+                        w.roll(now_ms);
+                        RateLimitUsage {
+                            rate_limit_type: w.rate_limit_type.clone(),
+                            interval: w.interval.clone(),
+                            interval_num: w.interval_num,
+                            limit: w.limit,
+                            used: w.used,
+                        }
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Reserve the cost of a request, waiting or failing according to the mode when a limit would be exceeded
+    pub async fn acquire(&self, host: &str, cost: RequestCost) -> Result<()> {
+        loop {
+            let wait = self.try_acquire(host, cost);
+            match (wait, self.mode) {
+                (None, _) => return Ok(()),
+                (Some(retry_after), RateLimitMode::FailFast) => {
+                    return Err(Error::RateLimited {
+                        retry_after: Some(retry_after),
+                    })
+                }
+                (Some(retry_after), RateLimitMode::Throttle) => tokio::time::sleep(retry_after).await,
+            }
+        }
+    }
+
+    /// Reserve the cost of a request, or return how long to wait before it fits in every window
+    fn try_acquire(&self, host: &str, cost: RequestCost) -> Option<Duration> {
+        let now_ms = now_millis();
+        let mut hosts = self.hosts.lock().unwrap();
+        let windows = hosts.get_mut(host)?;
+        let mut wait = None;
+        for w in windows.iter_mut() {
+            w.roll(now_ms);
+            match w.limit {
+                // a request heavier than the limit itself is left for the server to reject
+                Some(limit) if w.used + w.cost(cost) > limit && w.cost(cost) <= limit => {
+                    wait = wait.max(Some(w.retry_after(now_ms)));
+                }
+                _ => {}
+            }
+        }
+        if wait.is_none() {
+            for w in windows.iter_mut() {
+                w.used += w.cost(cost);
+            }
+        }
+        wait
+    }
+
+    /// Replace local counts with the usage reported by the server
+    pub fn update_from_headers(&self, host: &str, headers: &HeaderMap) {
+        let now_ms = now_millis();
+        let mut hosts = self.hosts.lock().unwrap();
+        let windows = hosts.entry(host.to_string()).or_default();
+        for (name, value) in headers {
+            let name = name.as_str();
+            let (rate_limit_type, suffix) = if let Some(suffix) = name.strip_prefix("x-mbx-used-weight-") {
+                (RateLimitType::RequestWeight, suffix)
+            } else if let Some(suffix) = name.strip_prefix("x-mbx-order-count-") {
+                (RateLimitType::Orders, suffix)
+            } else {
+                continue;
+            };
+            let (Some((interval, interval_num)), Some(used)) = (
+                parse_interval(suffix),
+                value.to_str().ok().and_then(|v| v.parse::<u32>().ok()),
+            ) else {
+                continue;
+            };
+            let window = match windows.iter_mut().position(|w| {
+                w.rate_limit_type == rate_limit_type && w.interval == interval && w.interval_num == interval_num
+            }) {
+                Some(i) => &mut windows[i],
+                None => {
+                    windows.push(Window {
+                        rate_limit_type,
+                        interval,
+                        interval_num,
+                        limit: None,
+                        used: 0,
+                        window: 0,
+                    });
+                    windows.last_mut().unwrap()
+                }
+            };
+            window.roll(now_ms);
+            window.used = used;
+        }
+    }
+}
+
+/// Parse a header interval suffix such as `1m` or `10s`
+fn parse_interval(suffix: &str) -> Option<(RateLimitInterval, u32)> {
+    let (num, unit) = suffix.split_at(suffix.len().checked_sub(1)?);
+    let num = num.parse().ok()?;
+    match unit {
+        "s" => Some((RateLimitInterval::Second, num)),
+        "m" => Some((RateLimitInterval::Minute, num)),
+        "h" => Some((RateLimitInterval::Minute, num * 60)),
+        "d" => Some((RateLimitInterval::Day, num)),
+        _ => None,
+    }
+}
+
+impl fmt::Debug for RateLimiter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RateLimiter").field("mode", &self.mode).finish()
+    }
+}
+
+/// Limiters are equal when they share the same state
+impl PartialEq for RateLimiter {
+    fn eq(&self, other: &Self) -> bool { Arc::ptr_eq(&self.hosts, &other.hosts) }
+}
+
+impl Eq for RateLimiter {}
+
+fn query_param<'q>(query: &'q str, key: &str) -> Option<&'q str> {
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(k, _)| *k == key)
+        .map(|(_, v)| v)
+}
+
+/// Number of orders in the json list sent as `batchOrders`, the maximum of 5 when it can't be read
+fn batch_size(query: &str) -> u32 {
+    url::form_urlencoded::parse(query.as_bytes())
+        .find(|(key, _)| key == "batchOrders")
+        .and_then(|(_, orders)| serde_json::from_str::<Vec<serde_json::Value>>(&orders).ok())
+        .map_or(5, |orders| orders.len() as u32)
+}
+
+/// Weight and order count of an endpoint, as documented by Binance.
+/// `/sapi` endpoints are not counted since they use separate limits
+pub fn request_cost(method: &Method, endpoint: &str, query: &str) -> RequestCost {
+    let has_symbol = query_param(query, "symbol").is_some();
+    let limit = query_param(query, "limit").and_then(|l| l.parse::<u32>().ok());
+    let by_symbol = |with: u32, without: u32| if has_symbol { with } else { without };
+    let order = |weight| RequestCost { weight, orders: 1 };
+    let weight = |weight| RequestCost { weight, orders: 0 };
+    match (method.as_str(), endpoint) {
+        (_, e) if e.starts_with("/sapi") => weight(0),
+        // spot
+        ("GET", "/api/v3/exchangeInfo") => weight(20),
+        ("GET", "/api/v3/depth") => weight(match limit.unwrap_or(100) {
+            0..=100 => 5,
+            101..=500 => 25,
+            501..=1000 => 50,
+            _ => 250,
+        }),
+        ("GET", "/api/v3/trades" | "/api/v3/historicalTrades") => weight(25),
+        ("GET", "/api/v3/aggTrades" | "/api/v3/klines" | "/api/v3/uiKlines" | "/api/v3/avgPrice") => weight(2),
+        ("GET", "/api/v3/ticker/24hr") => weight(by_symbol(2, 80)),
+        ("GET", "/api/v3/ticker/price" | "/api/v3/ticker/bookTicker") => weight(by_symbol(2, 4)),
+        ("POST", "/api/v3/order" | "/api/v3/order/cancelReplace") => order(1),
+        ("POST", "/api/v3/orderList/otoco") => RequestCost { weight: 1, orders: 3 },
+        ("POST", e) if e.starts_with("/api/v3/orderList") || e.starts_with("/api/v3/order/oco") => {
+            RequestCost { weight: 1, orders: 2 }
+        }
+        ("GET", "/api/v3/order" | "/api/v3/orderList") => weight(4),
+        ("GET", "/api/v3/openOrders") => weight(by_symbol(6, 80)),
+        ("GET", "/api/v3/openOrderList") => weight(6),
+        ("GET", "/api/v3/allOrderList") => weight(20),
+        ("GET", "/api/v3/allOrders" | "/api/v3/account" | "/api/v3/myTrades") => weight(20),
+        ("POST" | "PUT" | "DELETE", "/api/v3/userDataStream") => weight(2),
+        // usd-m futures
+        ("GET", "/fapi/v1/depth") => weight(match limit.unwrap_or(500) {
+            0..=50 => 2,
+            51..=100 => 5,
+            101..=500 => 10,
+            _ => 20,
+        }),
+        ("GET", "/fapi/v1/klines" | "/fapi/v1/continuousKlines" | "/fapi/v1/indexPriceKlines" | "/fapi/v1/markPriceKlines") => {
+            weight(match limit.unwrap_or(500) {
+                0..=99 => 1,
+                100..=499 => 2,
+                500..=1000 => 5,
+                _ => 10,
+            })
+        }
+        ("GET", "/fapi/v1/trades") => weight(5),
+        ("GET", "/fapi/v1/historicalTrades" | "/fapi/v1/aggTrades") => weight(20),
+        ("GET", "/fapi/v1/premiumIndex") => weight(by_symbol(1, 10)),
+        ("GET", "/fapi/v1/ticker/24hr") => weight(by_symbol(1, 40)),
+        ("GET", "/fapi/v1/ticker/price") => weight(by_symbol(1, 2)),
+        ("GET", "/fapi/v1/ticker/bookTicker") => weight(by_symbol(2, 5)),
+        ("POST" | "PUT", "/fapi/v1/order") => order(1),
+        ("POST", "/fapi/v1/batchOrders") => RequestCost {
+            weight: 5,
+            orders: batch_size(query),
+        },
+        ("GET", "/fapi/v1/openOrders") => weight(by_symbol(1, 40)),
+        ("GET", "/fapi/v1/allOrders" | "/fapi/v1/userTrades") => weight(5),
+        ("GET", "/fapi/v2/account" | "/fapi/v2/balance" | "/fapi/v2/positionRisk") => weight(5),
+        ("GET", "/fapi/v1/income" | "/fapi/v1/multiAssetsMargin") => weight(30),
+        ("GET", "/fapi/v1/commissionRate") => weight(20),
+        ("GET", "/fapi/v1/adlQuantile") => weight(5),
+        ("POST", "/fapi/v1/countdownCancelAll") => weight(10),
+        // options
+        ("GET", "/eapi/v1/depth") => weight(match limit.unwrap_or(100) {
+            0..=100 => 2,
+            101..=500 => 5,
+            _ => 10,
+        }),
+        ("GET", "/eapi/v1/trades" | "/eapi/v1/mark" | "/eapi/v1/ticker") => weight(5),
+        ("POST", "/eapi/v1/order") => order(1),
+        ("GET", "/eapi/v1/openOrders") => weight(by_symbol(1, 40)),
+        ("GET", "/eapi/v1/account") => weight(3),
+        ("GET", "/eapi/v1/position" | "/eapi/v1/userTrades") => weight(5),
+        // portfolio margin
+        ("POST", "/papi/v1/um/order" | "/papi/v1/cm/order" | "/papi/v1/margin/order") => order(1),
+        ("GET", "/papi/v1/balance" | "/papi/v1/account") => weight(20),
+        ("POST", "/papi/v1/marginLoan" | "/papi/v1/repayLoan") => weight(100),
+        _ => weight(1),
+    }
+}
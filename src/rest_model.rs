@@ -2,6 +2,13 @@ use chrono::{DateTime, Utc};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use std::collections::HashMap;
 
+/// Numeric type of prices and quantities, `rust_decimal::Decimal` with the `decimal` feature
+#[cfg(not(feature = "decimal"))]
+pub type Number = f64;
+/// Numeric type of prices and quantities, `rust_decimal::Decimal` with the `decimal` feature
+#[cfg(feature = "decimal")]
+pub type Number = rust_decimal::Decimal;
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ServerTime {
@@ -22,7 +29,7 @@ pub struct ExchangeInformation {
 #[serde(rename_all = "camelCase")]
 pub struct Symbol {
     pub symbol: String,
-    pub status: String,
+    pub status: SymbolStatus,
     pub base_asset: String,
     pub base_asset_precision: u64,
     pub quote_asset: String,
@@ -63,49 +70,60 @@ pub enum Filters {
     #[serde(rename_all = "camelCase")]
     PriceFilter {
         #[serde(with = "string_or_float")]
-        min_price: f64,
+        min_price: Number,
         #[serde(with = "string_or_float")]
-        max_price: f64,
+        max_price: Number,
         #[serde(with = "string_or_float")]
-        tick_size: f64,
+        tick_size: Number,
     },
     #[serde(rename = "PERCENT_PRICE")]
     #[serde(rename_all = "camelCase")]
     PercentPrice {
         #[serde(with = "string_or_float")]
-        multiplier_up: f64,
+        multiplier_up: Number,
         #[serde(with = "string_or_float")]
-        multiplier_down: f64,
+        multiplier_down: Number,
         avg_price_mins: u64,
     },
     #[serde(rename = "LOT_SIZE")]
     #[serde(rename_all = "camelCase")]
     LotSize {
         #[serde(with = "string_or_float")]
-        min_qty: f64,
+        min_qty: Number,
         #[serde(with = "string_or_float")]
-        max_qty: f64,
+        max_qty: Number,
         #[serde(with = "string_or_float")]
-        step_size: f64,
+        step_size: Number,
     },
     #[serde(rename = "MARKET_LOT_SIZE")]
     #[serde(rename_all = "camelCase")]
     MarketLotSize {
         #[serde(with = "string_or_float")]
-        min_qty: f64,
+        min_qty: Number,
         #[serde(with = "string_or_float")]
-        max_qty: f64,
+        max_qty: Number,
         #[serde(with = "string_or_float")]
-        step_size: f64,
+        step_size: Number,
     },
     #[serde(rename = "MIN_NOTIONAL")]
     #[serde(rename_all = "camelCase")]
     MinNotional {
         #[serde(with = "string_or_float")]
-        min_notional: f64,
+        min_notional: Number,
         apply_to_market: bool,
         avg_price_mins: u64,
     },
+    #[serde(rename = "NOTIONAL")]
+    #[serde(rename_all = "camelCase")]
+    Notional {
+        #[serde(with = "string_or_float")]
+        min_notional: Number,
+        apply_min_to_market: bool,
+        #[serde(with = "string_or_float")]
+        max_notional: Number,
+        apply_max_to_market: bool,
+        avg_price_mins: u64,
+    },
     #[serde(rename = "ICEBERG_PARTS")]
     #[serde(rename_all = "camelCase")]
     IcebergParts { limit: u16 },
@@ -122,7 +140,7 @@ pub enum Filters {
     #[serde(rename_all = "camelCase")]
     MaxPosition {
         #[serde(with = "string_or_float")]
-        max_position: f64,
+        max_position: Number,
     },
     #[serde(rename = "EXCHANGE_MAX_NUM_ORDERS")]
     #[serde(rename_all = "camelCase")]
@@ -141,13 +159,34 @@ pub struct AccountInformation {
     pub taker_commission: f32,
     pub buyer_commission: f32,
     pub seller_commission: f32,
+    pub commission_rates: Option<CommissionRates>,
     pub can_trade: bool,
     pub can_withdraw: bool,
     pub can_deposit: bool,
+    #[serde(default)]
+    pub brokered: bool,
+    #[serde(default)]
+    pub require_self_trade_prevention: bool,
+    #[serde(default)]
+    pub prevent_sor: bool,
     pub account_type: AccountType,
     pub balances: Vec<Balance>,
     pub permissions: Vec<AccountType>,
     pub update_time: i64,
+    pub uid: Option<u64>,
+}
+
+/// Commission rates of an account, as fractions of the traded amount
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CommissionRates {
+    #[serde(with = "string_or_float")]
+    pub maker: Number,
+    #[serde(with = "string_or_float")]
+    pub taker: Number,
+    #[serde(with = "string_or_float")]
+    pub buyer: Number,
+    #[serde(with = "string_or_float")]
+    pub seller: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
@@ -175,9 +214,9 @@ pub enum AccountType {
 pub struct Balance {
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub free: f64,
+    pub free: Number,
     #[serde(with = "string_or_float")]
-    pub locked: f64,
+    pub locked: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -188,27 +227,29 @@ pub struct Order {
     pub order_list_id: i32,
     pub client_order_id: String,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Number,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Number,
     #[serde(with = "string_or_float")]
-    pub cummulative_quote_qty: f64,
+    pub cummulative_quote_qty: Number,
     pub status: OrderStatus,
     pub time_in_force: TimeInForce,
     #[serde(rename = "type")]
     pub order_type: OrderType,
     pub side: OrderSide,
     #[serde(with = "string_or_float")]
-    pub stop_price: f64,
+    pub stop_price: Number,
     #[serde(with = "string_or_float")]
-    pub iceberg_qty: f64,
+    pub iceberg_qty: Number,
     pub time: u64,
     pub update_time: u64,
     pub is_working: bool,
+    pub working_time: Option<i64>,
     #[serde(with = "string_or_float")]
-    pub orig_quote_order_qty: f64,
+    pub orig_quote_order_qty: Number,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -217,7 +258,23 @@ pub struct OrderCanceled {
     pub symbol: String,
     pub orig_client_order_id: String,
     pub order_id: u64,
+    pub order_list_id: Option<i64>,
     pub client_order_id: String,
+    pub transact_time: Option<u64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub price: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub orig_qty: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub executed_qty: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub cummulative_quote_qty: Option<Number>,
+    pub status: Option<OrderStatus>,
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(rename = "type")]
+    pub order_type: Option<OrderType>,
+    pub side: Option<OrderSide>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -233,12 +290,13 @@ pub struct OrderCanceledReplaced {
 #[serde(rename_all = "camelCase")]
 pub struct Fill {
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
     #[serde(with = "string_or_float")]
-    pub commission: f64,
+    pub commission: Number,
     pub commission_asset: String,
+    pub trade_id: Option<u64>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -246,21 +304,24 @@ pub struct Fill {
 pub struct Transaction {
     pub symbol: String,
     pub order_id: u64,
+    pub order_list_id: Option<i64>,
     pub client_order_id: String,
     pub transact_time: u64,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Number,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Number,
     #[serde(with = "string_or_float")]
-    pub cummulative_quote_qty: f64,
+    pub cummulative_quote_qty: Number,
     pub status: OrderStatus,
     pub time_in_force: TimeInForce,
     #[serde(rename = "type")]
     pub order_type: OrderType,
     pub side: OrderSide,
+    pub working_time: Option<i64>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
     pub fills: Vec<Fill>,
 }
 
@@ -289,17 +350,17 @@ pub struct OrderBook {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Bids {
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Asks {
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -322,14 +383,17 @@ pub enum Prices {
 pub struct SymbolPrice {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct AveragePrice {
     pub mins: u64,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
+    /// Time of the last trade in the average
+    #[serde(rename = "closeTime")]
+    pub close_time: Option<u64>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -349,23 +413,28 @@ pub enum KlineSummaries {
 pub struct Tickers {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub bid_price: f64,
+    pub bid_price: Number,
     #[serde(with = "string_or_float")]
-    pub bid_qty: f64,
+    pub bid_qty: Number,
     #[serde(with = "string_or_float")]
-    pub ask_price: f64,
+    pub ask_price: Number,
     #[serde(with = "string_or_float")]
-    pub ask_qty: f64,
+    pub ask_qty: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct TradeHistory {
+    pub symbol: Option<String>,
     pub id: u64,
+    pub order_id: Option<u64>,
+    pub order_list_id: Option<i64>,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
+    #[serde(default, with = "string_or_float_opt")]
+    pub quote_qty: Option<Number>,
     pub commission: String,
     pub commission_asset: String,
     pub time: u64,
@@ -377,25 +446,34 @@ pub struct TradeHistory {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct PriceStats {
+    pub symbol: String,
     pub price_change: String,
     pub price_change_percent: String,
     pub weighted_avg_price: String,
     #[serde(with = "string_or_float")]
-    pub prev_close_price: f64,
+    pub prev_close_price: Number,
+    #[serde(with = "string_or_float")]
+    pub last_price: Number,
+    #[serde(with = "string_or_float")]
+    pub last_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub bid_price: Number,
     #[serde(with = "string_or_float")]
-    pub last_price: f64,
+    pub bid_qty: Number,
     #[serde(with = "string_or_float")]
-    pub bid_price: f64,
+    pub ask_price: Number,
     #[serde(with = "string_or_float")]
-    pub ask_price: f64,
+    pub ask_qty: Number,
     #[serde(with = "string_or_float")]
-    pub open_price: f64,
+    pub open_price: Number,
     #[serde(with = "string_or_float")]
-    pub high_price: f64,
+    pub high_price: Number,
     #[serde(with = "string_or_float")]
-    pub low_price: f64,
+    pub low_price: Number,
     #[serde(with = "string_or_float")]
-    pub volume: f64,
+    pub volume: Number,
+    #[serde(with = "string_or_float")]
+    pub quote_volume: Number,
     pub open_time: u64,
     pub close_time: u64,
     pub first_id: u64,
@@ -418,9 +496,9 @@ pub struct AggTrade {
     #[serde(rename = "M")]
     pub best_match: bool,
     #[serde(rename = "p", with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(rename = "q", with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
 }
 
 #[derive(Debug, Serialize_repr, Deserialize_repr, Clone, PartialEq, Eq)]
@@ -434,7 +512,7 @@ pub enum MarginTransferType {
 #[serde(rename_all = "camelCase")]
 pub struct Transfer {
     pub asset: String,
-    pub amount: f64,
+    pub amount: Number,
     #[serde(rename(serialize = "type", deserialize = "type"))]
     pub transfer_type: MarginTransferType,
 }
@@ -451,7 +529,7 @@ pub enum IsolatedMarginTransferType {
 pub struct IsolatedTransfer {
     pub asset: String,
     pub symbol: String,
-    pub amount: f64,
+    pub amount: Number,
     pub trans_from: IsolatedMarginTransferType,
     pub trans_to: IsolatedMarginTransferType,
 }
@@ -460,7 +538,7 @@ pub struct IsolatedTransfer {
 #[serde(rename_all = "camelCase")]
 pub struct Loan {
     pub asset: String,
-    pub amount: f64,
+    pub amount: Number,
     pub is_isolated: Option<String>,
     pub symbol: Option<String>,
 }
@@ -476,6 +554,21 @@ pub enum TimeInForce {
     FOK,
     /// Good till expired
     GTX,
+    /// Good till date
+    GTD,
+    #[serde(other)]
+    Other,
+}
+
+/// What happens when an order would match an order of the same account
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SelfTradePreventionMode {
+    None,
+    ExpireTaker,
+    ExpireMaker,
+    ExpireBoth,
+    Decrement,
     #[serde(other)]
     Other,
 }
@@ -567,14 +660,14 @@ pub struct MarginOrder {
     pub side: OrderSide,
     #[serde(rename(serialize = "type", deserialize = "type"))]
     pub order_type: OrderType,
-    pub quantity: Option<f64>,
-    pub quote_order_qty: Option<f64>,
-    pub price: Option<f64>,
+    pub quantity: Option<Number>,
+    pub quote_order_qty: Option<Number>,
+    pub price: Option<Number>,
     /// Used with `OrderType::StopLoss`, `OrderType::StopLossLimit`, `OrderType::TakeProfit` and `OrderType::TakeProfitLimit`
-    pub stop_price: Option<f64>,
+    pub stop_price: Option<Number>,
     pub new_client_order_id: Option<String>,
     /// Used with `OrderType::Limit`, `OrderType::StopLossLimit` and `OrderType::TakeProfitLimit` to create an iceberg order
-    pub iceberg_qty: Option<f64>,
+    pub iceberg_qty: Option<Number>,
     /// Default is `OrderResponse::ACK`
     pub new_order_resp_type: OrderResponse,
     /// N.B. : do not set with `OrderType::Market`
@@ -602,23 +695,27 @@ pub struct MarginOrderCancellationResult {
     pub order_id: Option<u64>,
     pub orig_client_order_id: Option<String>,
     pub client_order_id: Option<String>,
-    #[serde(with = "string_or_float_opt")]
-    pub price: Option<f64>,
-    #[serde(with = "string_or_float_opt")]
-    pub orig_qty: Option<f64>,
-    #[serde(with = "string_or_float_opt")]
-    pub executed_qty: Option<f64>,
-    #[serde(with = "string_or_float_opt")]
-    pub cummulative_quote_qty: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub price: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub orig_qty: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub executed_qty: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub cummulative_quote_qty: Option<Number>,
     pub status: Option<OrderStatus>,
     pub time_in_force: Option<TimeInForce>,
     #[serde(rename(serialize = "type", deserialize = "type"))]
     pub order_type: Option<OrderType>,
     pub side: Option<OrderSide>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
     pub is_isolated: Option<bool>,
     pub order_list_id: Option<i64>,
     pub transaction_time: Option<u64>,
     pub contingency_type: Option<ContingencyType>,
+    pub list_status_type: Option<OCOStatus>,
+    pub list_order_status: Option<OCOOrderStatus>,
+    pub list_client_order_id: Option<String>,
     pub orders: Option<Vec<OCOOrderDetail>>,
     pub order_reports: Option<Vec<OCOOrderReport>>,
 }
@@ -641,16 +738,16 @@ pub struct MarginOCOOrder {
     /// A unique identifier that will be applied to all orders
     pub list_client_order_id: Option<String>,
     pub side: OrderSide,
-    pub quantity: f64,
+    pub quantity: Number,
     /// A unique identifier that will be applied to the limit order
     pub limit_client_order_id: Option<String>,
-    pub price: f64,
-    pub limit_iceberg_qty: Option<f64>,
+    pub price: Number,
+    pub limit_iceberg_qty: Option<Number>,
     /// A unique identifier that will be applied to the stop order
     pub stop_client_order_id: Option<String>,
-    pub stop_price: f64,
-    pub stop_limit_price: Option<f64>,
-    pub stop_iceberg_qty: Option<f64>,
+    pub stop_price: Number,
+    pub stop_limit_price: Option<Number>,
+    pub stop_iceberg_qty: Option<Number>,
     pub stop_limit_time_in_force: Option<TimeInForce>,
     /// Default is `OrderResponse::ACK`
     pub new_order_resp_type: Option<OrderResponse>,
@@ -669,13 +766,30 @@ pub struct MarginOCOOrderResult {
     pub transaction_time: u128,
     pub symbol: String,
     #[serde(default, with = "string_or_float_opt")]
-    pub margin_buy_borrow_amount: Option<f64>,
+    pub margin_buy_borrow_amount: Option<Number>,
     pub margin_buy_borrow_asset: Option<String>,
     pub is_isolated: Option<bool>,
     pub orders: Vec<OCOOrderDetail>,
     pub order_reports: Vec<OCOOrderReport>,
 }
 
+/// A spot OCO, OTO or OTOCO order list,
+/// `order_reports` is only returned when placing or canceling the list
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderList {
+    pub order_list_id: u64,
+    pub contingency_type: ContingencyType,
+    pub list_status_type: OCOStatus,
+    pub list_order_status: OCOOrderStatus,
+    pub list_client_order_id: Option<String>,
+    pub transaction_time: u128,
+    pub symbol: String,
+    pub orders: Vec<OCOOrderDetail>,
+    #[serde(default)]
+    pub order_reports: Vec<OCOOrderReport>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct OCOOrderDetail {
@@ -688,26 +802,34 @@ pub struct OCOOrderDetail {
 #[serde(rename_all = "camelCase")]
 pub struct OCOOrderReport {
     pub symbol: String,
+    /// Only returned when canceling the list
+    pub orig_client_order_id: Option<String>,
     pub order_id: u64,
+    pub order_list_id: Option<i64>,
     pub client_order_id: Option<String>,
+    /// Not returned when canceling the list
+    #[serde(default)]
     pub transact_time: u128,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Number,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Number,
     #[serde(with = "string_or_float")]
-    pub cummulative_quote_qty: f64,
+    pub cummulative_quote_qty: Number,
     pub status: OrderStatus,
     pub time_in_force: TimeInForce,
     #[serde(rename = "type")]
     pub order_type: OrderType,
     pub side: OrderSide,
     #[serde(default, with = "string_or_float_opt")]
-    pub stop_price: Option<f64>,
+    pub stop_price: Option<Number>,
     #[serde(default, with = "string_or_float_opt")]
-    pub iceberg_qty: Option<f64>,
+    pub iceberg_qty: Option<Number>,
+    /// -1 while a pending order of an OTO or OTOCO list is not working
+    pub working_time: Option<i64>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
 }
 
 /// archived and is_isolated are only applicable to certain endpoints
@@ -782,12 +904,12 @@ pub struct IsolatedTransfersQuery {
 #[serde(rename_all = "camelCase")]
 pub struct RepayState {
     #[serde(with = "string_or_float")]
-    pub amount: f64,
+    pub amount: Number,
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub interest: f64,
+    pub interest: Number,
     #[serde(with = "string_or_float")]
-    pub principal: f64,
+    pub principal: Number,
     pub status: TransactionStatus,
     pub timestamp: u64,
     pub tx_id: u64,
@@ -809,7 +931,7 @@ pub enum TransactionStatus {
 pub struct LoanState {
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub principal: f64,
+    pub principal: Number,
     pub timestamp: u64,
     pub status: TransactionStatus,
     pub isolated_symbol: Option<String>,
@@ -830,7 +952,7 @@ pub enum TransferType {
 #[serde(rename_all = "camelCase")]
 pub struct OrderState {
     #[serde(with = "string_or_float")]
-    pub amount: f64,
+    pub amount: Number,
     pub asset: String,
     pub status: TransactionStatus,
     pub timestamp: u64,
@@ -859,12 +981,12 @@ pub enum InterestType {
 pub struct InterestState {
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub interest: f64,
+    pub interest: Number,
     pub interest_accured_time: u64,
     #[serde(with = "string_or_float")]
-    pub interest_rate: f64,
+    pub interest_rate: Number,
     #[serde(with = "string_or_float")]
-    pub principal: f64,
+    pub principal: Number,
     #[serde(rename = "type")]
     pub interest_type: InterestType,
     pub isolated_symbol: Option<String>,
@@ -874,14 +996,14 @@ pub struct InterestState {
 #[serde(rename_all = "camelCase")]
 pub struct ForcedLiquidationState {
     #[serde(with = "string_or_float")]
-    pub avg_price: f64,
+    pub avg_price: Number,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Number,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
     pub side: OrderSide,
     pub symbol: String,
     pub time_in_force: TimeInForce,
@@ -901,15 +1023,15 @@ pub struct RecordsQueryResult<R> {
 pub struct UserAsset {
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub borrowed: f64,
+    pub borrowed: Number,
     #[serde(with = "string_or_float")]
-    pub free: f64,
+    pub free: Number,
     #[serde(with = "string_or_float")]
-    pub interest: f64,
+    pub interest: Number,
     #[serde(with = "string_or_float")]
-    pub locked: f64,
+    pub locked: Number,
     #[serde(with = "string_or_float")]
-    pub net_asset: f64,
+    pub net_asset: Number,
 }
 
 pub type UserAssets = Vec<UserAsset>;
@@ -917,17 +1039,30 @@ pub type UserAssets = Vec<UserAsset>;
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct MarginAccountDetails {
+    /// Whether the margin account was created
+    pub created: Option<bool>,
     pub borrow_enabled: bool,
     #[serde(with = "string_or_float")]
-    pub margin_level: f64,
+    pub margin_level: Number,
+    #[serde(default, with = "string_or_float_opt")]
+    pub collateral_margin_level: Option<Number>,
     #[serde(with = "string_or_float")]
-    pub total_asset_of_btc: f64,
+    pub total_asset_of_btc: Number,
     #[serde(with = "string_or_float")]
-    pub total_liability_of_btc: f64,
+    pub total_liability_of_btc: Number,
     #[serde(with = "string_or_float")]
-    pub total_net_asset_of_btc: f64,
+    pub total_net_asset_of_btc: Number,
+    #[serde(default, rename = "TotalCollateralValueInUSDT", with = "string_or_float_opt")]
+    pub total_collateral_value_in_usdt: Option<Number>,
+    #[serde(default, rename = "totalOpenOrderLossInUSDT", with = "string_or_float_opt")]
+    pub total_open_order_loss_in_usdt: Option<Number>,
     pub trade_enabled: bool,
-    pub transfer_enabled: bool,
+    /// No longer returned, replaced by `transfer_in_enabled` and `transfer_out_enabled`
+    pub transfer_enabled: Option<bool>,
+    pub transfer_in_enabled: Option<bool>,
+    pub transfer_out_enabled: Option<bool>,
+    /// MARGIN_1 for classic margin accounts, MARGIN_2 for cross margin pro
+    pub account_type: Option<String>,
     pub user_assets: UserAssets,
 }
 
@@ -937,20 +1072,20 @@ pub struct IsolatedMarginAccountAsset {
     pub asset: String,
     pub borrow_enabled: bool,
     #[serde(with = "string_or_float")]
-    pub borrowed: f64,
+    pub borrowed: Number,
     #[serde(with = "string_or_float")]
-    pub free: f64,
+    pub free: Number,
     #[serde(with = "string_or_float")]
-    pub interest: f64,
+    pub interest: Number,
     #[serde(with = "string_or_float")]
-    pub locked: f64,
+    pub locked: Number,
     #[serde(with = "string_or_float")]
-    pub net_asset: f64,
+    pub net_asset: Number,
     #[serde(with = "string_or_float")]
-    pub net_asset_of_btc: f64,
+    pub net_asset_of_btc: Number,
     pub repay_enabled: bool,
     #[serde(with = "string_or_float")]
-    pub total_asset: f64,
+    pub total_asset: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -962,16 +1097,16 @@ pub struct IsolatedMarginAccountAssetDetails {
     pub isolated_created: bool,
     pub enabled: bool,
     #[serde(with = "string_or_float")]
-    pub margin_level: f64,
+    pub margin_level: Number,
     #[serde(with = "string_or_float")]
-    pub margin_ratio: f64,
+    pub margin_ratio: Number,
     pub margin_level_status: MarginLevelStatus,
     #[serde(with = "string_or_float")]
-    pub index_price: f64,
+    pub index_price: Number,
     #[serde(with = "string_or_float")]
-    pub liquidate_price: f64,
+    pub liquidate_price: Number,
     #[serde(with = "string_or_float")]
-    pub liquidate_rate: f64,
+    pub liquidate_rate: Number,
     pub trade_enabled: bool,
 }
 
@@ -992,11 +1127,11 @@ pub enum MarginLevelStatus {
 pub struct IsolatedMarginAccountDetails {
     pub assets: Vec<IsolatedMarginAccountAssetDetails>,
     #[serde(default, with = "string_or_float_opt")]
-    pub total_asset_of_btc: Option<f64>,
+    pub total_asset_of_btc: Option<Number>,
     #[serde(default, with = "string_or_float_opt")]
-    pub total_liability_of_btc: Option<f64>,
+    pub total_liability_of_btc: Option<Number>,
     #[serde(default, with = "string_or_float_opt")]
-    pub total_net_asset_of_btc: Option<f64>,
+    pub total_net_asset_of_btc: Option<Number>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -1020,9 +1155,9 @@ pub struct AssetDetails {
     pub is_borrowable: bool,
     pub is_mortgageable: bool,
     #[serde(with = "string_or_float")]
-    pub user_min_borrow: f64,
+    pub user_min_borrow: Number,
     #[serde(with = "string_or_float")]
-    pub user_min_repay: f64,
+    pub user_min_repay: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -1085,7 +1220,7 @@ pub type AllIsolatedPairs = Vec<IsolatedPairDetails>;
 pub struct PriceIndex {
     pub calc_time: u128,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     pub symbol: String,
 }
 
@@ -1106,22 +1241,23 @@ pub struct MarginOrderResult {
     pub client_order_id: String,
     pub transact_time: u128,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Number,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Number,
     #[serde(with = "string_or_float")]
-    pub cummulative_quote_qty: f64,
+    pub cummulative_quote_qty: Number,
     pub status: OrderStatus,
     pub time_in_force: TimeInForce,
     #[serde(rename(serialize = "type", deserialize = "type"))]
     pub order_type: OrderType,
     pub side: OrderSide,
     #[serde(default, with = "string_or_float_opt")]
-    pub margin_buy_borrow_amount: Option<f64>,
+    pub margin_buy_borrow_amount: Option<Number>,
     pub margin_buy_borrow_asset: Option<String>,
     pub is_isolated: Option<bool>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
     pub fills: Vec<Fill>,
 }
 
@@ -1130,27 +1266,28 @@ pub struct MarginOrderResult {
 pub struct MarginOrderState {
     pub client_order_id: String,
     #[serde(with = "string_or_float")]
-    pub cummulative_quote_qty: f64,
+    pub cummulative_quote_qty: Number,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Number,
     #[serde(with = "string_or_float")]
-    pub iceberg_qty: f64,
+    pub iceberg_qty: Number,
     pub is_working: bool,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Number,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     pub side: OrderSide,
     pub status: OrderStatus,
     #[serde(with = "string_or_float")]
-    pub stop_price: f64,
+    pub stop_price: Number,
     pub symbol: String,
     pub is_isolated: Option<bool>,
     pub time: u64,
     pub time_in_force: TimeInForce,
     #[serde(rename(serialize = "type", deserialize = "type"))]
     pub order_type: OrderType,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
     pub update_time: u64,
 }
 
@@ -1158,9 +1295,9 @@ pub struct MarginOrderState {
 #[serde(rename_all = "camelCase")]
 pub struct OrderSumaryState {
     pub id: u64,
-    pub price: f64,
-    pub qty: f64,
-    pub quote_qty: f64,
+    pub price: Number,
+    pub qty: Number,
+    pub quote_qty: Number,
     pub symbol: String,
     pub time: u128,
 }
@@ -1169,7 +1306,7 @@ pub struct OrderSumaryState {
 #[serde(rename_all = "camelCase")]
 pub struct OwnTradesState {
     #[serde(with = "string_or_float")]
-    pub commission: f64,
+    pub commission: Number,
     pub commission_asset: String,
     pub id: u64,
     pub is_best_match: bool,
@@ -1177,9 +1314,9 @@ pub struct OwnTradesState {
     pub is_maker: bool,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
     pub symbol: String,
     pub time: u128,
     pub is_isolated: bool,
@@ -1189,16 +1326,18 @@ pub struct OwnTradesState {
 #[serde(rename_all = "camelCase")]
 pub struct MaxBorrowableAmount {
     #[serde(with = "string_or_float")]
-    pub amount: f64,
+    pub amount: Number,
     #[serde(with = "string_or_float")]
-    pub borrow_limit: f64,
+    pub borrow_limit: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct MaxTransferableAmount {
     #[serde(with = "string_or_float")]
-    pub amount: f64,
+    pub amount: Number,
+    #[serde(default, with = "string_or_float_opt")]
+    pub borrow_limit: Option<Number>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
@@ -1237,6 +1376,8 @@ pub enum OrderStatus {
     Filled,
     /// The order has been canceled by the user.
     Canceled,
+    /// The order is part of an order list and waits for its working order to fill.
+    PendingNew,
     /// (currently unused)
     PendingCancel,
     /// The order was not accepted by the engine and not processed.
@@ -1252,6 +1393,8 @@ pub enum OrderStatus {
 pub enum OCOStatus {
     Response,
     ExecStarted,
+    /// An order of the list changed status without ending the list, ex: the working order of an OTO filled
+    Updated,
     AllDone,
 }
 
@@ -1283,9 +1426,11 @@ pub struct MarginOCOOrderQuery {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "UPPERCASE")]
 pub enum ContingencyType {
     OCO,
+    /// Used by OTO and OTOCO lists
+    OTO,
     #[serde(other)]
     Other,
 }
@@ -1361,7 +1506,7 @@ pub struct InterestRateHistoryQuery {
 pub struct InterestRateAssetHistory {
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub daily_interest_rate: f64,
+    pub daily_interest_rate: Number,
     pub timestamp: u128,
     pub vip_level: u8,
 }
@@ -1372,16 +1517,16 @@ pub type InterestRateHistory = Vec<InterestRateAssetHistory>;
 #[serde(rename_all = "camelCase")]
 pub struct KlineSummary {
     pub open_time: i64,
-    pub open: f64,
-    pub high: f64,
-    pub low: f64,
-    pub close: f64,
-    pub volume: f64,
+    pub open: Number,
+    pub high: Number,
+    pub low: Number,
+    pub close: Number,
+    pub volume: Number,
     pub close_time: i64,
-    pub quote_asset_volume: f64,
+    pub quote_asset_volume: Number,
     pub number_of_trades: i64,
-    pub taker_buy_base_asset_volume: f64,
-    pub taker_buy_quote_asset_volume: f64,
+    pub taker_buy_base_asset_volume: Number,
+    pub taker_buy_quote_asset_volume: Number,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1417,24 +1562,24 @@ pub struct WalletCoinInfo {
     pub coin: String,
     pub deposit_all_enable: bool,
     #[serde(with = "string_or_float")]
-    pub free: f64,
+    pub free: Number,
     #[serde(with = "string_or_float")]
-    pub freeze: f64,
+    pub freeze: Number,
     #[serde(with = "string_or_float")]
-    pub ipoable: f64,
+    pub ipoable: Number,
     #[serde(with = "string_or_float")]
-    pub ipoing: f64,
+    pub ipoing: Number,
     pub is_legal_money: bool,
     #[serde(with = "string_or_float")]
-    pub locked: f64,
+    pub locked: Number,
     pub name: String,
     pub network_list: Vec<CoinNetwork>,
     #[serde(with = "string_or_float")]
-    pub storage: f64,
+    pub storage: Number,
     pub trading: bool,
     pub withdraw_all_enable: bool,
     #[serde(with = "string_or_float")]
-    pub withdrawing: f64,
+    pub withdrawing: Number,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1457,15 +1602,21 @@ pub struct CoinNetwork {
     pub withdraw_desc: String,
     pub withdraw_enable: bool,
     #[serde(with = "string_or_float")]
-    pub withdraw_fee: f64,
+    pub withdraw_fee: Number,
     #[serde(with = "string_or_float")]
-    pub withdraw_integer_multiple: f64,
+    pub withdraw_integer_multiple: Number,
     #[serde(with = "string_or_float")]
-    pub withdraw_max: f64,
+    pub withdraw_max: Number,
     #[serde(with = "string_or_float")]
-    pub withdraw_min: f64,
+    pub withdraw_min: Number,
     #[serde(default)]
     pub same_address: bool,
+    /// Estimated minutes for a deposit or withdrawal to arrive
+    pub estimated_arrival_time: Option<u64>,
+    /// Whether the network is congested
+    pub busy: Option<bool>,
+    pub contract_address_url: Option<String>,
+    pub contract_address: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1490,7 +1641,7 @@ pub struct SnapshotVos {
 pub struct SnapshotVosData {
     pub balances: Vec<Balance>,
     #[serde(with = "string_or_float")]
-    pub total_asset_of_btc: f64,
+    pub total_asset_of_btc: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
@@ -1521,7 +1672,7 @@ pub struct CoinWithdrawalQuery {
     pub address: String,
     /// Secondary address identifier for coins like XRP,XMR etc.
     pub address_tag: Option<String>,
-    pub amount: f64,
+    pub amount: Number,
     /// When making internal transfer, true for returning the fee to the destination account; false for returning the fee back to the departure account. Default false.
     pub transaction_fee_flag: Option<bool>,
     /// Description of the address. Space in name should be encoded into %20.
@@ -1549,9 +1700,10 @@ pub struct DepositHistoryQuery {
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct DepositRecord {
+    pub id: Option<String>,
     pub coin: String,
     #[serde(with = "string_or_float")]
-    pub amount: f64,
+    pub amount: Number,
     pub network: String,
     pub status: u8,
     pub address: String,
@@ -1595,7 +1747,7 @@ pub struct RecordHistory<T> {
 pub struct WithdrawalRecord {
     pub address: String,
     #[serde(with = "string_or_float")]
-    pub amount: f64,
+    pub amount: Number,
     pub apply_time: String,
     pub coin: String,
     pub id: String,
@@ -1606,11 +1758,15 @@ pub struct WithdrawalRecord {
     pub transfer_type: u8,
     pub status: u8,
     #[serde(with = "string_or_float")]
-    pub transaction_fee: f64,
+    pub transaction_fee: Number,
     /// // confirm times for withdraw
     pub confirm_no: Option<u64>,
     pub info: Option<String>,
     pub tx_id: String,
+    pub wallet_type: Option<u32>,
+    pub tx_key: Option<String>,
+    /// Set once the withdrawal is completed
+    pub complete_time: Option<String>,
 }
 
 #[cfg(feature = "wallet_api")]
@@ -1683,7 +1839,7 @@ pub enum UniversalTransferType {
 #[serde(rename_all = "camelCase")]
 pub struct UniversalTransfer {
     pub asset: String,
-    pub amount: f64,
+    pub amount: Number,
     pub from_symbol: Option<String>,
     pub to_symbol: Option<String>,
     #[serde(rename(serialize = "type", deserialize = "type"))]
@@ -1718,7 +1874,7 @@ pub enum UniversalTransferStatus {
 pub struct UniversalTransferRecord {
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub amount: f64,
+    pub amount: Number,
     #[serde(rename(serialize = "type", deserialize = "type"))]
     pub transfer_type: UniversalTransferType,
     pub status: UniversalTransferStatus,
@@ -1774,10 +1930,10 @@ pub struct UserAssetDribblet {
     pub operate_time: u64,
     /// Total transfered BNB amount for this exchange.
     #[serde(with = "string_or_float")]
-    pub total_transfered_amount: f64,
+    pub total_transfered_amount: Number,
     ///Total service charge amount for this exchange.
     #[serde(with = "string_or_float")]
-    pub total_service_charge_amount: f64,
+    pub total_service_charge_amount: Number,
     pub trans_id: u64,
     pub user_asset_dribblet_details: Vec<UserAssetDribbletDetail>,
 }
@@ -1787,11 +1943,11 @@ pub struct UserAssetDribblet {
 pub struct UserAssetDribbletDetail {
     pub trans_id: u64,
     #[serde(with = "string_or_float")]
-    pub amount: f64,
+    pub amount: Number,
     #[serde(with = "string_or_float")]
-    pub transfered_amount: f64,
+    pub transfered_amount: Number,
     #[serde(with = "string_or_float")]
-    pub service_charge_amount: f64,
+    pub service_charge_amount: Number,
     pub operate_time: u64,
     pub from_asset: String,
 }
@@ -1802,12 +1958,12 @@ pub struct ConvertibleAssets {
     pub details: Vec<ConvertibleAssetDetails>,
     #[serde(with = "string_or_float")]
     #[serde(rename = "totalTransferBtc")]
-    pub total_transfer_btc: f64,
+    pub total_transfer_btc: Number,
     #[serde(with = "string_or_float")]
     #[serde(rename = "totalTransferBNB")]
-    pub total_transfer_bnb: f64,
+    pub total_transfer_bnb: Number,
     #[serde(with = "string_or_float_opt", default)]
-    pub driblet_percentage: Option<f64>,
+    pub driblet_percentage: Option<Number>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -1816,27 +1972,27 @@ pub struct ConvertibleAssetDetails {
     pub asset: String,
     pub asset_full_name: String,
     #[serde(with = "string_or_float")]
-    pub amount_free: f64,
+    pub amount_free: Number,
     #[serde(with = "string_or_float")]
     #[serde(rename = "toBNB")]
-    pub to_bnb: f64,
+    pub to_bnb: Number,
     #[serde(with = "string_or_float")]
     #[serde(rename = "toBTC")]
-    pub to_btc: f64,
+    pub to_btc: Number,
     #[serde(with = "string_or_float")]
     #[serde(rename = "toBNBOffExchange")]
-    pub to_bnb_off_exchange: f64,
+    pub to_bnb_off_exchange: Number,
     #[serde(with = "string_or_float")]
-    pub exchange: f64,
+    pub exchange: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct DustTransfer {
     #[serde(with = "string_or_float")]
-    pub total_service_charge: f64,
+    pub total_service_charge: Number,
     #[serde(with = "string_or_float")]
-    pub total_transferred: f64,
+    pub total_transferred: Number,
     pub transfer_result: Vec<DustTransferResult>,
 }
 
@@ -1844,14 +2000,14 @@ pub struct DustTransfer {
 #[serde(rename_all = "camelCase")]
 pub struct DustTransferResult {
     #[serde(with = "string_or_float")]
-    pub amount: f64,
+    pub amount: Number,
     pub from_asset: String,
     pub operate_time: u64,
     #[serde(with = "string_or_float")]
-    pub service_charge_amount: f64,
+    pub service_charge_amount: Number,
     pub tran_id: u64,
     #[serde(with = "string_or_float")]
-    pub transfered_amount: f64,
+    pub transfered_amount: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -1859,7 +2015,7 @@ pub struct DustTransferResult {
 pub struct AssetDividend {
     pub id: u64,
     #[serde(with = "string_or_float")]
-    pub amount: f64,
+    pub amount: Number,
     pub asset: String,
     pub div_time: u64,
     pub en_info: String,
@@ -1884,12 +2040,12 @@ pub struct SupportedAssetDetail {
     /// min withdraw amount
     #[serde(with = "string_or_float_opt")]
     #[serde(rename = "minWithdrawAmount")]
-    pub min_withdrawal_amount: Option<f64>,
+    pub min_withdrawal_amount: Option<Number>,
     /// deposit status (false if ALL of networks' are false)
     pub deposit_status: bool,
     /// withdraw fee
     #[serde(with = "string_or_float_opt")]
-    pub withdraw_fee: Option<f64>,
+    pub withdraw_fee: Option<Number>,
     /// withdraw status (false if ALL of networks' are false)
     pub withdraw_status: bool,
     /// reason
@@ -1903,9 +2059,9 @@ pub type TradeFees = Vec<TradeFee>;
 pub struct TradeFee {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub maker_commission: f64,
+    pub maker_commission: Number,
     #[serde(with = "string_or_float")]
-    pub taker_commission: f64,
+    pub taker_commission: Number,
 }
 
 pub type WalletFundings = Vec<WalletFunding>;
@@ -1915,15 +2071,15 @@ pub type WalletFundings = Vec<WalletFunding>;
 pub struct WalletFunding {
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub free: f64,
+    pub free: Number,
     #[serde(with = "string_or_float")]
-    pub locked: f64,
+    pub locked: Number,
     #[serde(with = "string_or_float")]
-    pub freeze: f64,
+    pub freeze: Number,
     #[serde(with = "string_or_float")]
-    pub withdrawing: f64,
+    pub withdrawing: Number,
     #[serde(with = "string_or_float")]
-    pub btc_valuation: f64,
+    pub btc_valuation: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -1948,10 +2104,16 @@ pub struct ApiKeyPermissions {
     enable_spot_and_margin_trading: bool,
     /// Expiration time for spot and margin trading permission
     trading_authority_expiration_time: Option<u64>,
+    /// Trading through the FIX API
+    enable_fix_api_trade: Option<bool>,
+    /// Reading through the FIX API
+    enable_fix_read_only: Option<bool>,
+    enable_portfolio_margin_trading: Option<bool>,
 }
 
 pub mod string_or_float {
     use std::fmt;
+    use std::str::FromStr;
 
     use serde::{de, Deserialize, Deserializer, Serializer};
 
@@ -1963,18 +2125,20 @@ pub mod string_or_float {
         serializer.collect_str(value)
     }
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<f64, D::Error>
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
     where
         D: Deserializer<'de>,
+        T: FromStr + Deserialize<'de>,
+        T::Err: fmt::Display,
     {
         #[derive(Deserialize)]
         #[serde(untagged)]
-        enum StringOrFloat {
+        enum StringOrFloat<T> {
             String(String),
-            Float(f64),
+            Float(T),
         }
 
-        match StringOrFloat::deserialize(deserializer)? {
+        match StringOrFloat::<T>::deserialize(deserializer)? {
             StringOrFloat::String(s) => s.parse().map_err(de::Error::custom),
             StringOrFloat::Float(i) => Ok(i),
         }
@@ -1983,8 +2147,9 @@ pub mod string_or_float {
 
 pub(crate) mod string_or_float_opt {
     use std::fmt;
+    use std::str::FromStr;
 
-    use serde::{Deserialize, Deserializer, Serializer};
+    use serde::{de, Deserialize, Deserializer, Serializer};
 
     pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -1997,18 +2162,26 @@ pub(crate) mod string_or_float_opt {
         }
     }
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
     where
         D: Deserializer<'de>,
+        T: FromStr + Deserialize<'de>,
+        T::Err: fmt::Display,
     {
         #[derive(Deserialize)]
         #[serde(untagged)]
-        enum StringOrFloat {
+        enum StringOrFloat<T> {
             String(String),
-            Float(f64),
+            Float(T),
         }
 
-        Ok(Some(crate::rest_model::string_or_float::deserialize(deserializer)?))
+        // null and empty strings stand for missing values
+        match Option::<StringOrFloat<T>>::deserialize(deserializer)? {
+            None => Ok(None),
+            Some(StringOrFloat::String(s)) if s.is_empty() => Ok(None),
+            Some(StringOrFloat::String(s)) => s.parse().map(Some).map_err(de::Error::custom),
+            Some(StringOrFloat::Float(i)) => Ok(Some(i)),
+        }
     }
 }
 
@@ -55,9 +55,19 @@ pub struct Network {
     pub withdraw_fee: f64,
     #[serde(with = "string_or_float")]
     pub withdraw_min: f64,
+    #[serde(default, with = "string_or_float_opt")]
+    pub withdraw_max: Option<f64>,
     // pub insert_time: Option<u64>, //commented out for now, because they are not inside the actual response (only the api doc example)
     // pub update_time: Option<u64>,
     pub withdraw_integer_multiple: Option<String>,
+    #[serde(default)]
+    pub same_address: bool,
+    /// Estimated minutes for a deposit or withdrawal to arrive
+    pub estimated_arrival_time: Option<u64>,
+    /// Whether the network is congested
+    pub busy: Option<bool>,
+    pub contract_address_url: Option<String>,
+    pub contract_address: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -0,0 +1,204 @@
+use std::fmt;
+use std::sync::Arc;
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use hex::encode as hex_encode;
+use ring::hmac;
+use ring::rand::SystemRandom;
+use ring::signature::{Ed25519KeyPair, KeyPair, RsaKeyPair, RSA_PKCS1_SHA256};
+
+use crate::config::Config;
+use crate::errors::*;
+
+/// Algorithm used to sign requests, the secret key holds the matching key material
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SignatureMethod {
+    /// HMAC-SHA256 with the secret key, hex encoded
+    #[default]
+    Hmac,
+    /// Ed25519 with a PEM encoded PKCS#8 private key, base64 encoded
+    Ed25519,
+    /// RSASSA-PKCS1-v1_5 with SHA-256 and a PEM encoded PKCS#1 or PKCS#8 private key, base64 encoded
+    Rsa,
+}
+
+/// Signs the query string of requests to authenticated endpoints
+pub trait Signer: Send + Sync + 'static {
+    /// The signature of `payload`, encoded as the exchange expects it
+    fn sign(&self, payload: &str) -> Result<String>;
+}
+
+/// Build the signer of `method` from the secret key
+pub fn signer(method: SignatureMethod, secret_key: &str) -> Result<Box<dyn Signer>> {
+    Ok(match method {
+        SignatureMethod::Hmac => Box::new(HmacSigner::new(secret_key)),
+        SignatureMethod::Ed25519 => Box::new(Ed25519Signer::from_pem(secret_key)?),
+        SignatureMethod::Rsa => Box::new(RsaSigner::from_pem(secret_key)?),
+    })
+}
+
+/// The signer of `conf` if one is set, otherwise the signer of its signature method for the secret key
+pub(crate) fn config_signer(conf: &Config, secret_key: Option<&str>) -> Result<Option<Arc<dyn Signer>>> {
+    if let Some(signer) = &conf.signer {
+        return Ok(Some(signer.0.clone()));
+    }
+    secret_key
+        .map(|key| signer(conf.signature_method, key).map(Arc::from))
+        .transpose()
+}
+
+/// A signer shared by every client built from a configuration
+#[derive(Clone)]
+pub struct SharedSigner(pub(crate) Arc<dyn Signer>);
+
+impl SharedSigner {
+    pub fn new(signer: impl Signer) -> Self { SharedSigner(Arc::new(signer)) }
+}
+
+impl fmt::Debug for SharedSigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("SharedSigner") }
+}
+
+/// Signers are equal when they are the same instance
+impl PartialEq for SharedSigner {
+    fn eq(&self, other: &Self) -> bool { Arc::ptr_eq(&self.0, &other.0) }
+}
+
+impl Eq for SharedSigner {}
+
+pub struct HmacSigner {
+    key: hmac::Key,
+}
+
+impl HmacSigner {
+    pub fn new(secret_key: &str) -> Self {
+        HmacSigner {
+            key: hmac::Key::new(hmac::HMAC_SHA256, secret_key.as_bytes()),
+        }
+    }
+}
+
+impl Signer for HmacSigner {
+    fn sign(&self, payload: &str) -> Result<String> { Ok(hex_encode(hmac::sign(&self.key, payload.as_bytes()).as_ref())) }
+}
+
+pub struct Ed25519Signer {
+    key_pair: Ed25519KeyPair,
+}
+
+impl Ed25519Signer {
+    /// Load a `PRIVATE KEY` PEM, as generated by `openssl genpkey -algorithm ed25519`
+    pub fn from_pem(pem: &str) -> Result<Self> {
+        let der = pem_to_der(pem)?;
+        let key_pair = Ed25519KeyPair::from_pkcs8_maybe_unchecked(&der).map_err(|e| Error::InvalidKey(e.to_string()))?;
+        Ok(Ed25519Signer { key_pair })
+    }
+
+    /// The public key to register with the API key
+    pub fn public_key(&self) -> &[u8] { self.key_pair.public_key().as_ref() }
+}
+
+impl Signer for Ed25519Signer {
+    fn sign(&self, payload: &str) -> Result<String> { Ok(BASE64.encode(self.key_pair.sign(payload.as_bytes()))) }
+}
+
+pub struct RsaSigner {
+    key_pair: RsaKeyPair,
+    rng: SystemRandom,
+}
+
+impl RsaSigner {
+    /// Load a `PRIVATE KEY` (PKCS#8) or `RSA PRIVATE KEY` (PKCS#1) PEM
+    pub fn from_pem(pem: &str) -> Result<Self> {
+        let der = pem_to_der(pem)?;
+        let key_pair = if pem.contains("BEGIN RSA PRIVATE KEY") {
+            RsaKeyPair::from_der(&der)
+        } else {
+            RsaKeyPair::from_pkcs8(&der)
+        }
+        .map_err(|e| Error::InvalidKey(e.to_string()))?;
+        Ok(RsaSigner {
+            key_pair,
+            rng: SystemRandom::new(),
+        })
+    }
+}
+
+impl Signer for RsaSigner {
+    fn sign(&self, payload: &str) -> Result<String> {
+        let mut signature = vec![0; self.key_pair.public_modulus_len()];
+        self.key_pair
+            .sign(&RSA_PKCS1_SHA256, &self.rng, payload.as_bytes(), &mut signature)
+            .map_err(|_| Error::InvalidKey("rsa signing failed".to_string()))?;
+        Ok(BASE64.encode(signature))
+    }
+}
+
+/// Signature of `payload`, url encoded since base64 signatures may contain `+`, `/` and `=`
+pub(crate) fn url_encoded_signature(signer: &dyn Signer, payload: &str) -> Result<String> {
+    Ok(url::form_urlencoded::byte_serialize(signer.sign(payload)?.as_bytes()).collect())
+}
+
+/// Decode the base64 body of a PEM document
+fn pem_to_der(pem: &str) -> Result<Vec<u8>> {
+    let body: String = pem
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.starts_with("-----"))
+        .collect();
+    BASE64.decode(body).map_err(|e| Error::InvalidKey(e.to_string()))
+}
+
+#[cfg(test)]
+mod test {
+    use ring::signature::{KeyPair, UnparsedPublicKey, ED25519, RSA_PKCS1_2048_8192_SHA256};
+
+    use super::*;
+
+    const PAYLOAD: &str = "symbol=BTCUSDT&side=SELL&type=LIMIT&quantity=1&price=0.2&timestamp=1668481559918";
+
+    #[test]
+    fn ed25519_round_trip() {
+        let signer = Ed25519Signer::from_pem(include_str!("../test_data/keys/ed25519.pem")).unwrap();
+        let signature = BASE64.decode(signer.sign(PAYLOAD).unwrap()).unwrap();
+        let public_key = UnparsedPublicKey::new(&ED25519, signer.key_pair.public_key().as_ref());
+        assert!(public_key.verify(PAYLOAD.as_bytes(), &signature).is_ok());
+        assert!(public_key.verify(b"symbol=ETHUSDT", &signature).is_err());
+    }
+
+    #[test]
+    fn rsa_round_trip() {
+        for pem in [include_str!("../test_data/keys/rsa.pem"), include_str!("../test_data/keys/rsa_pkcs1.pem")] {
+            let signer = RsaSigner::from_pem(pem).unwrap();
+            let signature = BASE64.decode(signer.sign(PAYLOAD).unwrap()).unwrap();
+            let public_key = UnparsedPublicKey::new(&RSA_PKCS1_2048_8192_SHA256, signer.key_pair.public_key().as_ref());
+            assert!(public_key.verify(PAYLOAD.as_bytes(), &signature).is_ok());
+            assert!(public_key.verify(b"symbol=ETHUSDT", &signature).is_err());
+        }
+    }
+
+    #[test]
+    fn invalid_keys() {
+        assert!(matches!(signer(SignatureMethod::Ed25519, "not a key"), Err(Error::InvalidKey(_))));
+        let rsa_as_ed25519 = signer(SignatureMethod::Ed25519, include_str!("../test_data/keys/rsa.pem"));
+        assert!(matches!(rsa_as_ed25519, Err(Error::InvalidKey(_))));
+    }
+
+    #[test]
+    fn url_encoding() {
+        let signer = Ed25519Signer::from_pem(include_str!("../test_data/keys/ed25519.pem")).unwrap();
+        // find payloads whose base64 signature holds each character that must be escaped
+        for special in ['+', '/'] {
+            let payload = (0..)
+                .map(|i| format!("{PAYLOAD}{i}"))
+                .find(|payload| signer.sign(payload).unwrap().contains(special))
+                .unwrap();
+            let encoded = url_encoded_signature(&signer, &payload).unwrap();
+            assert!(!encoded.contains(['+', '/', '=']));
+            let query = format!("signature={encoded}");
+            let (_, decoded) = url::form_urlencoded::parse(query.as_bytes()).next().unwrap();
+            assert_eq!(decoded, signer.sign(&payload).unwrap());
+        }
+    }
+}
@@ -0,0 +1,630 @@
+//! In-process mock of the Binance REST and websocket APIs for offline tests
+//!
+//! [`MockServer`] serves the spot, futures, options and portfolio margin REST endpoints on one port and streams on another,
+//! verifies the API key, HMAC signature and timestamp of signed requests, records every request
+//! and answers with canned, queued or scripted responses.
+//! WebSocket API requests are served on the stream port and mocked with the `WS` method
+//! and the request method as path, e.g. `server.mock("WS", "order.place", response)`.
+//! Each connection keeps its own session for `session.logon`, `session.status` and `session.logout`.
+//! [`MockProxy`] relays connections to the mock through HTTP and SOCKS5 proxy configurations.
+//! # Examples
+//! ```rust
+//! use binance::{api::*, account::*, market::*, testkit::*};
+//! tokio_test::block_on(async {
+//!     let server = MockServer::start("key", "secret").await;
+//!     server.mock("GET", "/api/v3/ticker/price", MockResponse::json(&serde_json::json!({"symbol": "BTCUSDT", "price": "30000.1"})));
+//!     let market: Market = Binance::new_with_config(None, None, &server.config());
+//!     assert_eq!(market.get_price("BTCUSDT").await.unwrap().price.to_string(), "30000.1");
+//!
+//!     server.mock("GET", "/api/v3/account", MockResponse::error(400, -2015, "Invalid API-key, IP, or permissions for action."));
+//!     let account: Account = Binance::new_with_config(Some("key".into()), Some("secret".into()), &server.config());
+//!     assert!(account.get_account().await.is_err());
+//!     assert!(server.requests().iter().all(|r| r.signature_valid != Some(false)));
+//! });
+//! ```
+
+use std::collections::{HashMap, VecDeque};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use futures::{SinkExt, StreamExt};
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Request, Response};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use ring::hmac;
+use ring::signature::{UnparsedPublicKey, ED25519};
+use serde::Serialize;
+use serde_json::Value;
+use tokio::net::TcpListener;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use tokio_tungstenite::tungstenite::handshake::server::{Request as WsRequest, Response as WsResponse};
+use tokio_tungstenite::tungstenite::Message;
+
+use crate::config::Config;
+pub use crate::util::num;
+
+/// A response served by the mock
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl MockResponse {
+    /// A 200 response with a json body
+    pub fn json<T: Serialize>(body: &T) -> Self {
+        MockResponse {
+            status: 200,
+            headers: vec![],
+            body: serde_json::to_string(body).unwrap(),
+        }
+    }
+
+    /// An error response in the format of Binance, e.g. `MockResponse::error(400, -1021, "Timestamp outside of recvWindow.")`
+    pub fn error(status: u16, code: i32, msg: &str) -> Self {
+        MockResponse {
+            status,
+            headers: vec![],
+            body: serde_json::json!({ "code": code, "msg": msg }).to_string(),
+        }
+    }
+
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+/// A request received by the mock
+#[derive(Debug, Clone)]
+pub struct MockRequest {
+    pub method: String,
+    pub path: String,
+    /// Query parameters, without the signature
+    pub params: HashMap<String, String>,
+    pub api_key: Option<String>,
+    /// `None` for unsigned requests
+    pub signature_valid: Option<bool>,
+}
+
+type Handler = Arc<dyn Fn(&MockRequest) -> MockResponse + Send + Sync>;
+
+#[derive(Default)]
+struct State {
+    handlers: HashMap<(String, String), Handler>,
+    queued: HashMap<(String, String), VecDeque<MockResponse>>,
+    requests: Vec<MockRequest>,
+    ws_paths: Vec<String>,
+    subscriptions: Vec<String>,
+    ed25519_public_key: Option<Vec<u8>>,
+}
+
+#[derive(Debug, Clone)]
+enum WsCommand {
+    Send(String),
+    Close,
+}
+
+/// In-process mock of the REST and websocket APIs, stopped when dropped
+pub struct MockServer {
+    http_addr: SocketAddr,
+    ws_addr: SocketAddr,
+    api_key: String,
+    secret_key: String,
+    state: Arc<Mutex<State>>,
+    ws: broadcast::Sender<WsCommand>,
+    tasks: Vec<JoinHandle<()>>,
+}
+
+impl MockServer {
+    /// Start the mock on local ports, requests are authenticated with `api_key` and `secret_key`
+    pub async fn start(api_key: &str, secret_key: &str) -> Self {
+        let state = Arc::new(Mutex::new(State::default()));
+        let (ws, _) = broadcast::channel(1024);
+
+        let http_state = state.clone();
+        let (key, secret) = (api_key.to_string(), secret_key.to_string());
+        let make_service = make_service_fn(move |_| {
+            let (state, key, secret) = (http_state.clone(), key.clone(), secret.clone());
+            async move {
+                Ok::<_, Infallible>(service_fn(move |req| {
+                    let (state, key, secret) = (state.clone(), key.clone(), secret.clone());
+                    async move { Ok::<_, Infallible>(handle(req, &state, &key, &secret)) }
+                }))
+            }
+        });
+        let server = hyper::Server::bind(&SocketAddr::from(([127, 0, 0, 1], 0))).serve(make_service);
+        let http_addr = server.local_addr();
+        let http_task = tokio::spawn(async move {
+            let _ = server.await;
+        });
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let ws_addr = listener.local_addr().unwrap();
+        let (ws_state, ws_tx) = (state.clone(), ws.clone());
+        let credentials = Arc::new((api_key.to_string(), secret_key.to_string()));
+        let ws_task = tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(serve_ws(stream, ws_state.clone(), credentials.clone(), ws_tx.subscribe()));
+            }
+        });
+
+        MockServer {
+            http_addr,
+            ws_addr,
+            api_key: api_key.to_string(),
+            secret_key: secret_key.to_string(),
+            state,
+            ws,
+            tasks: vec![http_task, ws_task],
+        }
+    }
+
+    /// A configuration pointing every endpoint at the mock
+    pub fn config(&self) -> Config {
+        Config::default()
+            .set_rest_api_endpoint(self.rest_endpoint())
+            .set_futures_rest_api_endpoint(self.rest_endpoint())
+            .set_ws_endpoint(self.ws_endpoint())
+            .set_ws_api_endpoint(self.ws_endpoint())
+            .set_futures_ws_endpoint(self.ws_endpoint())
+            .set_futures_ws_api_endpoint(self.ws_endpoint())
+            .set_coin_futures_rest_api_endpoint(self.rest_endpoint())
+            .set_coin_futures_ws_endpoint(self.ws_endpoint())
+            .set_options_rest_api_endpoint(self.rest_endpoint())
+            .set_options_ws_endpoint(self.ws_endpoint())
+            .set_portfolio_margin_rest_api_endpoint(self.rest_endpoint())
+            .set_portfolio_margin_ws_endpoint(self.ws_endpoint())
+    }
+
+    pub fn rest_endpoint(&self) -> String { format!("http://{}", self.http_addr) }
+
+    pub fn ws_endpoint(&self) -> String { format!("ws://{}", self.ws_addr) }
+
+    pub fn api_key(&self) -> &str { &self.api_key }
+
+    pub fn secret_key(&self) -> &str { &self.secret_key }
+
+    /// Always answer `method` `path` with `response`
+    pub fn mock(&self, method: &str, path: &str, response: MockResponse) {
+        self.mock_with(method, path, move |_| response.clone());
+    }
+
+    /// Answer the next `method` `path` request with `response`, before any other mock
+    pub fn mock_once(&self, method: &str, path: &str, response: MockResponse) {
+        self.state
+            .lock()
+            .unwrap()
+            .queued
+            .entry((method.to_string(), path.to_string()))
+            .or_default()
+            .push_back(response);
+    }
+
+    /// Answer `method` `path` with the response computed from each request
+    pub fn mock_with<F>(&self, method: &str, path: &str, handler: F)
+    where
+        F: Fn(&MockRequest) -> MockResponse + Send + Sync + 'static,
+    {
+        self.state
+            .lock()
+            .unwrap()
+            .handlers
+            .insert((method.to_string(), path.to_string()), Arc::new(handler));
+    }
+
+    /// Verify signatures with the Ed25519 public key registered for the API key instead of the HMAC secret key
+    pub fn set_ed25519_public_key(&self, public_key: &[u8]) {
+        self.state.lock().unwrap().ed25519_public_key = Some(public_key.to_vec());
+    }
+
+    /// Requests received so far
+    pub fn requests(&self) -> Vec<MockRequest> { self.state.lock().unwrap().requests.clone() }
+
+    /// Paths requested by websocket clients, e.g. `/ws/btcusdt@aggTrade`
+    pub fn ws_paths(&self) -> Vec<String> { self.state.lock().unwrap().ws_paths.clone() }
+
+    /// Streams currently subscribed with `SUBSCRIBE`
+    pub fn subscriptions(&self) -> Vec<String> { self.state.lock().unwrap().subscriptions.clone() }
+
+    /// Send an event to every connected websocket client
+    pub fn push_event<T: Serialize>(&self, event: &T) { self.push_raw(serde_json::to_string(event).unwrap()); }
+
+    /// Send a raw text message to every connected websocket client
+    pub fn push_raw<S: Into<String>>(&self, message: S) { let _ = self.ws.send(WsCommand::Send(message.into())); }
+
+    /// Close every websocket connection, e.g. to exercise reconnections, live subscriptions are dropped with them
+    pub fn disconnect_websockets(&self) {
+        self.state.lock().unwrap().subscriptions.clear();
+        let _ = self.ws.send(WsCommand::Close);
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        for task in &self.tasks {
+            task.abort();
+        }
+    }
+}
+
+fn handle(req: Request<Body>, state: &Mutex<State>, api_key: &str, secret_key: &str) -> Response<Body> {
+    let method = req.method().to_string();
+    let path = req.uri().path().to_string();
+    let query = req.uri().query().unwrap_or_default().to_string();
+    let mut params: HashMap<String, String> = url::form_urlencoded::parse(query.as_bytes()).into_owned().collect();
+    let signature = params.remove("signature");
+    let signature_valid = signature.map(|signature| {
+        let payload = query.split("&signature=").next().unwrap_or_default();
+        verify(state, secret_key, payload, &signature)
+    });
+    let request = MockRequest {
+        method,
+        path,
+        params,
+        api_key: req
+            .headers()
+            .get("x-mbx-apikey")
+            .and_then(|key| key.to_str().ok())
+            .map(str::to_string),
+        signature_valid,
+    };
+
+    let response = respond(&request, state, api_key, default_response);
+    state.lock().unwrap().requests.push(request);
+
+    let mut builder = Response::builder()
+        .status(response.status)
+        .header("content-type", "application/json");
+    for (name, value) in &response.headers {
+        builder = builder.header(name, value);
+    }
+    builder.body(Body::from(response.body)).unwrap()
+}
+
+fn verify(state: &Mutex<State>, secret_key: &str, payload: &str, signature: &str) -> bool {
+    if let Some(public_key) = state.lock().unwrap().ed25519_public_key.as_ref() {
+        let public_key = UnparsedPublicKey::new(&ED25519, public_key);
+        return BASE64
+            .decode(signature)
+            .is_ok_and(|signature| public_key.verify(payload.as_bytes(), &signature).is_ok());
+    }
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret_key.as_bytes());
+    hex::decode(signature).is_ok_and(|signature| hmac::verify(&key, payload.as_bytes(), &signature).is_ok())
+}
+
+/// Authenticate the request and pick its queued, scripted or `default` response
+fn respond<F>(request: &MockRequest, state: &Mutex<State>, api_key: &str, default: F) -> MockResponse
+where
+    F: FnOnce(&MockRequest) -> MockResponse,
+{
+    if request.signature_valid.is_some() && request.api_key.as_deref() != Some(api_key) {
+        MockResponse::error(401, -2015, "Invalid API-key, IP, or permissions for action.")
+    } else if request.signature_valid == Some(false) {
+        MockResponse::error(400, -1022, "Signature for this request is not valid.")
+    } else if !in_recv_window(request) {
+        MockResponse::error(400, -1021, "Timestamp for this request is outside of the recvWindow.")
+    } else {
+        let mut state = state.lock().unwrap();
+        let key = (request.method.clone(), request.path.clone());
+        match (
+            state.queued.get_mut(&key).and_then(VecDeque::pop_front),
+            state.handlers.get(&key),
+        ) {
+            (Some(response), _) => response,
+            (None, Some(handler)) => handler(request),
+            (None, None) => default(request),
+        }
+    }
+}
+
+/// Whether the timestamp of the request, if any, is accepted by the server like Binance does
+fn in_recv_window(request: &MockRequest) -> bool {
+    let param = |key: &str| request.params.get(key).and_then(|value| value.parse::<i64>().ok());
+    let Some(timestamp) = param("timestamp") else {
+        return true;
+    };
+    let now = chrono::Utc::now().timestamp_millis();
+    timestamp < now + 1000 && now - timestamp <= param("recvWindow").unwrap_or(5000)
+}
+
+/// Responses of endpoints that need no data
+fn default_response(request: &MockRequest) -> MockResponse {
+    match (request.method.as_str(), request.path.as_str()) {
+        ("GET", "/api/v3/ping" | "/fapi/v1/ping" | "/dapi/v1/ping" | "/eapi/v1/ping" | "/papi/v1/ping") => {
+            MockResponse::json(&serde_json::json!({}))
+        }
+        ("WS", "ping") => MockResponse::json(&serde_json::json!({})),
+        ("GET", "/api/v3/time" | "/fapi/v1/time" | "/dapi/v1/time" | "/eapi/v1/time") | ("WS", "time") => {
+            MockResponse::json(&serde_json::json!({ "serverTime": chrono::Utc::now().timestamp_millis() }))
+        }
+        (
+            "POST",
+            "/api/v3/userDataStream"
+            | "/sapi/v1/userDataStream"
+            | "/sapi/v1/userDataStream/isolated"
+            | "/fapi/v1/listenKey"
+            | "/dapi/v1/listenKey"
+            | "/papi/v1/listenKey",
+        ) => MockResponse::json(&serde_json::json!({ "listenKey": "mock-listen-key" })),
+        (
+            "PUT" | "DELETE",
+            "/api/v3/userDataStream"
+            | "/sapi/v1/userDataStream"
+            | "/sapi/v1/userDataStream/isolated"
+            | "/fapi/v1/listenKey"
+            | "/dapi/v1/listenKey"
+            | "/papi/v1/listenKey",
+        ) => MockResponse::json(&serde_json::json!({})),
+        (method, path) => MockResponse::error(404, -1, &format!("no mock for {method} {path}")),
+    }
+}
+
+async fn serve_ws(
+    stream: tokio::net::TcpStream,
+    state: Arc<Mutex<State>>,
+    credentials: Arc<(String, String)>,
+    mut commands: broadcast::Receiver<WsCommand>,
+) {
+    let path_state = state.clone();
+    let record_path = move |request: &WsRequest, response: WsResponse| {
+        path_state.lock().unwrap().ws_paths.push(request.uri().to_string());
+        Ok(response)
+    };
+    let Ok(socket) = tokio_tungstenite::accept_hdr_async(stream, record_path).await else {
+        return;
+    };
+    let (mut sink, mut stream) = socket.split();
+    let mut session = Session::new();
+    loop {
+        tokio::select! {
+            command = commands.recv() => match command {
+                Ok(WsCommand::Send(message)) => {
+                    if sink.send(Message::Text(message)).await.is_err() {
+                        return;
+                    }
+                }
+                Ok(WsCommand::Close) | Err(broadcast::error::RecvError::Closed) => {
+                    let _ = sink.close().await;
+                    return;
+                }
+                Err(broadcast::error::RecvError::Lagged(_)) => {}
+            },
+            message = stream.next() => match message {
+                Some(Ok(Message::Text(text))) => {
+                    let (api_key, secret_key) = credentials.as_ref();
+                    let reply = ws_api_reply(&text, &state, &mut session, api_key, secret_key)
+                        .or_else(|| control_reply(&text, &state));
+                    if let Some(reply) = reply {
+                        if sink.send(Message::Text(reply)).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+                Some(Ok(Message::Ping(payload))) => {
+                    let _ = sink.send(Message::Pong(payload)).await;
+                }
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => return,
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+}
+
+/// WebSocket API session of a connection
+struct Session {
+    api_key: Option<String>,
+    authorized_since: Option<i64>,
+    connected_since: i64,
+}
+
+impl Session {
+    fn new() -> Self {
+        Session {
+            api_key: None,
+            authorized_since: None,
+            connected_since: chrono::Utc::now().timestamp_millis(),
+        }
+    }
+
+    /// Log the session on with the API key of a signed `session.logon` or out, and report its status
+    fn reply(&mut self, request: &MockRequest) -> MockResponse {
+        match request.path.as_str() {
+            "session.logon" if request.signature_valid.is_none() => {
+                return MockResponse::error(400, -1102, "Mandatory parameter 'signature' was not sent.");
+            }
+            "session.logon" => {
+                self.api_key = request.api_key.clone();
+                self.authorized_since = Some(chrono::Utc::now().timestamp_millis());
+            }
+            "session.logout" => {
+                self.api_key = None;
+                self.authorized_since = None;
+            }
+            _ => {}
+        }
+        MockResponse::json(&serde_json::json!({
+            "apiKey": self.api_key,
+            "authorizedSince": self.authorized_since,
+            "connectedSince": self.connected_since,
+            "returnRateLimits": false,
+            "serverTime": chrono::Utc::now().timestamp_millis(),
+        }))
+    }
+}
+
+/// Reply to WebSocket API requests, their methods are namespaced e.g. `order.place`
+fn ws_api_reply(
+    text: &str,
+    state: &Mutex<State>,
+    session: &mut Session,
+    api_key: &str,
+    secret_key: &str,
+) -> Option<String> {
+    let request: Value = serde_json::from_str(text).ok()?;
+    let id = request.get("id")?.clone();
+    let method = request.get("method")?.as_str()?;
+    if !method.contains('.') && !matches!(method, "ping" | "time") {
+        return None;
+    }
+    let mut params: HashMap<String, String> = request
+        .get("params")
+        .and_then(Value::as_object)
+        .map(|params| {
+            params
+                .iter()
+                .map(|(k, v)| (k.clone(), v.as_str().map_or_else(|| v.to_string(), str::to_string)))
+                .collect()
+        })
+        .unwrap_or_default();
+    let signature = params.remove("signature");
+    let signature_valid = signature.map(|signature| {
+        let mut sorted: Vec<_> = params.iter().collect();
+        sorted.sort();
+        let payload: Vec<String> = sorted.into_iter().map(|(k, v)| format!("{k}={v}")).collect();
+        verify(state, secret_key, &payload.join("&"), &signature)
+    });
+    let request = MockRequest {
+        method: "WS".to_string(),
+        path: method.to_string(),
+        api_key: params.remove("apiKey"),
+        params,
+        signature_valid,
+    };
+    let response = respond(&request, state, api_key, |request| match request.path.as_str() {
+        "session.logon" | "session.status" | "session.logout" => session.reply(request),
+        _ => default_response(request),
+    });
+    state.lock().unwrap().requests.push(request);
+    let body: Value = serde_json::from_str(&response.body).unwrap_or(Value::Null);
+    let reply = if response.status == 200 {
+        serde_json::json!({ "id": id, "status": 200, "result": body })
+    } else {
+        serde_json::json!({ "id": id, "status": response.status, "error": body })
+    };
+    Some(reply.to_string())
+}
+
+/// Reply to live subscription requests
+fn control_reply(text: &str, state: &Mutex<State>) -> Option<String> {
+    let request: Value = serde_json::from_str(text).ok()?;
+    let id = request.get("id")?.clone();
+    let params: Vec<String> = request
+        .get("params")
+        .and_then(|p| serde_json::from_value(p.clone()).ok())
+        .unwrap_or_default();
+    let mut state = state.lock().unwrap();
+    let result = match request.get("method")?.as_str()? {
+        "SUBSCRIBE" => {
+            for param in params {
+                if !state.subscriptions.contains(&param) {
+                    state.subscriptions.push(param);
+                }
+            }
+            Value::Null
+        }
+        "UNSUBSCRIBE" => {
+            state.subscriptions.retain(|s| !params.contains(s));
+            Value::Null
+        }
+        "LIST_SUBSCRIPTIONS" => serde_json::json!(state.subscriptions),
+        _ => return Some(serde_json::json!({ "error": { "code": 2, "msg": "Invalid request" }, "id": id }).to_string()),
+    };
+    Some(serde_json::json!({ "result": result, "id": id }).to_string())
+}
+
+/// Proxy relaying HTTP CONNECT tunnels, plain HTTP requests and SOCKS5 connections, stopped when dropped
+pub struct MockProxy {
+    addr: SocketAddr,
+    connections: Arc<Mutex<Vec<String>>>,
+    task: JoinHandle<()>,
+}
+
+impl MockProxy {
+    /// Start the proxy on a local port
+    pub async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let connections = Arc::new(Mutex::new(Vec::new()));
+        let accepted = connections.clone();
+        let task = tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(relay(stream, accepted.clone()));
+            }
+        });
+        MockProxy { addr, connections, task }
+    }
+
+    /// Url of the proxy for `scheme`, e.g. `http` or `socks5`
+    pub fn url(&self, scheme: &str) -> String { format!("{scheme}://{}", self.addr) }
+
+    /// Relayed connections, the request line of HTTP ones, e.g. `CONNECT 127.0.0.1:80 HTTP/1.1`,
+    /// and `SOCKS5 <host>:<port>` for SOCKS5 ones
+    pub fn connections(&self) -> Vec<String> { self.connections.lock().unwrap().clone() }
+}
+
+impl Drop for MockProxy {
+    fn drop(&mut self) { self.task.abort(); }
+}
+
+/// Connect to the target requested by the client and copy the traffic both ways
+async fn relay(mut client: tokio::net::TcpStream, connections: Arc<Mutex<Vec<String>>>) -> std::io::Result<()> {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let invalid = |msg: &str| std::io::Error::new(std::io::ErrorKind::InvalidData, msg.to_string());
+    let first = client.read_u8().await?;
+    let mut server = if first == 5 {
+        // SOCKS5 greeting, accept without authentication
+        let methods = client.read_u8().await?;
+        client.read_exact(&mut vec![0; methods as usize]).await?;
+        client.write_all(&[5, 0]).await?;
+        let mut request = [0; 4];
+        client.read_exact(&mut request).await?;
+        let host = match request[3] {
+            1 => std::net::Ipv4Addr::from(client.read_u32().await?).to_string(),
+            3 => {
+                let mut domain = vec![0; client.read_u8().await? as usize];
+                client.read_exact(&mut domain).await?;
+                String::from_utf8(domain).map_err(|_| invalid("invalid domain"))?
+            }
+            4 => std::net::Ipv6Addr::from(client.read_u128().await?).to_string(),
+            _ => return Err(invalid("invalid address type")),
+        };
+        let target = format!("{host}:{}", client.read_u16().await?);
+        connections.lock().unwrap().push(format!("SOCKS5 {target}"));
+        let server = tokio::net::TcpStream::connect(target).await?;
+        client.write_all(&[5, 0, 0, 1, 0, 0, 0, 0, 0, 0]).await?;
+        server
+    } else {
+        let mut head = vec![first];
+        while !head.ends_with(b"\r\n\r\n") {
+            head.push(client.read_u8().await?);
+        }
+        let head = String::from_utf8(head).map_err(|_| invalid("invalid request"))?;
+        let request_line = head.lines().next().unwrap_or_default().to_string();
+        connections.lock().unwrap().push(request_line.clone());
+        match request_line.split_whitespace().collect::<Vec<_>>()[..] {
+            ["CONNECT", target, _] => {
+                let server = tokio::net::TcpStream::connect(target).await?;
+                client.write_all(b"HTTP/1.1 200 Connection established\r\n\r\n").await?;
+                server
+            }
+            // plain requests are sent with the absolute url, forwarded as is
+            [_, url, _] => {
+                let url = url::Url::parse(url).map_err(|_| invalid("invalid url"))?;
+                let host = url.host_str().ok_or_else(|| invalid("no host"))?;
+                let mut server =
+                    tokio::net::TcpStream::connect((host, url.port_or_known_default().unwrap_or(80))).await?;
+                server.write_all(head.as_bytes()).await?;
+                server
+            }
+            _ => return Err(invalid("invalid request line")),
+        }
+    };
+    tokio::io::copy_bidirectional(&mut client, &mut server).await?;
+    Ok(())
+}
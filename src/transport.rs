@@ -0,0 +1,103 @@
+//! HTTP transport of the REST APIs, replace it to add middleware such as logging, metrics, fault injection
+//! or record and replay
+//!
+//! Requests go through the transport once signed, rate limited requests and retries each go through it.
+//! # Examples
+//! ```rust,no_run
+//! use binance::{api::*, config::*, general::*, transport::*};
+//! use futures::future::BoxFuture;
+//!
+//! struct Logged(ReqwestTransport);
+//!
+//! impl Transport for Logged {
+//!     fn send(&self, request: HttpRequest) -> BoxFuture<'_, binance::errors::Result<HttpResponse>> {
+//!         Box::pin(async move {
+//!             println!("{} {}", request.method, request.url);
+//!             let response = self.0.send(request).await?;
+//!             println!("{}", response.status);
+//!             Ok(response)
+//!         })
+//!     }
+//! }
+//!
+//! let conf = Config::default().set_transport(Logged(ReqwestTransport::default()));
+//! let general: General = Binance::new_with_config(None, None, &conf);
+//! ```
+
+use std::fmt;
+use std::sync::Arc;
+
+use futures::future::BoxFuture;
+pub use reqwest::header::HeaderMap;
+pub use reqwest::{Method, StatusCode};
+
+use crate::errors::*;
+
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: Method,
+    /// Full url, including the query string and the signature
+    pub url: String,
+    pub headers: HeaderMap,
+}
+
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+/// Sends the requests of the REST APIs
+///
+/// Timeouts are retried according to the retry policy when reported as a timed out [`reqwest::Error`].
+pub trait Transport: Send + Sync + 'static {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>>;
+}
+
+/// The default transport
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: reqwest::Client) -> Self { ReqwestTransport { client } }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+        Box::pin(async move {
+            let response = self
+                .client
+                .request(request.method, &request.url)
+                .headers(request.headers)
+                .send()
+                .await?;
+            Ok(HttpResponse {
+                status: response.status(),
+                headers: response.headers().clone(),
+                body: response.bytes().await?.to_vec(),
+            })
+        })
+    }
+}
+
+/// A transport shared by every client built from a configuration
+#[derive(Clone)]
+pub struct SharedTransport(pub(crate) Arc<dyn Transport>);
+
+impl SharedTransport {
+    pub fn new(transport: impl Transport) -> Self { SharedTransport(Arc::new(transport)) }
+}
+
+impl fmt::Debug for SharedTransport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("SharedTransport") }
+}
+
+/// Transports are equal when they are the same instance
+impl PartialEq for SharedTransport {
+    fn eq(&self, other: &Self) -> bool { Arc::ptr_eq(&self.0, &other.0) }
+}
+
+impl Eq for SharedTransport {}
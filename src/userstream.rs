@@ -1,9 +1,25 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use futures::StreamExt;
+use tokio::time::{interval, interval_at, Instant};
+use tracing::warn;
+
 use crate::client::*;
+use crate::config::Config;
 use crate::errors::*;
 use crate::rest_model::*;
+use crate::websockets::{connect_split, endpoint_url, ConnectionEvent};
+use crate::ws_model::WebsocketEvent;
 
 static USER_DATA_STREAM: &str = "/api/v3/userDataStream";
 
+/// Listen keys expire 60 minutes after their last keep alive
+pub static DEFAULT_KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
+/// How often [`UserDataSession::event_loop`] checks whether it was stopped
+pub static STOP_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
 #[derive(Clone)]
 pub struct UserStream {
     pub client: Client,
@@ -50,3 +66,223 @@ impl UserStream {
         self.client.delete(USER_DATA_STREAM, listen_key, None).await
     }
 }
+
+/// The API a user data stream listens to, each has its own listen key endpoints
+#[derive(Clone)]
+pub enum ListenKeySource {
+    Spot(UserStream),
+    #[cfg(feature = "margin_api")]
+    Margin(crate::margin::Margin),
+    /// Isolated margin account of a symbol
+    #[cfg(feature = "margin_api")]
+    IsolatedMargin(crate::margin::Margin, String),
+    #[cfg(feature = "futures_api")]
+    Futures(crate::futures::userstream::FuturesUserStream),
+}
+
+impl ListenKeySource {
+    pub async fn start(&self) -> Result<String> {
+        match self {
+            ListenKeySource::Spot(user_stream) => Ok(user_stream.start().await?.listen_key),
+            #[cfg(feature = "margin_api")]
+            ListenKeySource::Margin(margin) => Ok(margin.start().await?.listen_key),
+            #[cfg(feature = "margin_api")]
+            ListenKeySource::IsolatedMargin(margin, symbol) => Ok(margin.start_isolated(symbol).await?.listen_key),
+            #[cfg(feature = "futures_api")]
+            ListenKeySource::Futures(user_stream) => Ok(user_stream.start().await?.listen_key),
+        }
+    }
+
+    pub async fn keep_alive(&self, listen_key: &str) -> Result<Success> {
+        match self {
+            ListenKeySource::Spot(user_stream) => user_stream.keep_alive(listen_key).await,
+            #[cfg(feature = "margin_api")]
+            ListenKeySource::Margin(margin) => margin.keep_alive(listen_key).await,
+            #[cfg(feature = "margin_api")]
+            ListenKeySource::IsolatedMargin(margin, symbol) => margin.keep_alive_isolated(listen_key, symbol).await,
+            #[cfg(feature = "futures_api")]
+            ListenKeySource::Futures(user_stream) => user_stream.keep_alive(listen_key).await,
+        }
+    }
+
+    pub async fn close(&self, listen_key: &str) -> Result<Success> {
+        match self {
+            ListenKeySource::Spot(user_stream) => user_stream.close(listen_key).await,
+            #[cfg(feature = "margin_api")]
+            ListenKeySource::Margin(margin) => margin.close(listen_key).await,
+            #[cfg(feature = "margin_api")]
+            ListenKeySource::IsolatedMargin(margin, symbol) => margin.close_isolated(listen_key, symbol).await,
+            #[cfg(feature = "futures_api")]
+            ListenKeySource::Futures(user_stream) => user_stream.close(listen_key).await,
+        }
+    }
+
+    fn ws_endpoint<'c>(&self, conf: &'c Config) -> &'c str {
+        match self {
+            #[cfg(feature = "futures_api")]
+            ListenKeySource::Futures(_) => &conf.futures_ws_endpoint,
+            _ => &conf.ws_endpoint,
+        }
+    }
+}
+
+/// Events received on a user data stream
+pub trait UserDataEvent: serde::de::DeserializeOwned {
+    /// Whether the event notifies that the listen key of the stream expired
+    fn is_listen_key_expired(&self) -> bool;
+}
+
+impl UserDataEvent for WebsocketEvent {
+    fn is_listen_key_expired(&self) -> bool { matches!(self, WebsocketEvent::ListenKeyExpired(_)) }
+}
+
+/// A user data stream that manages its own listen key
+///
+/// The listen key is created when the session starts and kept alive in the background,
+/// it is re-created and the websocket reconnected when it expires.
+/// Other disconnections and failures to create the listen key are retried according to [`Config::set_ws_reconnect`].
+/// # Examples
+/// ```rust,no_run
+/// use binance::{api::*, config::*, userstream::*, ws_model::*};
+/// use std::sync::atomic::AtomicBool;
+/// let conf = Config::testnet();
+/// let user_stream: UserStream = Binance::new_with_env(&conf);
+/// let mut session = UserDataSession::new(
+///     ListenKeySource::Spot(user_stream),
+///     |event: WebsocketEvent| {
+///         println!("{event:?}");
+///         Ok(())
+///     },
+///     conf,
+/// );
+/// let running = AtomicBool::new(true);
+/// let result = tokio_test::block_on(session.event_loop(&running));
+/// ```
+pub struct UserDataSession<'a, WE> {
+    source: ListenKeySource,
+    handler: Box<dyn FnMut(WE) -> Result<()> + 'a + Send>,
+    connection_handler: Option<Box<dyn FnMut(ConnectionEvent) -> Result<()> + 'a + Send>>,
+    keep_alive_interval: Duration,
+    conf: Config,
+}
+
+impl<'a, WE: UserDataEvent> UserDataSession<'a, WE> {
+    pub fn new<Callback>(source: ListenKeySource, handler: Callback, conf: Config) -> UserDataSession<'a, WE>
+    where
+        Callback: FnMut(WE) -> Result<()> + 'a + Send,
+    {
+        UserDataSession {
+            source,
+            handler: Box::new(handler),
+            connection_handler: None,
+            keep_alive_interval: DEFAULT_KEEP_ALIVE_INTERVAL,
+            conf,
+        }
+    }
+
+    /// Receive connection lifecycle events (connected, disconnected, reconnecting)
+    pub fn set_connection_handler<Callback>(&mut self, handler: Callback)
+    where
+        Callback: FnMut(ConnectionEvent) -> Result<()> + 'a + Send,
+    {
+        self.connection_handler = Some(Box::new(handler));
+    }
+
+    /// Interval between two keep alive requests, defaults to [`DEFAULT_KEEP_ALIVE_INTERVAL`]
+    pub fn set_keep_alive_interval(&mut self, interval: Duration) { self.keep_alive_interval = interval; }
+
+    fn notify(&mut self, event: ConnectionEvent) -> Result<()> {
+        match self.connection_handler {
+            Some(ref mut handler) => handler(event),
+            None => Ok(()),
+        }
+    }
+
+    /// Wait before reconnecting after `error`, fails if reconnection is disabled or gave up
+    async fn backoff(&mut self, error: Error, attempt: u32) -> Result<()> {
+        let Some(policy) = self.conf.ws_reconnect.clone() else {
+            return Err(error);
+        };
+        if policy.max_attempts.is_some_and(|max| attempt > max) {
+            return Err(Error::Msg(format!("Unable to reconnect after {} attempts", attempt - 1)));
+        }
+        let delay = policy.delay(attempt);
+        self.notify(ConnectionEvent::Reconnecting { attempt, delay })?;
+        tokio::time::sleep(delay).await;
+        Ok(())
+    }
+
+    /// Dispatches user data events to the handler until `running` is unset, checked every [`STOP_POLL_INTERVAL`].
+    /// The listen key is closed on exit, whether stopped or failed
+    pub async fn event_loop(&mut self, running: &AtomicBool) -> Result<()> {
+        let mut listen_key = None;
+        let result = self.run(running, &mut listen_key).await;
+        if let Some(listen_key) = listen_key {
+            if let Err(e) = self.source.close(&listen_key).await {
+                warn!("Failed to close listen key: {e}");
+            }
+        }
+        result
+    }
+
+    /// The event loop, `listen_key` holds the last listen key started
+    async fn run(&mut self, running: &AtomicBool, listen_key: &mut Option<String>) -> Result<()> {
+        let mut attempt = 0;
+        let mut stop_poll = interval(STOP_POLL_INTERVAL);
+        while running.load(Ordering::Relaxed) {
+            let key = match self.source.start().await {
+                Ok(key) => listen_key.insert(key).clone(),
+                Err(e) => {
+                    attempt += 1;
+                    self.backoff(e, attempt).await?;
+                    continue;
+                }
+            };
+            let url = endpoint_url(self.source.ws_endpoint(&self.conf), &key)?;
+            let (_, mut events) = match connect_split::<WE>(url.clone(), &self.conf).await {
+                Ok(split) => split,
+                Err(e) => {
+                    attempt += 1;
+                    self.backoff(e, attempt).await?;
+                    continue;
+                }
+            };
+            attempt = 0;
+            self.notify(ConnectionEvent::Connected { url: url.into() })?;
+            let start = Instant::now() + self.keep_alive_interval;
+            let mut keep_alive = interval_at(start, self.keep_alive_interval);
+            let error = loop {
+                tokio::select! {
+                    event = events.next() => match event {
+                        Some(Ok(event)) => {
+                            let expired = event.is_listen_key_expired();
+                            (self.handler)(event)?;
+                            if expired {
+                                break Error::InvalidListenKey("listenKeyExpired".to_string());
+                            }
+                        }
+                        Some(Err(Error::Json(e))) => return Err(Error::Json(e)),
+                        Some(Err(e)) => break e,
+                        None => break Error::Msg("Disconnected".to_string()),
+                    },
+                    _ = keep_alive.tick() => match self.source.keep_alive(&key).await {
+                        Ok(_) => {}
+                        Err(e @ Error::InvalidListenKey(_)) => break e,
+                        Err(e) => warn!("Failed to keep listen key alive: {e}"),
+                    },
+                    _ = stop_poll.tick() => if !running.load(Ordering::Relaxed) {
+                        return Ok(());
+                    },
+                }
+            };
+            self.notify(ConnectionEvent::Disconnected {
+                reason: error.to_string(),
+            })?;
+            if !matches!(error, Error::InvalidListenKey(_)) {
+                attempt += 1;
+                self.backoff(error, attempt).await?;
+            }
+        }
+        Ok(())
+    }
+}
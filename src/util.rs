@@ -1,10 +1,14 @@
+use std::collections::HashMap;
 use std::ops::Not;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::{Arc, Mutex};
 
 use boolinator::Boolinator;
 use chrono::{Duration, Utc};
 use serde_json::Value;
 
 use crate::errors::*;
+use crate::rest_model::Number;
 
 // pub fn build_request(parameters: &BTreeMap<String, String>) -> String {
 pub fn build_request(parameters: impl IntoIterator<Item = (impl AsRef<str>, impl AsRef<str>)>) -> String {
@@ -81,8 +85,76 @@ pub fn to_f64(v: &Value) -> f64 {
     v.as_str().unwrap().parse().unwrap()
 }
 
+/// Parse a number sent as a string, prices and quantities of klines for instance
+pub fn to_number(v: &Value) -> Result<Number> {
+    v.as_str()
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(|| Error::Msg(format!("Expected a number as a string, got {v}")))
+}
+
+/// A price or quantity for tests, from a float or a string, as a float or a decimal depending on the `decimal` feature
+#[cfg(any(test, feature = "testkit"))]
+pub fn num(value: impl ToString) -> Number { value.to_string().parse().unwrap() }
+
+/// Local timestamp, signed requests are stamped again with the server time offset of their client when sent
 pub fn get_timestamp() -> Result<u64> { Ok(Utc::now().timestamp_millis() as u64) }
 
+/// Offset between the server clock and the local clock of a host, shared by the clients of this host
+#[derive(Debug, Default)]
+pub(crate) struct TimeSync {
+    /// In milliseconds
+    offset: AtomicI64,
+    /// Local time of the last offset measurement, in milliseconds
+    last_sync: AtomicI64,
+}
+
+impl TimeSync {
+    /// Local timestamp corrected with the server time offset
+    pub(crate) fn timestamp(&self) -> u64 { (Utc::now().timestamp_millis() + self.offset()) as u64 }
+
+    pub(crate) fn offset(&self) -> i64 { self.offset.load(Ordering::Relaxed) }
+
+    pub(crate) fn set_offset(&self, offset: i64) {
+        self.offset.store(offset, Ordering::Relaxed);
+        self.last_sync.store(Utc::now().timestamp_millis(), Ordering::Relaxed);
+    }
+
+    /// Time elapsed since the offset was last set, in milliseconds
+    pub(crate) fn since_sync(&self) -> i64 { Utc::now().timestamp_millis() - self.last_sync.load(Ordering::Relaxed) }
+}
+
+/// Server time offsets by the host measuring them, shared by the clones of a [`crate::config::Config`]
+#[derive(Clone, Debug, Default)]
+pub struct TimeSyncs(Arc<Mutex<HashMap<String, Arc<TimeSync>>>>);
+
+impl TimeSyncs {
+    pub(crate) fn get(&self, host: &str) -> Arc<TimeSync> {
+        let mut syncs = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        syncs.entry(host.to_string()).or_default().clone()
+    }
+}
+
+impl PartialEq for TimeSyncs {
+    fn eq(&self, other: &Self) -> bool { Arc::ptr_eq(&self.0, &other.0) }
+}
+
+impl Eq for TimeSyncs {}
+
+/// Replace the timestamp of a signed request with `timestamp`
+pub(crate) fn refresh_timestamp(request: &str, timestamp: u64) -> String {
+    request
+        .split('&')
+        .map(|pair| {
+            if pair.starts_with("timestamp=") {
+                format!("timestamp={timestamp}")
+            } else {
+                pair.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
 /// Returns a duration in milliseconds for the `days`
 pub fn days_millis(days: i64) -> i64 { Duration::days(days).num_milliseconds() }
 
@@ -291,13 +291,14 @@ impl Wallet {
     /// ```rust,no_run
     /// use binance::{api::*, wallet::*, config::*, rest_model::*};
     /// let wallet: Wallet = Binance::new_with_env(&Config::testnet());
-    /// let records = tokio_test::block_on(wallet.universal_transfer("BTC".to_string(), 1.0, None, None, UniversalTransferType::FundingMain));
+    /// let amount = "1.0".parse().unwrap();
+    /// let records = tokio_test::block_on(wallet.universal_transfer("BTC".to_string(), amount, None, None, UniversalTransferType::FundingMain));
     /// assert!(records.is_ok(), "{:?}", records);
     /// ```
     pub async fn universal_transfer(
         &self,
         asset: String,
-        amount: f64,
+        amount: Number,
         from_symbol: Option<String>,
         to_symbol: Option<String>,
         transfer_type: UniversalTransferType,
@@ -1,16 +1,27 @@
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::collections::{BTreeSet, HashMap};
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+use std::sync::Arc;
+use std::task::{ready, Context, Poll};
+use std::time::Duration;
 
-use futures::StreamExt;
-use serde_json::from_str;
+use futures::stream::{SplitSink, SplitStream};
+use futures::{SinkExt, Stream, StreamExt};
+use serde_json::{from_str, Value};
 use tokio::net::TcpStream;
+use tokio::sync::{mpsc, oneshot};
 use tokio_tungstenite::tungstenite::handshake::client::Response;
 use tokio_tungstenite::tungstenite::Message;
 use tokio_tungstenite::WebSocketStream;
-use tokio_tungstenite::{connect_async, MaybeTlsStream};
+use tokio_tungstenite::MaybeTlsStream;
+use tracing::{debug, warn};
 use url::Url;
 
 use crate::config::Config;
 use crate::errors::*;
+use crate::net::connect_websocket;
+use crate::ws_model::{QueryResult, SubscriptionMethod, SubscriptionRequest};
 
 pub static STREAM_ENDPOINT: &str = "stream";
 pub static WS_ENDPOINT: &str = "ws";
@@ -58,10 +69,253 @@ pub fn diff_book_depth_stream(symbol: &str, update_speed: u16) -> String { forma
 
 fn combined_stream(streams: Vec<String>) -> String { streams.join("/") }
 
+pub(crate) fn endpoint_url(ws_endpoint: &str, endpoint: &str) -> Result<Url> {
+    Ok(Url::parse(&format!("{ws_endpoint}/{WS_ENDPOINT}/{endpoint}"))?)
+}
+
+pub(crate) fn combined_url(ws_endpoint: &str, endpoints: Vec<String>) -> Result<Url> {
+    let mut url = Url::parse(ws_endpoint)?;
+    url.path_segments_mut()
+        .map_err(|_| Error::UrlParserError(url::ParseError::RelativeUrlWithoutBase))?
+        .push(STREAM_ENDPOINT);
+    url.set_query(Some(&format!("streams={}", combined_stream(endpoints))));
+    Ok(url)
+}
+
+pub type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Write half of a split websocket, used to send control messages such as pings or subscriptions
+pub type ControlSink = SplitSink<WsStream, Message>;
+
+/// Read half of a split websocket, yields deserialized events
+/// The stream ends when the server closes the connection, dropping it cancels the subscription
+pub struct EventStream<WE> {
+    inner: SplitStream<WsStream>,
+    _event: PhantomData<fn() -> WE>,
+}
+
+impl<WE: serde::de::DeserializeOwned> EventStream<WE> {
+    /// Split a connected socket into its control sink and event stream
+    pub fn split(socket: WsStream) -> (ControlSink, EventStream<WE>) {
+        let (sink, inner) = socket.split();
+        (sink, EventStream {
+            inner,
+            _event: PhantomData,
+        })
+    }
+}
+
+impl<WE: serde::de::DeserializeOwned> Stream for EventStream<WE> {
+    type Item = Result<WE>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match ready!(self.inner.poll_next_unpin(cx)) {
+                Some(Ok(Message::Text(msg))) if msg.is_empty() => return Poll::Ready(None),
+                Some(Ok(Message::Text(msg))) => return Poll::Ready(Some(from_str(msg.as_str()).map_err(Error::from))),
+                Some(Ok(Message::Close(_))) | None => return Poll::Ready(None),
+                Some(Ok(Message::Ping(_) | Message::Pong(_) | Message::Binary(_) | Message::Frame(_))) => continue,
+                Some(Err(e)) => return Poll::Ready(Some(Err(e.into()))),
+            }
+        }
+    }
+}
+
+pub(crate) async fn connect_split<WE: serde::de::DeserializeOwned>(
+    url: Url,
+    conf: &Config,
+) -> Result<(ControlSink, EventStream<WE>)> {
+    let (socket, _) = connect_websocket(url, conf).await?;
+    Ok(EventStream::split(socket))
+}
+
+/// Connect to a websocket endpoint and return its events as a [`Stream`]
+/// # Examples
+/// ```rust,no_run
+/// use binance::{config::*, websockets::*, ws_model::*};
+/// use futures::StreamExt;
+/// let (_sink, mut events) = tokio_test::block_on(connect_stream::<WebsocketEvent>(
+///     &Config::default(),
+///     &agg_trade_stream("btcusdt"),
+/// ))
+/// .unwrap();
+/// let event = tokio_test::block_on(events.next());
+/// ```
+pub async fn connect_stream<WE: serde::de::DeserializeOwned>(
+    conf: &Config,
+    endpoint: &str,
+) -> Result<(ControlSink, EventStream<WE>)> {
+    connect_split(endpoint_url(&conf.ws_endpoint, endpoint)?, conf).await
+}
+
+/// Connect to multiple websocket endpoints and return their events as a [`Stream`]
+/// N.B: WE has to be CombinedStreamEvent
+pub async fn connect_multiple_streams<WE: serde::de::DeserializeOwned>(
+    conf: &Config,
+    endpoints: Vec<String>,
+) -> Result<(ControlSink, EventStream<WE>)> {
+    connect_split(combined_url(&conf.ws_endpoint, endpoints)?, conf).await
+}
+
+/// Connection lifecycle notifications, only emitted to the connection handler
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConnectionEvent {
+    /// The websocket handshake succeeded
+    Connected { url: String },
+    /// The connection dropped, `reason` describes the close frame or error
+    Disconnected { reason: String },
+    /// A new connection attempt will be made after `delay`
+    Reconnecting { attempt: u32, delay: Duration },
+}
+
+type PendingReply = oneshot::Sender<Result<Option<Value>>>;
+
+/// Handle to add, remove and list the streams of an open websocket while its event loop runs
+/// Requests are sent by the event loop, the returned futures resolve when Binance replies
+#[derive(Clone)]
+pub struct Subscriptions {
+    requests: mpsc::UnboundedSender<(SubscriptionRequest, PendingReply)>,
+    next_id: Arc<AtomicI64>,
+}
+
+impl Subscriptions {
+    async fn request(&self, method: SubscriptionMethod, params: Vec<String>) -> Result<Option<Value>> {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let (reply, response) = oneshot::channel();
+        self.requests
+            .send((SubscriptionRequest { method, params, id }, reply))
+            .map_err(|_| Error::Msg("The websocket was dropped".to_string()))?;
+        response
+            .await
+            .map_err(|_| Error::Msg("Connection closed before a response was received".to_string()))?
+    }
+
+    /// Subscribe to additional streams
+    pub async fn subscribe(&self, streams: Vec<String>) -> Result<()> {
+        self.request(SubscriptionMethod::Subscribe, streams).await?;
+        Ok(())
+    }
+
+    /// Unsubscribe from streams
+    pub async fn unsubscribe(&self, streams: Vec<String>) -> Result<()> {
+        self.request(SubscriptionMethod::Unsubscribe, streams).await?;
+        Ok(())
+    }
+
+    /// List the streams the connection is currently subscribed to
+    pub async fn list_subscriptions(&self) -> Result<Vec<String>> {
+        match self.request(SubscriptionMethod::ListSubscriptions, vec![]).await? {
+            Some(streams) => Ok(serde_json::from_value(streams)?),
+            None => Ok(vec![]),
+        }
+    }
+}
+
+/// Book-keeping for live subscriptions, shared by spot and futures event loops
+pub(crate) struct SubscriptionState {
+    handle: Subscriptions,
+    requests: mpsc::UnboundedReceiver<(SubscriptionRequest, PendingReply)>,
+    pending: HashMap<i64, (SubscriptionRequest, Option<PendingReply>)>,
+    added: BTreeSet<String>,
+    removed: BTreeSet<String>,
+}
+
+impl SubscriptionState {
+    pub(crate) fn new() -> Self {
+        let (sender, requests) = mpsc::unbounded_channel();
+        SubscriptionState {
+            handle: Subscriptions {
+                requests: sender,
+                next_id: Arc::new(AtomicI64::new(1)),
+            },
+            requests,
+            pending: HashMap::new(),
+            added: BTreeSet::new(),
+            removed: BTreeSet::new(),
+        }
+    }
+
+    pub(crate) fn handle(&self) -> Subscriptions { self.handle.clone() }
+
+    pub(crate) async fn next_request(&mut self) -> Option<(SubscriptionRequest, PendingReply)> {
+        self.requests.recv().await
+    }
+
+    /// Track a request until its reply comes back and return the message to send
+    pub(crate) fn register(&mut self, request: SubscriptionRequest, reply: Option<PendingReply>) -> Result<Message> {
+        let message = Message::Text(serde_json::to_string(&request)?);
+        self.pending.insert(request.id, (request, reply));
+        Ok(message)
+    }
+
+    /// Resolve the pending request matching `msg`, returns false if `msg` is not a reply
+    pub(crate) fn resolve(&mut self, msg: &str) -> bool {
+        if self.pending.is_empty() {
+            return false;
+        }
+        let Ok(reply) = from_str::<QueryResult>(msg) else {
+            return false;
+        };
+        let Some((request, sender)) = self.pending.remove(&reply.id) else {
+            return false;
+        };
+        let result = match reply.error {
+            Some(e) => Err(Error::Msg(format!("code: {}, msg: {}", e.code, e.msg))),
+            None => {
+                match request.method {
+                    SubscriptionMethod::Subscribe => {
+                        for stream in request.params {
+                            self.removed.remove(&stream);
+                            self.added.insert(stream);
+                        }
+                    }
+                    SubscriptionMethod::Unsubscribe => {
+                        for stream in request.params {
+                            self.added.remove(&stream);
+                            self.removed.insert(stream);
+                        }
+                    }
+                    SubscriptionMethod::ListSubscriptions => {}
+                }
+                Ok(reply.result)
+            }
+        };
+        if let Some(sender) = sender {
+            let _ = sender.send(result);
+        }
+        true
+    }
+
+    /// Fail all requests still waiting for a reply
+    pub(crate) fn disconnected(&mut self) { self.pending.clear(); }
+
+    /// Messages replaying live changes on a new connection, so it matches the streams before it dropped
+    pub(crate) fn replay(&mut self) -> Result<Vec<Message>> {
+        let mut requests = vec![];
+        if !self.added.is_empty() {
+            requests.push((SubscriptionMethod::Subscribe, self.added.iter().cloned().collect()));
+        }
+        if !self.removed.is_empty() {
+            requests.push((SubscriptionMethod::Unsubscribe, self.removed.iter().cloned().collect()));
+        }
+        requests
+            .into_iter()
+            .map(|(method, params)| {
+                let id = self.handle.next_id.fetch_add(1, Ordering::Relaxed);
+                self.register(SubscriptionRequest { method, params, id }, None)
+            })
+            .collect()
+    }
+}
+
 pub struct WebSockets<'a, WE> {
-    pub socket: Option<(WebSocketStream<MaybeTlsStream<TcpStream>>, Response)>,
+    pub socket: Option<(WsStream, Response)>,
     handler: Box<dyn FnMut(WE) -> Result<()> + 'a + Send>,
+    connection_handler: Option<Box<dyn FnMut(ConnectionEvent) -> Result<()> + 'a + Send>>,
+    url: Option<Url>,
+    subscriptions: SubscriptionState,
     conf: Config,
+    ws_endpoint: String,
 }
 
 impl<'a, WE: serde::de::DeserializeOwned> WebSockets<'a, WE> {
@@ -85,38 +339,119 @@ impl<'a, WE: serde::de::DeserializeOwned> WebSockets<'a, WE> {
         WebSockets {
             socket: None,
             handler: Box::new(handler),
+            connection_handler: None,
+            url: None,
+            subscriptions: SubscriptionState::new(),
+            ws_endpoint: conf.ws_endpoint.clone(),
             conf,
         }
     }
 
+    /// Connect to another websocket endpoint than the spot one
+    #[cfg(any(feature = "options", feature = "futures_api"))]
+    pub(crate) fn with_ws_endpoint(mut self, ws_endpoint: String) -> Self {
+        self.ws_endpoint = ws_endpoint;
+        self
+    }
+
+    /// Handle to subscribe and unsubscribe streams at runtime, requests are sent by [`WebSockets::event_loop`]
+    /// Live changes are replayed when the connection is re-established
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{websockets::*, ws_model::*};
+    /// use std::sync::atomic::AtomicBool;
+    /// let mut web_socket: WebSockets<'_, CombinedStreamEvent<WebsocketEvent>> = WebSockets::new(|_| Ok(()));
+    /// let subscriptions = web_socket.subscriptions();
+    /// tokio::spawn(async move {
+    ///     subscriptions.subscribe(vec![agg_trade_stream("ethusdt")]).await
+    /// });
+    /// let running = AtomicBool::new(true);
+    /// tokio_test::block_on(async {
+    ///     web_socket.connect_multiple(vec![agg_trade_stream("btcusdt")]).await?;
+    ///     web_socket.event_loop(&running).await
+    /// });
+    /// ```
+    pub fn subscriptions(&self) -> Subscriptions { self.subscriptions.handle() }
+
+    /// Receive connection lifecycle events (connected, disconnected, reconnecting)
+    /// Reconnection itself is enabled with [`Config::set_ws_reconnect`]
+    pub fn set_connection_handler<Callback>(&mut self, handler: Callback)
+    where
+        Callback: FnMut(ConnectionEvent) -> Result<()> + 'a + Send,
+    {
+        self.connection_handler = Some(Box::new(handler));
+    }
+
     /// Connect to multiple websocket endpoints
     /// N.B: WE has to be CombinedStreamEvent
     pub async fn connect_multiple(&mut self, endpoints: Vec<String>) -> Result<()> {
-        let mut url = Url::parse(&self.conf.ws_endpoint)?;
-        url.path_segments_mut()
-            .map_err(|_| Error::UrlParserError(url::ParseError::RelativeUrlWithoutBase))?
-            .push(STREAM_ENDPOINT);
-        url.set_query(Some(&format!("streams={}", combined_stream(endpoints))));
+        let url = combined_url(&self.ws_endpoint, endpoints)?;
 
         self.handle_connect(url).await
     }
 
     /// Connect to a websocket endpoint
     pub async fn connect(&mut self, endpoint: &str) -> Result<()> {
-        let wss: String = format!("{}/{}/{}", self.conf.ws_endpoint, WS_ENDPOINT, endpoint);
-        let url = Url::parse(&wss)?;
+        let url = endpoint_url(&self.ws_endpoint, endpoint)?;
 
         self.handle_connect(url).await
     }
 
     async fn handle_connect(&mut self, url: Url) -> Result<()> {
-        match connect_async(url).await {
-            Ok(answer) => {
-                self.socket = Some(answer);
-                Ok(())
+        debug!("{url}");
+        self.socket = Some(connect_websocket(url.clone(), &self.conf).await?);
+        self.url = Some(url.clone());
+        self.notify(ConnectionEvent::Connected { url: url.into() })
+    }
+
+    fn notify(&mut self, event: ConnectionEvent) -> Result<()> {
+        match self.connection_handler {
+            Some(ref mut handler) => handler(event),
+            None => Ok(()),
+        }
+    }
+
+    /// Connect to `url` again and replay the live subscriptions, the socket is only kept when both succeed
+    async fn redial(&mut self, url: &Url) -> Result<()> {
+        let (mut socket, response) = connect_websocket(url.clone(), &self.conf).await?;
+        let replayed = async {
+            for message in self.subscriptions.replay()? {
+                socket.send(message).await?;
+            }
+            Ok(())
+        };
+        if let Err(e) = replayed.await {
+            self.subscriptions.disconnected();
+            return Err(e);
+        }
+        self.socket = Some((socket, response));
+        Ok(())
+    }
+
+    /// Redial the last connected url until it succeeds, the policy gives up or `running` is unset
+    async fn reconnect(&mut self, running: &AtomicBool, error: Error) -> Result<()> {
+        let (policy, url) = match (self.conf.ws_reconnect.clone(), self.url.clone()) {
+            (Some(policy), Some(url)) => (policy, url),
+            _ => return Err(error),
+        };
+        self.notify(ConnectionEvent::Disconnected {
+            reason: error.to_string(),
+        })?;
+        let mut attempt = 0;
+        while running.load(Ordering::Relaxed) {
+            attempt += 1;
+            if policy.max_attempts.is_some_and(|max| attempt > max) {
+                return Err(Error::Msg(format!("Unable to reconnect after {} attempts", attempt - 1)));
+            }
+            let delay = policy.delay(attempt);
+            self.notify(ConnectionEvent::Reconnecting { attempt, delay })?;
+            tokio::time::sleep(delay).await;
+            match self.redial(&url).await {
+                Ok(()) => return self.notify(ConnectionEvent::Connected { url: url.into() }),
+                Err(e) => warn!("Reconnection attempt {attempt} to {url} failed: {e}"),
             }
-            Err(e) => Err(Error::Msg(format!("Error during handshake {e}"))),
         }
+        Ok(())
     }
 
     /// Disconnect from the endpoint
@@ -129,28 +464,56 @@ impl<'a, WE: serde::de::DeserializeOwned> WebSockets<'a, WE> {
         }
     }
 
-    pub fn socket(&self) -> &Option<(WebSocketStream<MaybeTlsStream<TcpStream>>, Response)> { &self.socket }
+    pub fn socket(&self) -> &Option<(WsStream, Response)> { &self.socket }
+
+    /// Take the connected socket and split it into a control sink and an event stream
+    /// The handler is no longer called for events read from the returned stream
+    pub fn split(&mut self) -> Result<(ControlSink, EventStream<WE>)> {
+        match self.socket.take() {
+            Some((socket, _)) => Ok(EventStream::split(socket)),
+            None => Err(Error::Msg("Not connected".to_string())),
+        }
+    }
 
+    /// Dispatches events to the handler until `running` is unset
+    /// When a reconnect policy is configured, dropped connections are redialed on the same streams
     pub async fn event_loop(&mut self, running: &AtomicBool) -> Result<()> {
         while running.load(Ordering::Relaxed) {
-            if let Some((ref mut socket, _)) = self.socket {
-                // TODO: return error instead of panic?
-                let message = socket.next().await.unwrap()?;
-
+            let error = if let Some((ref mut socket, _)) = self.socket {
+                let message = tokio::select! {
+                    message = socket.next() => message,
+                    Some((request, reply)) = self.subscriptions.next_request() => {
+                        let message = self.subscriptions.register(request, Some(reply))?;
+                        match socket.send(message).await {
+                            Ok(()) => continue,
+                            Err(e) => Some(Err(e)),
+                        }
+                    }
+                };
                 match message {
-                    Message::Text(msg) => {
+                    Some(Ok(Message::Text(msg))) => {
                         if msg.is_empty() {
                             return Ok(());
                         }
+                        if self.subscriptions.resolve(&msg) {
+                            continue;
+                        }
                         let event: WE = from_str(msg.as_str())?;
                         (self.handler)(event)?;
+                        continue;
                     }
-                    Message::Ping(_) | Message::Pong(_) | Message::Binary(_) | Message::Frame(_) => {}
-                    Message::Close(e) => {
-                        return Err(Error::Msg(format!("Disconnected {e:?}")));
-                    }
+                    Some(Ok(Message::Ping(_) | Message::Pong(_) | Message::Binary(_) | Message::Frame(_))) => continue,
+                    Some(Ok(Message::Close(e))) => Error::Msg(format!("Disconnected {e:?}")),
+                    Some(Err(e)) => Error::Tungstenite(e),
+                    None => Error::Msg("Disconnected".to_string()),
                 }
-            }
+            } else {
+                Error::Msg("Not connected".to_string())
+            };
+            debug!("websocket connection lost: {error}");
+            self.socket = None;
+            self.subscriptions.disconnected();
+            self.reconnect(running, error).await?;
         }
         Ok(())
     }
@@ -0,0 +1,328 @@
+//! WebSocket API, signed requests such as order placement sent over one persistent connection
+//!
+//! Requests are multiplexed by id, replies deserialize into the same models as the REST endpoints.
+//! # Examples
+//! ```rust,no_run
+//! use binance::{account::*, config::*, rest_model::*, ws_api::*};
+//! tokio_test::block_on(async {
+//!     let ws_api = WsApi::connect(Some("api_key".into()), Some("secret_key".into()), &Config::testnet()).await?;
+//!     let order = OrderRequest {
+//!         symbol: "BTCUSDT".to_string(),
+//!         side: OrderSide::Buy,
+//!         order_type: OrderType::Market,
+//!         quantity: Some("0.001".parse().unwrap()),
+//!         ..OrderRequest::default()
+//!     };
+//!     let transaction = ws_api.place_order(order).await?;
+//!     Ok::<_, binance::errors::Error>(())
+//! });
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use chrono::Utc;
+use futures::{SinkExt, StreamExt};
+use serde::de::DeserializeOwned;
+use serde_json::{from_str, Value};
+use tokio::sync::{mpsc, oneshot};
+use tokio_tungstenite::tungstenite::Message;
+use tracing::{debug, warn};
+use url::Url;
+
+use crate::account::{OrderCancellation, OrderRequest, OrderStatusRequest};
+use crate::client::handle_content_error;
+use crate::config::Config;
+use crate::errors::*;
+use crate::net::connect_websocket;
+use crate::rest_model::{AccountInformation, Order, OrderCanceled, ServerTime, Transaction};
+use crate::signer::{config_signer, SignatureMethod, Signer};
+use crate::util::{build_request, TimeSync};
+use crate::websockets::WsStream;
+use crate::ws_model::{SessionStatus, WsApiRequest, WsApiResponse};
+
+type PendingReply = oneshot::Sender<Result<Value>>;
+
+enum Command {
+    Send(WsApiRequest, PendingReply),
+    /// Forget the reply to a request that is no longer awaited
+    Cancel(u64),
+}
+
+/// A WebSocket API connection, cloned handles share the connection which closes once they are all dropped
+///
+/// Requests fail once the connection drops, it is not re-established.
+#[derive(Clone)]
+pub struct WsApiConnection {
+    api_key: String,
+    signer: Option<Arc<dyn Signer>>,
+    signature_method: SignatureMethod,
+    recv_window: u64,
+    timeout: Option<Duration>,
+    requests: mpsc::UnboundedSender<Command>,
+    next_id: Arc<AtomicU64>,
+    logged_on: Arc<AtomicBool>,
+    time_sync: Arc<TimeSync>,
+}
+
+impl WsApiConnection {
+    /// Connect to a WebSocket API endpoint, the secret key holds the private key of the configured signature method
+    pub async fn connect(
+        endpoint: &str,
+        api_key: Option<String>,
+        secret_key: Option<String>,
+        conf: &Config,
+    ) -> Result<Self> {
+        let signer = config_signer(conf, secret_key.as_deref())?;
+        let (socket, _) = connect_websocket(Url::parse(endpoint)?, conf).await?;
+        let (sender, requests) = mpsc::unbounded_channel();
+        tokio::spawn(dispatch(socket, requests));
+        Ok(WsApiConnection {
+            api_key: api_key.unwrap_or_default(),
+            signer,
+            signature_method: conf.signature_method,
+            recv_window: conf.recv_window,
+            timeout: conf.timeout.map(Duration::from_secs),
+            requests: sender,
+            next_id: Arc::new(AtomicU64::new(1)),
+            logged_on: Arc::new(AtomicBool::new(false)),
+            time_sync: conf.time_syncs.get(endpoint),
+        })
+    }
+
+    /// Send a public request and wait for its result
+    pub async fn request<T: DeserializeOwned, P: serde::Serialize>(&self, method: &str, params: P) -> Result<T> {
+        self.send(method, to_params(params)?).await
+    }
+
+    /// Send an authenticated request and wait for its result,
+    /// requests are signed unless the session is logged on with [`WsApiConnection::logon`]
+    pub async fn signed_request<T: DeserializeOwned, P: serde::Serialize>(&self, method: &str, params: P) -> Result<T> {
+        let mut params = to_params(params)?;
+        if self.recv_window > 0 {
+            params
+                .entry("recvWindow".to_string())
+                .or_insert_with(|| self.recv_window.to_string());
+        }
+        params.insert("timestamp".to_string(), self.time_sync.timestamp().to_string());
+        if !self.logged_on.load(Ordering::Relaxed) {
+            self.sign(&mut params)?;
+        }
+        self.send(method, params).await
+    }
+
+    /// Authenticate the session with the API key so that later requests no longer need to be signed
+    ///
+    /// Only sessions using an Ed25519 key can log on.
+    pub async fn logon(&self) -> Result<SessionStatus> {
+        if self.signature_method != SignatureMethod::Ed25519 {
+            return Err(Error::InvalidKey("session logon requires an Ed25519 key".to_string()));
+        }
+        let mut params = BTreeMap::from([("timestamp".to_string(), self.time_sync.timestamp().to_string())]);
+        self.sign(&mut params)?;
+        let status = self.send("session.logon", params).await?;
+        self.logged_on.store(true, Ordering::Relaxed);
+        Ok(status)
+    }
+
+    /// Forget the API key of the session, later requests are signed again
+    pub async fn logout(&self) -> Result<SessionStatus> {
+        let status = self.send("session.logout", BTreeMap::new()).await?;
+        self.logged_on.store(false, Ordering::Relaxed);
+        Ok(status)
+    }
+
+    /// Measure the offset between the server clock and the local clock with the `time` method,
+    /// the offset is then applied to the timestamp of signed requests sent over this connection
+    pub async fn sync_time(&self) -> Result<i64> {
+        let before = Utc::now().timestamp_millis();
+        let time: ServerTime = self.send("time", BTreeMap::new()).await?;
+        let after = Utc::now().timestamp_millis();
+        // assume the server time was taken halfway through the round trip
+        let offset = time.server_time as i64 - (before + after) / 2;
+        self.time_sync.set_offset(offset);
+        Ok(offset)
+    }
+
+    pub async fn session_status(&self) -> Result<SessionStatus> { self.send("session.status", BTreeMap::new()).await }
+
+    /// Add the API key and the signature of the parameters, sorted by name
+    fn sign(&self, params: &mut BTreeMap<String, String>) -> Result<()> {
+        let signer = self
+            .signer
+            .as_ref()
+            .ok_or_else(|| Error::InvalidKey("a secret key is required to sign requests".to_string()))?;
+        params.insert("apiKey".to_string(), self.api_key.clone());
+        let signature = signer.sign(&build_request(params.iter()))?;
+        params.insert("signature".to_string(), signature);
+        Ok(())
+    }
+
+    async fn send<T: DeserializeOwned>(&self, method: &str, params: BTreeMap<String, String>) -> Result<T> {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let (reply, response) = oneshot::channel();
+        let request = WsApiRequest {
+            id,
+            method: method.to_string(),
+            params,
+        };
+        self.requests
+            .send(Command::Send(request, reply))
+            .map_err(|_| Error::Msg("The ws-api connection is closed".to_string()))?;
+        let response = async {
+            response
+                .await
+                .map_err(|_| Error::Msg("Connection closed before a response was received".to_string()))?
+        };
+        let result = match self.timeout {
+            Some(timeout) => match tokio::time::timeout(timeout, response).await {
+                Ok(result) => result?,
+                Err(_) => {
+                    let _ = self.requests.send(Command::Cancel(id));
+                    return Err(Error::Msg(format!("No response to {method} after {timeout:?}")));
+                }
+            },
+            None => response.await?,
+        };
+        Ok(serde_json::from_value(result)?)
+    }
+}
+
+/// Request parameters as strings, serialized like REST query parameters
+fn to_params<P: serde::Serialize>(params: P) -> Result<BTreeMap<String, String>> {
+    Ok(qs::from_str(&qs::to_string(&params)?)?)
+}
+
+/// Send requests and route replies to them until every handle is dropped or the connection drops,
+/// requests still waiting for a reply fail when it returns
+async fn dispatch(mut socket: WsStream, mut requests: mpsc::UnboundedReceiver<Command>) {
+    let mut pending: HashMap<u64, PendingReply> = HashMap::new();
+    loop {
+        tokio::select! {
+            command = requests.recv() => {
+                let (request, reply) = match command {
+                    Some(Command::Send(request, reply)) => (request, reply),
+                    Some(Command::Cancel(id)) => {
+                        pending.remove(&id);
+                        continue;
+                    }
+                    None => {
+                        let _ = socket.close(None).await;
+                        return;
+                    }
+                };
+                let message = match serde_json::to_string(&request) {
+                    Ok(message) => message,
+                    Err(e) => {
+                        let _ = reply.send(Err(e.into()));
+                        continue;
+                    }
+                };
+                pending.insert(request.id, reply);
+                if let Err(e) = socket.send(Message::Text(message)).await {
+                    debug!("ws-api connection lost: {e}");
+                    return;
+                }
+            }
+            message = socket.next() => match message {
+                Some(Ok(Message::Text(msg))) => resolve(&mut pending, &msg),
+                Some(Ok(Message::Ping(_) | Message::Pong(_) | Message::Binary(_) | Message::Frame(_))) => {}
+                Some(Ok(Message::Close(e))) => {
+                    debug!("ws-api connection closed: {e:?}");
+                    return;
+                }
+                Some(Err(e)) => {
+                    debug!("ws-api connection lost: {e}");
+                    return;
+                }
+                None => return,
+            }
+        }
+    }
+}
+
+fn resolve(pending: &mut HashMap<u64, PendingReply>, msg: &str) {
+    let response = match from_str::<WsApiResponse>(msg) {
+        Ok(response) => response,
+        Err(e) => {
+            warn!("Unexpected ws-api message {msg}: {e}");
+            return;
+        }
+    };
+    if let Some(reply) = response.id.and_then(|id| pending.remove(&id)) {
+        let _ = reply.send(response_result(response));
+    }
+}
+
+fn response_result(response: WsApiResponse) -> Result<Value> {
+    let Some(error) = response.error else {
+        return Ok(response.result.unwrap_or(Value::Null));
+    };
+    // rate limit errors carry the timestamp at which requests are accepted again
+    let retry_at = error.pointer("/data/retryAfter").and_then(Value::as_u64);
+    match response.status {
+        429 => Err(Error::RateLimited {
+            retry_after: retry_at.and_then(|retry_at| {
+                let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?;
+                Duration::from_millis(retry_at).checked_sub(now)
+            }),
+        }),
+        418 => Err(Error::IpBanned { until: retry_at }),
+        _ => Err(handle_content_error(serde_json::from_value(error)?)),
+    }
+}
+
+/// Spot trading over the WebSocket API
+#[derive(Clone)]
+pub struct WsApi {
+    pub connection: WsApiConnection,
+}
+
+impl WsApi {
+    /// Connect to the spot WebSocket API endpoint of `conf`
+    pub async fn connect(api_key: Option<String>, secret_key: Option<String>, conf: &Config) -> Result<Self> {
+        Ok(WsApi {
+            connection: WsApiConnection::connect(&conf.ws_api_endpoint, api_key, secret_key, conf).await?,
+        })
+    }
+
+    /// Test connectivity
+    pub async fn ping(&self) -> Result<()> {
+        let _: Value = self.connection.request("ping", BTreeMap::<String, String>::new()).await?;
+        Ok(())
+    }
+
+    /// General account information
+    pub async fn get_account(&self) -> Result<AccountInformation> {
+        self.connection
+            .signed_request("account.status", BTreeMap::<String, String>::new())
+            .await
+    }
+
+    /// Place an order, validated before sending like [`crate::account::Account::place_order`]
+    pub async fn place_order(&self, order: OrderRequest) -> Result<Transaction> {
+        order.valid()?;
+        self.connection.signed_request("order.place", order).await
+    }
+
+    /// Check an order's status
+    pub async fn order_status(&self, osr: OrderStatusRequest) -> Result<Order> {
+        self.connection.signed_request("order.status", osr).await
+    }
+
+    /// All currently open orders for a single symbol
+    pub async fn get_open_orders<S>(&self, symbol: S) -> Result<Vec<Order>>
+    where
+        S: Into<String>,
+    {
+        let params = BTreeMap::from([("symbol", symbol.into())]);
+        self.connection.signed_request("openOrders.status", params).await
+    }
+
+    /// Cancel an order
+    pub async fn cancel_order(&self, o: OrderCancellation) -> Result<OrderCanceled> {
+        self.connection.signed_request("order.cancel", o).await
+    }
+}
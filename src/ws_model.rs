@@ -1,4 +1,9 @@
-use crate::rest_model::{string_or_float, Asks, Bids, OrderBook, OrderSide, OrderStatus, OrderType, TimeInForce};
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
+use crate::rest_model::{string_or_float, string_or_u64, Asks, Bids, Number, OrderBook, OrderSide, OrderStatus,
+                        OrderType, SelfTradePreventionMode, TimeInForce};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "e")]
@@ -23,14 +28,76 @@ pub enum WebsocketEvent {
     OrderUpdate(Box<OrderUpdate>),
     #[serde(alias = "listStatus")]
     ListOrderUpdate(Box<OrderListUpdate>),
+    #[serde(alias = "listenKeyExpired")]
+    ListenKeyExpired(Box<ListenKeyExpired>),
 }
 
+/// Reply to a [`SubscriptionRequest`], `result` is null for (un)subscriptions
+/// and holds the list of streams for `LIST_SUBSCRIPTIONS`
 #[derive(Serialize, Deserialize, Debug)]
 pub struct QueryResult {
-    pub result: Option<String>,
+    #[serde(default)]
+    pub result: Option<serde_json::Value>,
+    #[serde(default)]
+    pub error: Option<QueryError>,
+    pub id: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct QueryError {
+    pub code: i32,
+    pub msg: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SubscriptionMethod {
+    Subscribe,
+    Unsubscribe,
+    ListSubscriptions,
+}
+
+/// Live control request sent on an open websocket
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubscriptionRequest {
+    pub method: SubscriptionMethod,
+    pub params: Vec<String>,
     pub id: i64,
 }
 
+/// Request sent to the WebSocket API, signed requests carry `apiKey`, `timestamp` and `signature` in `params`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WsApiRequest {
+    pub id: u64,
+    pub method: String,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub params: BTreeMap<String, String>,
+}
+
+/// Reply of the WebSocket API, `result` is set when `status` is 200 and `error` otherwise
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WsApiResponse {
+    /// `None` when the request could not be parsed
+    pub id: Option<u64>,
+    pub status: u16,
+    #[serde(default)]
+    pub result: Option<Value>,
+    #[serde(default)]
+    pub error: Option<Value>,
+}
+
+/// State of a WebSocket API session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionStatus {
+    /// The API key the session is logged on with
+    pub api_key: Option<String>,
+    pub authorized_since: Option<u64>,
+    pub connected_since: u64,
+    pub return_rate_limits: bool,
+    pub server_time: u64,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct TradesEvent {
@@ -61,7 +128,7 @@ pub struct TradesEvent {
     #[serde(rename = "m")]
     pub is_buyer_maker: bool,
 
-    #[serde(skip, rename = "M")]
+    #[serde(default, rename = "M")]
     pub m_ignore: bool,
 }
 
@@ -95,7 +162,7 @@ pub struct TradeEvent {
     #[serde(rename = "m")]
     pub is_buyer_maker: bool,
 
-    #[serde(skip, rename = "M")]
+    #[serde(default, rename = "M")]
     pub m_ignore: bool,
 }
 
@@ -197,33 +264,33 @@ pub struct Kline {
     pub last_trade_id: i64,
     #[serde(with = "string_or_float")]
     #[serde(rename = "o")]
-    pub open: f64,
+    pub open: Number,
     #[serde(with = "string_or_float")]
     #[serde(rename = "c")]
-    pub close: f64,
+    pub close: Number,
     #[serde(with = "string_or_float")]
     #[serde(rename = "h")]
-    pub high: f64,
+    pub high: Number,
     #[serde(with = "string_or_float")]
     #[serde(rename = "l")]
-    pub low: f64,
+    pub low: Number,
     #[serde(with = "string_or_float")]
     #[serde(rename = "v")]
-    pub volume: f64,
+    pub volume: Number,
     #[serde(rename = "n")]
     pub number_of_trades: i64,
     #[serde(rename = "x")]
     pub is_final_bar: bool,
     #[serde(with = "string_or_float")]
     #[serde(rename = "q")]
-    pub quote_volume: f64,
+    pub quote_volume: Number,
     #[serde(with = "string_or_float")]
     #[serde(rename = "V")]
-    pub active_buy_volume: f64,
+    pub active_buy_volume: Number,
     #[serde(with = "string_or_float")]
     #[serde(rename = "Q")]
-    pub active_volume_buy_quote: f64,
-    #[serde(skip, rename = "B")]
+    pub active_volume_buy_quote: Number,
+    #[serde(default, rename = "B")]
     pub ignore_me: String,
 }
 
@@ -247,6 +314,18 @@ pub struct DepthOrderBookEvent {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct BookTickerEvent {
+    /// "bookTicker", only sent on futures streams
+    #[serde(rename = "e", default, skip_serializing_if = "Option::is_none")]
+    pub event_type: Option<String>,
+
+    /// Only sent on futures streams
+    #[serde(rename = "E")]
+    pub event_time: Option<u64>,
+
+    /// Only sent on futures streams
+    #[serde(rename = "T")]
+    pub transaction_time: Option<u64>,
+
     #[serde(rename = "u")]
     pub update_id: u64,
 
@@ -254,16 +333,16 @@ pub struct BookTickerEvent {
     pub symbol: String,
 
     #[serde(rename = "b", with = "string_or_float")]
-    pub best_bid: f64,
+    pub best_bid: Number,
 
     #[serde(rename = "B", with = "string_or_float")]
-    pub best_bid_qty: f64,
+    pub best_bid_qty: Number,
 
     #[serde(rename = "a", with = "string_or_float")]
-    pub best_ask: f64,
+    pub best_ask: Number,
 
     #[serde(rename = "A", with = "string_or_float")]
-    pub best_ask_qty: f64,
+    pub best_ask_qty: Number,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -299,13 +378,13 @@ impl<T> CombinedStreamEvent<T> {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountPositionUpdate {
-    #[serde(alias = "E")]
+    #[serde(rename = "E", alias = "eventTime")]
     pub event_time: u64,
 
-    #[serde(alias = "u")]
+    #[serde(rename = "u", alias = "lastUpdateTime")]
     pub last_update_time: u64,
 
-    #[serde(alias = "B")]
+    #[serde(rename = "B", alias = "balances")]
     pub balances: Vec<EventBalance>,
 }
 
@@ -346,16 +425,16 @@ pub struct EventBalance {
     pub asset: String,
     #[serde(rename = "f")]
     #[serde(with = "string_or_float")]
-    pub free: f64,
+    pub free: Number,
     #[serde(rename = "l")]
     #[serde(with = "string_or_float")]
-    pub locked: f64,
+    pub locked: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct BalanceUpdate {
-    #[serde(alias = "E")]
+    #[serde(rename = "E", alias = "eventTime")]
     pub event_time: u64,
 
     #[serde(rename = "a")]
@@ -363,9 +442,9 @@ pub struct BalanceUpdate {
 
     #[serde(rename = "d")]
     #[serde(with = "string_or_float")]
-    pub delta: f64,
+    pub delta: Number,
 
-    #[serde(alias = "T")]
+    #[serde(rename = "T", alias = "clearTime")]
     pub clear_time: u64,
 }
 
@@ -386,16 +465,16 @@ pub struct OrderUpdate {
     pub time_in_force: TimeInForce,
     #[serde(rename = "q")]
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
     #[serde(rename = "p")]
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(rename = "P")]
     #[serde(with = "string_or_float")]
-    pub stop_price: f64,
+    pub stop_price: Number,
     #[serde(rename = "F")]
     #[serde(with = "string_or_float")]
-    pub iceberg_qty: f64,
+    pub iceberg_qty: Number,
     #[serde(rename = "g")]
     pub order_list_id: i64,
     #[serde(rename = "C")]
@@ -410,42 +489,60 @@ pub struct OrderUpdate {
     pub order_id: u64,
     #[serde(rename = "l")]
     #[serde(with = "string_or_float")]
-    pub qty_last_executed: f64,
+    pub qty_last_executed: Number,
     #[serde(rename = "z")]
     #[serde(with = "string_or_float")]
-    pub cumulative_filled_qty: f64,
+    pub cumulative_filled_qty: Number,
     #[serde(rename = "L")]
     #[serde(with = "string_or_float")]
-    pub last_executed_price: f64,
+    pub last_executed_price: Number,
     #[serde(rename = "n")]
     #[serde(with = "string_or_float")]
-    pub commission: f64,
+    pub commission: Number,
     #[serde(rename = "N")]
     pub commission_asset: Option<String>,
     #[serde(rename = "T")]
     pub trade_order_time: u64,
     #[serde(rename = "t")]
     pub trade_id: i64,
-    #[serde(skip, rename = "I")]
+    #[serde(default, rename = "I")]
     pub i_ignore: u64,
     #[serde(rename = "w")]
     pub is_order_on_the_book: bool,
     #[serde(rename = "m")]
     pub is_buyer_maker: bool,
-    #[serde(skip, rename = "M")]
+    #[serde(default, rename = "M")]
     pub m_ignore: bool,
     #[serde(rename = "O")]
     pub order_creation_time: u64,
     #[serde(rename = "Z")]
     #[serde(with = "string_or_float")]
-    pub cumulative_quote_asset_transacted_qty: f64,
+    pub cumulative_quote_asset_transacted_qty: Number,
     /// (i.e. lastPrice * lastQty)
     #[serde(rename = "Y")]
     #[serde(with = "string_or_float")]
-    pub last_quote_asset_transacted_qty: f64,
+    pub last_quote_asset_transacted_qty: Number,
     #[serde(rename = "Q")]
     #[serde(with = "string_or_float")]
-    pub quote_order_qty: f64,
+    pub quote_order_qty: Number,
+    /// When the order started working on the book
+    #[serde(rename = "W")]
+    pub working_time: Option<u64>,
+    #[serde(rename = "V")]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    /// Set when the order expired because of self trade prevention
+    #[serde(rename = "v")]
+    pub prevented_match_id: Option<u64>,
+}
+
+/// Sent on the user data stream when its listen key is no longer valid
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ListenKeyExpired {
+    #[serde(rename = "E", with = "string_or_u64")]
+    pub event_time: u64,
+    #[serde(default)]
+    pub listen_key: Option<String>,
 }
 
 /// For OCO Events
@@ -0,0 +1,367 @@
+//! Deserialization regression suite over the payloads recorded in `test_data/fixtures`.
+//!
+//! Every fixture is deserialized into its model and serialized back, the result is then compared
+//! to the recorded payload. Fields the model drops are reported as unknown fields and values that
+//! don't survive the round trip as changed, so drift in the API is caught without a network.
+//! Differences that can't be modelled may be listed per fixture with `ignore [...]`, array indices
+//! are written `[]` in their paths.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+struct Fixture {
+    path: &'static str,
+    check: fn(&Value) -> Result<Value, serde_json::Error>,
+    ignored: &'static [&'static str],
+}
+
+fn round_trip<T: DeserializeOwned + Serialize>(payload: &Value) -> Result<Value, serde_json::Error> {
+    let model: T = serde_json::from_value(payload.clone())?;
+    serde_json::to_value(model)
+}
+
+macro_rules! fixtures {
+    ($($path:literal => $model:ty $(, ignore [$($ignored:literal),* $(,)?])?;)*) => {
+        vec![$(Fixture {
+            path: $path,
+            check: round_trip::<$model>,
+            ignored: &[$($($ignored),*)?],
+        }),*]
+    };
+}
+
+fn fixtures_dir() -> PathBuf { Path::new(env!("CARGO_MANIFEST_DIR")).join("test_data/fixtures") }
+
+fn numeric(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+fn push(report: &mut Vec<String>, line: String) {
+    if !report.contains(&line) {
+        report.push(line);
+    }
+}
+
+fn compare(path: &str, recorded: &Value, serialized: &Value, ignored: &[&str], report: &mut Vec<String>) {
+    if ignored.contains(&path) {
+        return;
+    }
+    match (recorded, serialized) {
+        (Value::Object(recorded), Value::Object(serialized)) => {
+            for (key, value) in recorded {
+                let field = if path.is_empty() { key.clone() } else { format!("{path}.{key}") };
+                match serialized.get(key) {
+                    Some(serialized) => compare(&field, value, serialized, ignored, report),
+                    None if ignored.contains(&field.as_str()) => {}
+                    None => push(report, format!("unknown field `{field}`")),
+                }
+            }
+        }
+        (Value::Array(recorded), Value::Array(serialized)) if recorded.len() == serialized.len() => {
+            let path = format!("{path}[]");
+            for (recorded, serialized) in recorded.iter().zip(serialized) {
+                compare(&path, recorded, serialized, ignored, report);
+            }
+        }
+        // price levels are recorded as `[price, qty]` and modelled as structs
+        (Value::Array(recorded), Value::Object(serialized)) if recorded.len() == serialized.len() => {
+            let path = format!("{path}[]");
+            for (recorded, serialized) in recorded.iter().zip(serialized.values()) {
+                compare(&path, recorded, serialized, ignored, report);
+            }
+        }
+        (recorded, serialized) if recorded == serialized => {}
+        // empty strings are modelled as `None`
+        (Value::String(recorded), Value::Null) if recorded.is_empty() => {}
+        // tagged enums serialize the variant name as the event type
+        _ if path == "e" || path == "[].e" => {}
+        (recorded, serialized) => match (numeric(recorded), numeric(serialized)) {
+            (Some(a), Some(b)) if a == b => {}
+            _ => push(report, format!("`{path}` changed from {recorded} to {serialized}")),
+        },
+    }
+}
+
+fn run(fixtures: Vec<Fixture>) {
+    let mut failures = vec![];
+    for fixture in &fixtures {
+        let file = fixtures_dir().join(fixture.path);
+        let recorded: Value = serde_json::from_str(&std::fs::read_to_string(&file).unwrap()).unwrap();
+        match (fixture.check)(&recorded) {
+            Ok(serialized) => {
+                let mut report = vec![];
+                compare("", &recorded, &serialized, fixture.ignored, &mut report);
+                failures.extend(report.into_iter().map(|line| format!("{}: {line}", fixture.path)));
+            }
+            Err(e) => failures.push(format!("{}: {e}", fixture.path)),
+        }
+    }
+    assert!(failures.is_empty(), "fixture regressions:\n{}", failures.join("\n"));
+}
+
+fn recorded_files(dir: &Path, files: &mut BTreeSet<String>) {
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            recorded_files(&path, files);
+        } else if path.extension().is_some_and(|ext| ext == "json") {
+            let relative = path.strip_prefix(fixtures_dir()).unwrap();
+            files.insert(relative.to_string_lossy().replace('\\', "/"));
+        }
+    }
+}
+
+fn spot_fixtures() -> Vec<Fixture> {
+    use binance::rest_model::*;
+    use binance::ws_model::*;
+    fixtures! {
+        "spot/rest/account.json" => AccountInformation;
+        "spot/rest/agg_trade.json" => AggTrade;
+        "spot/rest/avg_price.json" => AveragePrice;
+        "spot/rest/book_ticker.json" => Tickers;
+        "spot/rest/cancel_order.json" => OrderCanceled;
+        "spot/rest/depth.json" => OrderBook;
+        "spot/rest/my_trade.json" => TradeHistory;
+        "spot/rest/new_order_full.json" => Transaction;
+        "spot/rest/order.json" => Order;
+        "spot/rest/order_list.json" => OrderList;
+        "spot/rest/order_list_oto.json" => OrderList;
+        "spot/rest/server_time.json" => ServerTime;
+        "spot/rest/ticker_24hr.json" => PriceStats;
+        "spot/rest/ticker_price.json" => SymbolPrice;
+        "spot/rest/user_data_stream.json" => UserDataStream;
+        "spot/ws/agg_trade.json" => WebsocketEvent;
+        "spot/ws/balance_update.json" => WebsocketEvent;
+        "spot/ws/book_ticker.json" => WebsocketEventUntag;
+        "spot/ws/depth_update.json" => WebsocketEvent;
+        "spot/ws/execution_report.json" => WebsocketEvent;
+        "spot/ws/kline.json" => WebsocketEvent;
+        "spot/ws/list_status.json" => WebsocketEvent;
+        "spot/ws/listen_key_expired.json" => WebsocketEvent;
+        "spot/ws/mini_ticker_24hr.json" => WebsocketEvent;
+        "spot/ws/outbound_account_position.json" => WebsocketEvent;
+        "spot/ws/partial_depth.json" => WebsocketEventUntag;
+        "spot/ws/ticker_24hr.json" => WebsocketEvent;
+        "spot/ws/trade.json" => WebsocketEvent;
+    }
+}
+
+fn margin_fixtures() -> Vec<Fixture> {
+    use binance::rest_model::*;
+    fixtures! {
+        "margin/rest/account.json" => MarginAccountDetails;
+        "margin/rest/cancel_oco_order.json" => MarginOCOOrderResult;
+        "margin/rest/cancel_open_orders.json" => MarginOrdersCancellationResult;
+        "margin/rest/cancel_order.json" => MarginOrderCancellationResult;
+        "margin/rest/loans.json" => RecordsQueryResult<LoanState>;
+        "margin/rest/max_borrowable.json" => MaxBorrowableAmount;
+        "margin/rest/max_transferable.json" => MaxTransferableAmount;
+        "margin/rest/new_order_full.json" => MarginOrderResult;
+        "margin/rest/oco_order.json" => MarginOCOOrderResult;
+        "margin/rest/order.json" => MarginOrderState;
+        "margin/rest/price_index.json" => PriceIndex;
+        "margin/rest/trade.json" => OwnTradesState;
+        "margin/rest/transfer.json" => TransactionId;
+    }
+}
+
+fn wallet_fixtures() -> Vec<Fixture> {
+    use binance::rest_model::*;
+    fixtures! {
+        "wallet/rest/account_status.json" => AccountStatus;
+        "wallet/rest/api_key_permissions.json" => ApiKeyPermissions;
+        "wallet/rest/coin_info.json" => WalletCoinInfo;
+        "wallet/rest/deposit_address.json" => DepositAddress;
+        "wallet/rest/deposit_history.json" => DepositRecord;
+        "wallet/rest/system_status.json" => SystemStatus;
+        "wallet/rest/trade_fee.json" => TradeFee;
+        "wallet/rest/withdraw_history.json" => WithdrawalRecord;
+    }
+}
+
+#[cfg(feature = "savings_api")]
+fn savings_fixtures() -> Vec<Fixture> {
+    use binance::savings::*;
+    use std::collections::BTreeMap;
+    fixtures! {
+        "savings/rest/asset_detail.json" => BTreeMap<String, AssetDetail>;
+        "savings/rest/coin_info.json" => CoinInfo;
+        "savings/rest/deposit_address.json" => DepositAddress;
+    }
+}
+
+#[cfg(feature = "futures_api")]
+fn futures_fixtures() -> Vec<Fixture> {
+    use binance::futures::rest_model::*;
+    use binance::futures::ws_model::*;
+    fixtures! {
+        "futures/rest/account.json" => AccountInformation;
+        "futures/rest/adl_quantile.json" => AdlQuantile;
+        "futures/rest/agg_trade.json" => AggTrade;
+        "futures/rest/balance.json" => AccountBalance;
+        "futures/rest/cancel_order.json" => CanceledOrder;
+        "futures/rest/commission_rate.json" => CommissionRate;
+        "futures/rest/countdown_cancel_all.json" => CountdownCancelAll;
+        "futures/rest/depth.json" => DepthOrderBook;
+        "futures/rest/force_order.json" => LiquidationOrder;
+        "futures/rest/funding_rate.json" => FundingRate;
+        "futures/rest/income.json" => Income;
+        "futures/rest/leverage.json" => ChangeLeverageResponse;
+        "futures/rest/leverage_bracket.json" => SymbolBrackets;
+        "futures/rest/listen_key.json" => FuturesUserDataStream;
+        "futures/rest/long_short_ratio.json" => LongShortRatio;
+        "futures/rest/multi_assets_margin.json" => MultiAssetsMode;
+        "futures/rest/new_order.json" => Transaction;
+        "futures/rest/open_interest.json" => OpenInterest;
+        "futures/rest/open_interest_hist.json" => OpenInterestHistory;
+        "futures/rest/order.json" => Order;
+        "futures/rest/position_margin.json" => PositionMarginResponse;
+        "futures/rest/position_margin_history.json" => PositionMarginChange;
+        "futures/rest/position_risk.json" => Position;
+        "futures/rest/premium_index.json" => MarkPrice;
+        "futures/rest/ticker_24hr.json" => PriceStats;
+        "futures/rest/trade.json" => Trade;
+        "futures/rest/user_trade.json" => UserTrade;
+        "futures/ws/account_update.json" => FuturesWebsocketEvent;
+        "futures/ws/agg_trade.json" => FuturesWebsocketEvent;
+        "futures/ws/book_ticker.json" => FuturesWebsocketEventUntag;
+        "futures/ws/depth_update.json" => FuturesWebsocketEvent;
+        "futures/ws/kline.json" => FuturesWebsocketEvent;
+        "futures/ws/listen_key_expired.json" => FuturesWebsocketEvent;
+        "futures/ws/order_trade_update.json" => FuturesWebsocketEvent;
+    }
+}
+
+#[cfg(feature = "futures_api")]
+fn coin_futures_fixtures() -> Vec<Fixture> {
+    use binance::coin_futures::rest_model::*;
+    use binance::futures::ws_model::*;
+    fixtures! {
+        "coin_futures/rest/account.json" => AccountInformation;
+        "coin_futures/rest/balance.json" => AccountBalance;
+        "coin_futures/rest/exchange_info.json" => ExchangeInformation;
+        "coin_futures/rest/leverage.json" => ChangeLeverageResponse;
+        "coin_futures/rest/new_order.json" => Order;
+        "coin_futures/rest/open_interest.json" => OpenInterest;
+        "coin_futures/rest/order.json" => Order;
+        "coin_futures/rest/position_risk.json" => Position;
+        "coin_futures/rest/premium_index.json" => MarkPrice;
+        "coin_futures/rest/premium_index_delivery.json" => MarkPrice;
+        "coin_futures/rest/ticker_24hr.json" => PriceStats;
+        "coin_futures/rest/trade.json" => Trade;
+        "coin_futures/rest/user_trade.json" => UserTrade;
+        "coin_futures/ws/account_update.json" => FuturesWebsocketEvent;
+        "coin_futures/ws/order_trade_update.json" => FuturesWebsocketEvent;
+    }
+}
+
+#[cfg(feature = "options")]
+fn options_fixtures() -> Vec<Fixture> {
+    use binance::options::rest_model::*;
+    use binance::options::ws_model::*;
+    fixtures! {
+        "options/rest/account.json" => AccountInformation;
+        "options/rest/depth.json" => OrderBook;
+        "options/rest/exchange_info.json" => ExchangeInformation;
+        "options/rest/index.json" => IndexPrice;
+        "options/rest/kline.json" => binance::options::rest_model::Kline;
+        "options/rest/mark.json" => MarkPrice;
+        "options/rest/open_interest.json" => OpenInterest;
+        "options/rest/order.json" => Order;
+        "options/rest/position.json" => Position;
+        "options/rest/ticker.json" => Ticker;
+        "options/rest/trade.json" => Trade;
+        "options/rest/user_trade.json" => UserTrade;
+        "options/ws/depth.json" => OptionsWebsocketEvent;
+        "options/ws/index.json" => OptionsWebsocketEvent;
+        "options/ws/kline.json" => OptionsWebsocketEvent;
+        "options/ws/mark_price.json" => OptionsWebsocketEventUntag;
+        "options/ws/open_interest.json" => OptionsWebsocketEventUntag;
+        "options/ws/ticker.json" => OptionsWebsocketEvent;
+        "options/ws/trade.json" => OptionsWebsocketEvent;
+    }
+}
+
+#[cfg(feature = "portfolio_margin")]
+fn portfolio_margin_fixtures() -> Vec<Fixture> {
+    use binance::portfolio_margin::rest_model::*;
+    use binance::portfolio_margin::ws_model::*;
+    fixtures! {
+        "portfolio_margin/rest/account.json" => AccountInformation;
+        "portfolio_margin/rest/balance.json" => Balance;
+        "portfolio_margin/rest/cm_order.json" => CmOrder;
+        "portfolio_margin/rest/margin_loan.json" => TransactionId;
+        "portfolio_margin/rest/um_order.json" => UmOrder;
+        "portfolio_margin/ws/account_update.json" => PortfolioMarginWebsocketEvent;
+        "portfolio_margin/ws/liability_change.json" => PortfolioMarginWebsocketEvent;
+        "portfolio_margin/ws/order_trade_update.json" => PortfolioMarginWebsocketEvent;
+        "portfolio_margin/ws/risk_level_change.json" => PortfolioMarginWebsocketEvent;
+    }
+}
+
+#[test]
+fn spot_models() { run(spot_fixtures()) }
+
+#[test]
+fn margin_models() { run(margin_fixtures()) }
+
+#[test]
+fn wallet_models() { run(wallet_fixtures()) }
+
+#[cfg(feature = "savings_api")]
+#[test]
+fn savings_models() { run(savings_fixtures()) }
+
+#[cfg(feature = "futures_api")]
+#[test]
+fn futures_models() { run(futures_fixtures()) }
+
+#[cfg(feature = "futures_api")]
+#[test]
+fn coin_futures_models() { run(coin_futures_fixtures()) }
+
+#[cfg(feature = "options")]
+#[test]
+fn options_models() { run(options_fixtures()) }
+
+#[cfg(feature = "portfolio_margin")]
+#[test]
+fn portfolio_margin_models() { run(portfolio_margin_fixtures()) }
+
+/// Fixtures of the APIs behind a disabled feature can't be checked
+fn enabled(path: &str) -> bool {
+    match path.split('/').next() {
+        Some("futures" | "coin_futures") => cfg!(feature = "futures_api"),
+        Some("options") => cfg!(feature = "options"),
+        Some("portfolio_margin") => cfg!(feature = "portfolio_margin"),
+        Some("savings") => cfg!(feature = "savings_api"),
+        _ => true,
+    }
+}
+
+#[test]
+fn every_fixture_is_checked() {
+    let mut files = BTreeSet::new();
+    recorded_files(&fixtures_dir(), &mut files);
+    let mut fixtures = spot_fixtures();
+    fixtures.extend(margin_fixtures().into_iter().chain(wallet_fixtures()));
+    #[cfg(feature = "futures_api")]
+    fixtures.extend(futures_fixtures().into_iter().chain(coin_futures_fixtures()));
+    #[cfg(feature = "options")]
+    fixtures.extend(options_fixtures());
+    #[cfg(feature = "portfolio_margin")]
+    fixtures.extend(portfolio_margin_fixtures());
+    #[cfg(feature = "savings_api")]
+    fixtures.extend(savings_fixtures());
+    let checked: BTreeSet<String> = fixtures.into_iter().map(|fixture| fixture.path.to_string()).collect();
+    let unchecked: Vec<_> = files.iter().filter(|file| enabled(file) && !checked.contains(*file)).collect();
+    assert!(unchecked.is_empty(), "fixtures without a model: {unchecked:?}");
+}
@@ -0,0 +1,944 @@
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use binance::account::*;
+use binance::api::*;
+use binance::config::{Config, ReconnectPolicy, RetryPolicy};
+use binance::errors::Error;
+use binance::exchange::*;
+use binance::futures::websockets::FuturesWebSockets;
+use binance::general::General;
+use binance::margin::Margin;
+use binance::market::*;
+use binance::rate_limit::*;
+use binance::rest_model::*;
+use binance::signer::*;
+use binance::testkit::*;
+use binance::transport::*;
+use binance::userstream::*;
+use binance::websockets::*;
+use binance::ws_api::WsApi;
+use binance::ws_model::*;
+use futures::future::BoxFuture;
+use futures::StreamExt;
+use serde_json::{json, Value};
+use tokio::sync::mpsc;
+
+#[tokio::test]
+async fn signed_requests() {
+    let server = MockServer::start("key", "secret").await;
+    server.mock(
+        "GET",
+        "/api/v3/account",
+        MockResponse::json(&json!({
+            "makerCommission": 15, "takerCommission": 15, "buyerCommission": 0, "sellerCommission": 0,
+            "canTrade": true, "canWithdraw": true, "canDeposit": true, "accountType": "SPOT",
+            "balances": [{"asset": "BTC", "free": "1.5", "locked": "0.0"}], "permissions": ["SPOT"], "updateTime": 0
+        })),
+    );
+    let account: Account = Binance::new_with_config(Some("key".into()), Some("secret".into()), &server.config());
+    let info = account.get_account().await.unwrap();
+    assert_eq!(info.balances[0].asset, "BTC");
+    let request = server.requests().pop().unwrap();
+    assert_eq!(request.signature_valid, Some(true));
+    assert!(request.params.contains_key("timestamp"));
+
+    let forged: Account = Binance::new_with_config(Some("key".into()), Some("wrong".into()), &server.config());
+    match forged.get_account().await {
+        Err(Error::BinanceError { response }) => assert_eq!(response.code, -1022),
+        other => panic!("{other:?}"),
+    }
+
+    server.mock("POST", "/sapi/v1/margin/transfer", MockResponse::json(&json!({"tranId": 42})));
+    let margin: Margin = Binance::new_with_config(Some("key".into()), Some("secret".into()), &server.config());
+    let transfer = margin
+        .transfer("BTC", num("0.001"), MarginTransferType::FromMainToMargin)
+        .await
+        .unwrap();
+    assert_eq!(transfer.tran_id, 42);
+}
+
+#[tokio::test]
+async fn scripted_errors() {
+    let server = MockServer::start("key", "secret").await;
+    server.mock_once(
+        "GET",
+        "/api/v3/ticker/price",
+        MockResponse::error(400, -1121, "Invalid symbol."),
+    );
+    server.mock_with("GET", "/api/v3/ticker/price", |request| {
+        MockResponse::json(&json!({"symbol": request.params["symbol"], "price": "1.25"}))
+    });
+    let market: Market = Binance::new_with_config(None, None, &server.config());
+    match market.get_price("BTCUSDT").await {
+        Err(Error::BinanceError { response }) => assert_eq!(response.code, -1121),
+        other => panic!("{other:?}"),
+    }
+    assert_eq!(market.get_price("ETHUSDT").await.unwrap().symbol, "ETHUSDT");
+
+    server.mock(
+        "GET",
+        "/api/v3/depth",
+        MockResponse::error(429, -1003, "Too many requests.").with_header("Retry-After", "3"),
+    );
+    assert!(matches!(
+        market.get_depth("BTCUSDT").await,
+        Err(Error::RateLimited {
+            retry_after: Some(d)
+        }) if d == Duration::from_secs(3)
+    ));
+}
+
+#[tokio::test]
+async fn market_klines() {
+    let server = MockServer::start("key", "secret").await;
+    let row = json!([1, "1.5", "2.5", "1.0", "2.0", "100.0", 2, "150.0", 10, "60.0", "90.0", "0"]);
+    server.mock("GET", "/api/v3/klines", MockResponse::json(&json!([row])));
+    let market: Market = Binance::new_with_config(None, None, &server.config());
+    let KlineSummaries::AllKlineSummaries(klines) = market.get_klines("BTCUSDT", "1m", 1, None, None).await.unwrap();
+    assert_eq!((klines[0].open, klines[0].close, klines[0].volume), (num("1.5"), num("2.0"), num("100.0")));
+
+    // numbers are sent as strings
+    server.mock("GET", "/api/v3/klines", MockResponse::json(&json!([[1, 1.5, "2.5", "1.0", "2.0", "100.0", 2]])));
+    assert!(matches!(
+        market.get_klines("BTCUSDT", "1m", 1, None, None).await,
+        Err(Error::Msg(_))
+    ));
+}
+
+#[tokio::test]
+async fn websocket_streams() {
+    let server = MockServer::start("key", "secret").await;
+    let (_, mut events) = connect_stream::<WebsocketEvent>(&server.config(), &agg_trade_stream("btcusdt"))
+        .await
+        .unwrap();
+    while server.ws_paths().is_empty() {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(server.ws_paths(), vec!["/ws/btcusdt@aggTrade".to_string()]);
+    server.push_event(&json!({
+        "e": "aggTrade", "E": 1, "s": "BTCUSDT", "a": 1, "p": "30000.0", "q": "0.1",
+        "f": 1, "l": 1, "T": 1, "m": true, "M": true
+    }));
+    match events.next().await {
+        Some(Ok(WebsocketEvent::AggTrade(trade))) => assert_eq!(trade.symbol, "BTCUSDT"),
+        other => panic!("{other:?}"),
+    }
+    server.disconnect_websockets();
+    assert!(events.next().await.is_none());
+}
+
+fn reconnect_config(server: &MockServer) -> binance::config::Config {
+    server.config().set_ws_reconnect(ReconnectPolicy {
+        initial_delay_ms: 10,
+        jitter: false,
+        ..ReconnectPolicy::default()
+    })
+}
+
+/// Drop the connection and expect it to be redialed and the stream to resume
+async fn assert_reconnects(
+    server: &MockServer,
+    connections: &mut mpsc::UnboundedReceiver<ConnectionEvent>,
+    events: &mut mpsc::UnboundedReceiver<Value>,
+) {
+    assert!(matches!(connections.recv().await, Some(ConnectionEvent::Connected { .. })));
+    server.disconnect_websockets();
+    assert!(matches!(connections.recv().await, Some(ConnectionEvent::Disconnected { .. })));
+    assert_eq!(
+        connections.recv().await,
+        Some(ConnectionEvent::Reconnecting {
+            attempt: 1,
+            delay: Duration::from_millis(10)
+        })
+    );
+    match connections.recv().await {
+        Some(ConnectionEvent::Connected { url }) => assert!(url.ends_with("/ws/btcusdt@aggTrade")),
+        other => panic!("{other:?}"),
+    }
+    while server.ws_paths().len() < 2 {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    server.push_event(&json!({"e": "aggTrade", "s": "BTCUSDT"}));
+    assert_eq!(events.recv().await.unwrap()["s"], "BTCUSDT");
+}
+
+#[tokio::test]
+async fn websocket_reconnect() {
+    let server = MockServer::start("key", "secret").await;
+    let (event_tx, mut events) = mpsc::unbounded_channel();
+    let (connection_tx, mut connections) = mpsc::unbounded_channel();
+    let mut web_socket: WebSockets<'static, Value> = WebSockets::new_with_options(
+        move |event| {
+            let _ = event_tx.send(event);
+            Ok(())
+        },
+        reconnect_config(&server),
+    );
+    web_socket.set_connection_handler(move |event| {
+        let _ = connection_tx.send(event);
+        Ok(())
+    });
+    web_socket.connect(&agg_trade_stream("btcusdt")).await.unwrap();
+    tokio::spawn(async move { web_socket.event_loop(&AtomicBool::new(true)).await });
+    assert_reconnects(&server, &mut connections, &mut events).await;
+}
+
+#[tokio::test]
+async fn futures_websocket_reconnect() {
+    let server = MockServer::start("key", "secret").await;
+    let (event_tx, mut events) = mpsc::unbounded_channel();
+    let (connection_tx, mut connections) = mpsc::unbounded_channel();
+    let mut web_socket: FuturesWebSockets<'static, Value> = FuturesWebSockets::new_with_options(
+        move |event| {
+            let _ = event_tx.send(event);
+            Ok(())
+        },
+        reconnect_config(&server),
+    );
+    web_socket.set_connection_handler(move |event| {
+        let _ = connection_tx.send(event);
+        Ok(())
+    });
+    web_socket.connect(&agg_trade_stream("btcusdt")).await.unwrap();
+    tokio::spawn(async move { web_socket.event_loop(&AtomicBool::new(true)).await });
+    assert_reconnects(&server, &mut connections, &mut events).await;
+}
+
+#[tokio::test]
+async fn live_subscriptions() {
+    let server = MockServer::start("key", "secret").await;
+    let (connection_tx, mut connections) = mpsc::unbounded_channel();
+    let mut web_socket: WebSockets<'static, Value> =
+        WebSockets::new_with_options(|_| Ok(()), reconnect_config(&server));
+    web_socket.set_connection_handler(move |event| {
+        let _ = connection_tx.send(event);
+        Ok(())
+    });
+    web_socket
+        .connect_multiple(vec![agg_trade_stream("btcusdt")])
+        .await
+        .unwrap();
+    let subscriptions = web_socket.subscriptions();
+    tokio::spawn(async move { web_socket.event_loop(&AtomicBool::new(true)).await });
+
+    let (eth, bnb) = (agg_trade_stream("ethusdt"), agg_trade_stream("bnbusdt"));
+    subscriptions.subscribe(vec![eth.clone(), bnb.clone()]).await.unwrap();
+    subscriptions.unsubscribe(vec![bnb]).await.unwrap();
+    assert_eq!(subscriptions.list_subscriptions().await.unwrap(), vec![eth.clone()]);
+    assert_eq!(server.subscriptions(), vec![eth.clone()]);
+
+    server.disconnect_websockets();
+    assert!(server.subscriptions().is_empty());
+    while !matches!(connections.recv().await, Some(ConnectionEvent::Reconnecting { .. })) {}
+    assert!(matches!(connections.recv().await, Some(ConnectionEvent::Connected { .. })));
+    while server.subscriptions().is_empty() {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(server.subscriptions(), vec![eth.clone()]);
+    assert_eq!(subscriptions.list_subscriptions().await.unwrap(), vec![eth]);
+}
+
+#[tokio::test]
+async fn user_data_session() {
+    let server = MockServer::start("key", "secret").await;
+    server.mock_once("POST", "/api/v3/userDataStream", MockResponse::error(503, -1001, "Internal error."));
+    let conf = reconnect_config(&server);
+    let user_stream: UserStream = Binance::new_with_config(Some("key".into()), None, &conf);
+    let (event_tx, mut events) = mpsc::unbounded_channel();
+    let (connection_tx, mut connections) = mpsc::unbounded_channel();
+    let mut session = UserDataSession::new(
+        ListenKeySource::Spot(user_stream),
+        move |event: WebsocketEvent| {
+            let _ = event_tx.send(event);
+            Ok(())
+        },
+        conf,
+    );
+    session.set_connection_handler(move |event| {
+        let _ = connection_tx.send(event);
+        Ok(())
+    });
+    tokio::spawn(async move { session.event_loop(&AtomicBool::new(true)).await });
+
+    // the listen key is created again after a failure
+    assert!(matches!(connections.recv().await, Some(ConnectionEvent::Reconnecting { attempt: 1, .. })));
+    assert!(matches!(connections.recv().await, Some(ConnectionEvent::Connected { .. })));
+    assert_eq!(server.ws_paths(), vec!["/ws/mock-listen-key".to_string()]);
+
+    // and when it expires
+    server.push_event(&json!({"e": "listenKeyExpired", "E": 1, "listenKey": "mock-listen-key"}));
+    assert!(matches!(events.recv().await, Some(WebsocketEvent::ListenKeyExpired(_))));
+    assert!(matches!(connections.recv().await, Some(ConnectionEvent::Disconnected { .. })));
+    assert!(matches!(connections.recv().await, Some(ConnectionEvent::Connected { .. })));
+    let created = server
+        .requests()
+        .iter()
+        .filter(|r| r.method == "POST" && r.path == "/api/v3/userDataStream")
+        .count();
+    assert_eq!(created, 3);
+}
+
+#[tokio::test]
+async fn user_data_session_exit() {
+    let server = MockServer::start("key", "secret").await;
+    let conf = reconnect_config(&server);
+    let closed = |server: &MockServer| {
+        let requests = server.requests();
+        requests.iter().filter(|r| r.method == "DELETE").count()
+    };
+    let new_session = |handler: fn(WebsocketEvent) -> binance::errors::Result<()>| {
+        let user_stream: UserStream = Binance::new_with_config(Some("key".into()), None, &conf);
+        let (connection_tx, connections) = mpsc::unbounded_channel();
+        let mut session = UserDataSession::new(ListenKeySource::Spot(user_stream), handler, conf.clone());
+        session.set_connection_handler(move |event| {
+            let _ = connection_tx.send(event);
+            Ok(())
+        });
+        (session, connections)
+    };
+
+    // stopping does not wait for the next event or keep alive
+    let (mut session, mut connections) = new_session(|_| Ok(()));
+    let running = Arc::new(AtomicBool::new(true));
+    let stop = running.clone();
+    let task = tokio::spawn(async move { session.event_loop(&stop).await });
+    assert!(matches!(connections.recv().await, Some(ConnectionEvent::Connected { .. })));
+    running.store(false, Ordering::Relaxed);
+    tokio::time::timeout(Duration::from_secs(1), task).await.unwrap().unwrap().unwrap();
+    assert_eq!(closed(&server), 1);
+
+    // the listen key is closed when the handler fails
+    let (mut session, mut connections) = new_session(|_| Err(Error::Msg("handler".to_string())));
+    let task = tokio::spawn(async move { session.event_loop(&AtomicBool::new(true)).await });
+    assert!(matches!(connections.recv().await, Some(ConnectionEvent::Connected { .. })));
+    server.push_event(&json!({"e": "listenKeyExpired", "E": 1, "listenKey": "mock-listen-key"}));
+    assert!(matches!(task.await.unwrap(), Err(Error::Msg(msg)) if msg == "handler"));
+    assert_eq!(closed(&server), 2);
+
+    // and on events that cannot be read
+    let (mut session, mut connections) = new_session(|_| Ok(()));
+    let task = tokio::spawn(async move { session.event_loop(&AtomicBool::new(true)).await });
+    assert!(matches!(connections.recv().await, Some(ConnectionEvent::Connected { .. })));
+    server.push_raw("not json");
+    assert!(matches!(task.await.unwrap(), Err(Error::Json(_))));
+    assert_eq!(closed(&server), 3);
+}
+
+#[tokio::test]
+async fn ws_api_requests() {
+    let server = MockServer::start("key", "secret").await;
+    server.mock_with("WS", "order.place", |request| {
+        MockResponse::json(&json!({
+            "symbol": request.params["symbol"], "orderId": 12, "orderListId": -1, "clientOrderId": "ws",
+            "transactTime": 1, "price": "0.00000000", "origQty": request.params["quantity"], "executedQty": "0.1",
+            "cummulativeQuoteQty": "3000.0", "status": "FILLED", "timeInForce": "GTC", "type": "MARKET", "side": "BUY",
+            "fills": []
+        }))
+    });
+    let ws_api = WsApi::connect(Some("key".into()), Some("secret".into()), &server.config())
+        .await
+        .unwrap();
+    ws_api.ping().await.unwrap();
+    assert!(ws_api.connection.sync_time().await.unwrap().abs() < 1000);
+    let order = OrderRequest {
+        symbol: "BTCUSDT".to_string(),
+        side: OrderSide::Buy,
+        order_type: OrderType::Market,
+        quantity: Some(num("0.1")),
+        ..OrderRequest::default()
+    };
+    let transaction = ws_api.place_order(order.clone()).await.unwrap();
+    assert_eq!((transaction.order_id, transaction.executed_qty), (12, num("0.1")));
+    let request = server.requests().pop().unwrap();
+    assert_eq!((request.method.as_str(), request.path.as_str()), ("WS", "order.place"));
+    assert_eq!(request.api_key.as_deref(), Some("key"));
+    assert_eq!(request.signature_valid, Some(true));
+    assert!(request.params.contains_key("timestamp"));
+
+    server.mock_once("WS", "order.place", MockResponse::error(400, -2010, "Account has insufficient balance."));
+    match ws_api.place_order(order.clone()).await {
+        Err(Error::BinanceError { response }) => assert_eq!(response.code, -2010),
+        other => panic!("{other:?}"),
+    }
+
+    let forged = WsApi::connect(Some("key".into()), Some("wrong".into()), &server.config())
+        .await
+        .unwrap();
+    match forged.place_order(order).await {
+        Err(Error::BinanceError { response }) => assert_eq!(response.code, -1022),
+        other => panic!("{other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn ws_api_session_logon() {
+    use binance::futures::ws_api::FuturesWsApi;
+
+    let pem = include_str!("../test_data/keys/ed25519.pem");
+    let server = MockServer::start("key", "unused").await;
+    server.set_ed25519_public_key(Ed25519Signer::from_pem(pem).unwrap().public_key());
+    server.mock("WS", "openOrders.status", MockResponse::json(&json!([])));
+    server.mock("WS", "v2/account.position", MockResponse::json(&json!([])));
+    let config = server.config().set_signature_method(SignatureMethod::Ed25519);
+    let unsigned = |request: &MockRequest| {
+        request.api_key.is_none() && request.signature_valid.is_none() && request.params.contains_key("timestamp")
+    };
+
+    let ws_api = WsApi::connect(Some("key".into()), Some(pem.into()), &config).await.unwrap();
+    ws_api.get_open_orders("BTCUSDT").await.unwrap();
+    assert_eq!(server.requests().pop().unwrap().signature_valid, Some(true));
+    let status = ws_api.connection.logon().await.unwrap();
+    assert_eq!(status.api_key.as_deref(), Some("key"));
+    let logon = server.requests().pop().unwrap();
+    assert_eq!((logon.api_key.as_deref(), logon.signature_valid), (Some("key"), Some(true)));
+    ws_api.get_open_orders("BTCUSDT").await.unwrap();
+    assert!(unsigned(&server.requests().pop().unwrap()));
+    assert!(ws_api.connection.session_status().await.unwrap().authorized_since.is_some());
+    ws_api.connection.logout().await.unwrap();
+    ws_api.get_open_orders("BTCUSDT").await.unwrap();
+    assert_eq!(server.requests().pop().unwrap().signature_valid, Some(true));
+
+    let futures = FuturesWsApi::connect(Some("key".into()), Some(pem.into()), &config)
+        .await
+        .unwrap();
+    futures.connection.logon().await.unwrap();
+    futures.position_information("BTCUSDT").await.unwrap();
+    let request = server.requests().pop().unwrap();
+    assert_eq!(request.path, "v2/account.position");
+    assert!(unsigned(&request));
+    // the session belongs to the connection
+    assert!(ws_api.connection.session_status().await.unwrap().api_key.is_none());
+}
+
+async fn buy_and_cancel(exchange: &impl Exchange) -> (ExchangeOrder, ExchangeOrder) {
+    let placed = exchange
+        .place_order(NewOrder::limit("BTCUSDT", OrderSide::Buy, num("0.1"), num("20000")))
+        .await
+        .unwrap();
+    exchange.cancel_order("BTCUSDT", placed.order_id).await.unwrap();
+    (placed, exchange.order_status("BTCUSDT", 12).await.unwrap())
+}
+
+#[tokio::test]
+async fn exchange_trait() {
+    let server = MockServer::start("key", "secret").await;
+    let order = json!({
+        "symbol": "BTCUSDT", "orderId": 12, "orderListId": -1, "clientOrderId": "x", "transactTime": 1,
+        "price": "20000.0", "origQty": "0.1", "executedQty": "0.05", "cummulativeQuoteQty": "1000.0",
+        "status": "PARTIALLY_FILLED", "timeInForce": "GTC", "type": "LIMIT", "side": "BUY",
+        "fills": [{"price": "20000.0", "qty": "0.05", "commission": "0.00005", "commissionAsset": "BTC"}],
+        "stopPrice": "0.0", "icebergQty": "0.0", "time": 1, "updateTime": 2, "isWorking": true,
+        "origQuoteOrderQty": "0.0"
+    });
+    server.mock("POST", "/api/v3/order", MockResponse::json(&order));
+    server.mock("GET", "/api/v3/order", MockResponse::json(&order));
+    server.mock(
+        "DELETE",
+        "/api/v3/order",
+        MockResponse::json(&json!({
+            "symbol": "BTCUSDT", "origClientOrderId": "x", "orderId": 12, "clientOrderId": "y"
+        })),
+    );
+    let account: Account = Binance::new_with_config(Some("key".into()), Some("secret".into()), &server.config());
+    let (placed, queried) = buy_and_cancel(&account).await;
+    assert_eq!((placed.kind, placed.fills.len()), (Some(OrderKind::Limit), 1));
+    assert_eq!((queried.update_time, queried.stop_price), (2, None));
+    let requests = server.requests();
+    assert_eq!(requests[0].params["type"], "LIMIT");
+    assert_eq!(requests[0].params["timeInForce"], "GTC");
+    assert_eq!(requests[0].params["newOrderRespType"], "FULL");
+    assert_eq!(requests[1].params["orderId"], "12");
+}
+
+#[tokio::test]
+async fn shared_client() {
+    let server = MockServer::start("key", "secret").await;
+    server.mock("POST", "/sapi/v1/margin/transfer", MockResponse::json(&json!({"tranId": 42})));
+    let binance = BinanceClient::new(Some("key".into()), Some("secret".into()), &server.config()).unwrap();
+    binance.sync_time().await.unwrap();
+    let transfer = binance
+        .margin()
+        .transfer("BTC", num("0.001"), MarginTransferType::FromMainToMargin)
+        .await
+        .unwrap();
+    assert_eq!(transfer.tran_id, 42);
+    let request = server.requests().pop().unwrap();
+    assert_eq!(request.api_key.as_deref(), Some("key"));
+    assert_eq!(request.signature_valid, Some(true));
+}
+
+#[tokio::test]
+async fn client_time_sync() {
+    let (server, futures) = (MockServer::start("key", "secret").await, MockServer::start("key", "secret").await);
+    server.mock("GET", "/api/v3/openOrders", MockResponse::json(&json!([])));
+    server.mock("GET", "/sapi/v1/margin/openOrders", MockResponse::json(&json!([])));
+    futures.mock("GET", "/fapi/v1/openOrders", MockResponse::json(&json!([])));
+    // the server clock is 3s behind
+    server.mock_with("GET", "/api/v3/time", |_| {
+        MockResponse::json(&json!({ "serverTime": chrono::Utc::now().timestamp_millis() - 3000 }))
+    });
+    let config = server.config().set_futures_rest_api_endpoint(futures.rest_endpoint());
+    let general: General = Binance::new_with_config(None, None, &config);
+    let offset = general.sync_time().await.unwrap();
+    assert!((-3100..=-2900).contains(&offset), "{offset}");
+    let lag = |server: &MockServer| {
+        let request = server.requests().pop().unwrap();
+        assert_eq!(request.signature_valid, Some(true));
+        chrono::Utc::now().timestamp_millis() - request.params["timestamp"].parse::<i64>().unwrap()
+    };
+    // handles built from the configuration or its clones share the offset of their host
+    let account: Account = Binance::new_with_config(Some("key".into()), Some("secret".into()), &config.clone());
+    account.get_open_orders("BTCUSDT").await.unwrap();
+    assert!(lag(&server) >= 2900);
+    let binance = BinanceClient::new(Some("key".into()), Some("secret".into()), &config).unwrap();
+    binance.spot().get_open_orders("BTCUSDT").await.unwrap();
+    assert!(lag(&server) >= 2900);
+    binance.margin().open_orders("BTCUSDT", None).await.unwrap();
+    assert!(lag(&server) >= 2900);
+    // other hosts keep their own offset
+    binance.futures().get_open_orders("BTCUSDT").await.unwrap();
+    assert!(lag(&futures) < 1000);
+
+    // as do other configurations
+    let other = BinanceClient::new(Some("key".into()), Some("secret".into()), &server.config()).unwrap();
+    other.spot().get_open_orders("BTCUSDT").await.unwrap();
+    assert!(lag(&server) < 1000);
+}
+
+#[tokio::test]
+async fn invalid_http_client() {
+    let config = Config::default().set_proxy("not a proxy");
+    assert!(matches!(
+        BinanceClient::new(None, None, &config).err(),
+        Some(Error::InvalidConfig(_))
+    ));
+    // handles are still created, their requests fail
+    let market: Market = Binance::new_with_config(None, None, &config);
+    assert!(matches!(market.get_price("BTCUSDT").await, Err(Error::InvalidConfig(_))));
+
+    let certificate = "-----BEGIN CERTIFICATE-----\nnot base64\n-----END CERTIFICATE-----\n";
+    let config = Config::default().add_root_certificate(certificate);
+    let market: Market = Binance::new_with_config(None, None, &config);
+    assert!(matches!(market.get_price("BTCUSDT").await, Err(Error::InvalidConfig(_))));
+}
+
+#[tokio::test]
+async fn throttled_signed_request() {
+    let server = MockServer::start("key", "secret").await;
+    server.mock("GET", "/api/v3/openOrders", MockResponse::json(&json!([])));
+    let limiter = RateLimiter::new(RateLimitMode::Throttle);
+    limiter.set_limits(&server.rest_endpoint(), &[RateLimit {
+        interval: RateLimitInterval::Second,
+        rate_limit_type: RateLimitType::RequestWeight,
+        interval_num: 1,
+        limit: 6,
+    }]);
+    let config = server.config().set_rate_limiter(limiter).set_recv_window(500);
+    let account: Account = Binance::new_with_config(Some("key".into()), Some("secret".into()), &config);
+    // start at the beginning of a window so the second request waits longer than the receive window
+    while chrono::Utc::now().timestamp_subsec_millis() > 100 {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    account.get_open_orders("BTCUSDT").await.unwrap();
+    account.get_open_orders("BTCUSDT").await.unwrap();
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert!(requests.iter().all(|r| r.signature_valid == Some(true)));
+}
+
+#[tokio::test]
+async fn retried_signed_request() {
+    let server = MockServer::start("key", "secret").await;
+    server.mock("GET", "/api/v3/openOrders", MockResponse::json(&json!([])));
+    let rate_limited = MockResponse::error(429, -1003, "Too many requests.").with_header("Retry-After", "1");
+    server.mock_once("GET", "/api/v3/openOrders", rate_limited);
+    let retry_policy = RetryPolicy {
+        initial_delay_ms: 1,
+        ..RetryPolicy::default()
+    };
+    let config = server.config().set_retry_policy(retry_policy).set_recv_window(500);
+    let account: Account = Binance::new_with_config(Some("key".into()), Some("secret".into()), &config);
+    account.get_open_orders("BTCUSDT").await.unwrap();
+    // the retry waited for Retry-After and was stamped again to stay within the receive window
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert!(requests.iter().all(|r| r.signature_valid == Some(true)));
+    let timestamp = |request: &MockRequest| request.params["timestamp"].parse::<u64>().unwrap();
+    assert!(timestamp(&requests[1]) >= timestamp(&requests[0]) + 1000);
+}
+
+#[tokio::test]
+async fn spot_order_lists() {
+    let server = MockServer::start("key", "secret").await;
+    let order_list = include_str!("../test_data/fixtures/spot/rest/order_list.json");
+    let order_list: Value = serde_json::from_str(order_list).unwrap();
+    let oto: Value = serde_json::from_str(include_str!("../test_data/fixtures/spot/rest/order_list_oto.json")).unwrap();
+    server.mock("POST", "/api/v3/orderList/oco", MockResponse::json(&order_list));
+    server.mock("POST", "/api/v3/orderList/oto", MockResponse::json(&oto));
+    server.mock("POST", "/api/v3/orderList/otoco", MockResponse::json(&order_list));
+    let account: Account = Binance::new_with_config(Some("key".into()), Some("secret".into()), &server.config());
+    let assert_params = |path: &str, expected: &[(&str, &str)], absent: &[&str]| {
+        let request = server.requests().pop().unwrap();
+        assert_eq!(request.path, path);
+        assert_eq!(request.signature_valid, Some(true));
+        for (key, value) in expected {
+            assert_eq!(request.params.get(*key).map(String::as_str), Some(*value), "{key}");
+        }
+        for key in absent {
+            assert!(!request.params.contains_key(*key), "{key}");
+        }
+    };
+
+    let oco = account
+        .place_oco_order(OCOOrderRequest {
+            symbol: "LTCBTC".to_string(),
+            side: OrderSide::Sell,
+            quantity: num("1.5"),
+            above_type: OrderType::LimitMaker,
+            above_price: Some(num("0.0045")),
+            below_type: OrderType::StopLossLimit,
+            below_price: Some(num("0.0035")),
+            below_stop_price: Some(num("0.0036")),
+            below_time_in_force: Some(TimeInForce::GTC),
+            ..OCOOrderRequest::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(oco.order_list_id, 27);
+    assert_eq!(oco.orders.len(), 2);
+    assert_params(
+        "/api/v3/orderList/oco",
+        &[
+            ("symbol", "LTCBTC"),
+            ("side", "SELL"),
+            ("quantity", "1.5"),
+            ("aboveType", "LIMIT_MAKER"),
+            ("abovePrice", "0.0045"),
+            ("belowType", "STOP_LOSS_LIMIT"),
+            ("belowPrice", "0.0035"),
+            ("belowStopPrice", "0.0036"),
+            ("belowTimeInForce", "GTC"),
+        ],
+        &["aboveStopPrice", "aboveTimeInForce", "listClientOrderId"],
+    );
+
+    let oto = account
+        .place_oto_order(OTOOrderRequest {
+            symbol: "BTCUSDT".to_string(),
+            working_type: OrderType::Limit,
+            working_side: OrderSide::Buy,
+            working_price: num("60000"),
+            working_quantity: num("0.01"),
+            working_time_in_force: Some(TimeInForce::GTC),
+            pending_type: OrderType::LimitMaker,
+            pending_side: OrderSide::Sell,
+            pending_price: Some(num("65000")),
+            pending_quantity: num("0.01"),
+            ..OTOOrderRequest::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(oto.order_list_id, 629);
+    assert_params(
+        "/api/v3/orderList/oto",
+        &[
+            ("workingType", "LIMIT"),
+            ("workingSide", "BUY"),
+            ("workingPrice", "60000"),
+            ("workingQuantity", "0.01"),
+            ("workingTimeInForce", "GTC"),
+            ("pendingType", "LIMIT_MAKER"),
+            ("pendingSide", "SELL"),
+            ("pendingPrice", "65000"),
+            ("pendingQuantity", "0.01"),
+        ],
+        &["pendingStopPrice", "pendingTimeInForce"],
+    );
+
+    account
+        .place_otoco_order(OTOCOOrderRequest {
+            symbol: "BTCUSDT".to_string(),
+            working_type: OrderType::Limit,
+            working_side: OrderSide::Buy,
+            working_price: num("60000"),
+            working_quantity: num("0.01"),
+            working_time_in_force: Some(TimeInForce::GTC),
+            pending_side: OrderSide::Sell,
+            pending_quantity: num("0.01"),
+            pending_above_type: OrderType::LimitMaker,
+            pending_above_price: Some(num("65000")),
+            pending_below_type: Some(OrderType::StopLoss),
+            pending_below_stop_price: Some(num("58000")),
+            ..OTOCOOrderRequest::default()
+        })
+        .await
+        .unwrap();
+    assert_params(
+        "/api/v3/orderList/otoco",
+        &[
+            ("workingType", "LIMIT"),
+            ("workingPrice", "60000"),
+            ("pendingSide", "SELL"),
+            ("pendingQuantity", "0.01"),
+            ("pendingAboveType", "LIMIT_MAKER"),
+            ("pendingAbovePrice", "65000"),
+            ("pendingBelowType", "STOP_LOSS"),
+            ("pendingBelowStopPrice", "58000"),
+        ],
+        &["pendingBelowPrice", "pendingAboveStopPrice"],
+    );
+}
+
+#[tokio::test]
+async fn futures_batch_orders() {
+    use binance::futures::account::{FuturesAccount, OrderRequest};
+    use binance::futures::rest_model::OrderType;
+
+    let server = MockServer::start("key", "secret").await;
+    let placed = include_str!("../test_data/fixtures/futures/rest/new_order.json");
+    let placed: Value = serde_json::from_str(placed).unwrap();
+    server.mock(
+        "POST",
+        "/fapi/v1/batchOrders",
+        MockResponse::json(&json!([placed, {"code": -2019, "msg": "Margin is insufficient."}])),
+    );
+    let limiter = RateLimiter::new(RateLimitMode::FailFast);
+    limiter.set_limits(&server.rest_endpoint(), &[RateLimit {
+        interval: RateLimitInterval::Second,
+        rate_limit_type: RateLimitType::Orders,
+        interval_num: 10,
+        limit: 3,
+    }]);
+    let config = server.config().set_rate_limiter(limiter);
+    let account: FuturesAccount = Binance::new_with_config(Some("key".into()), Some("secret".into()), &config);
+    let order = |side, price: &str| OrderRequest {
+        symbol: "BTCUSDT".to_string(),
+        side,
+        order_type: OrderType::Limit,
+        time_in_force: Some(TimeInForce::GTC),
+        quantity: Some(num("0.01")),
+        price: Some(num(price)),
+        ..OrderRequest::default()
+    };
+    let results = account
+        .place_batch_orders(vec![order(OrderSide::Buy, "30000"), order(OrderSide::Sell, "40000")])
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].as_ref().unwrap().order_id, 22542179);
+    assert!(matches!(&results[1], Err(Error::BinanceError { response }) if response.code == -2019));
+
+    let request = server.requests().pop().unwrap();
+    assert_eq!(request.signature_valid, Some(true));
+    let batch: Vec<Value> = serde_json::from_str(&request.params["batchOrders"]).unwrap();
+    assert_eq!(batch.len(), 2);
+    assert_eq!(batch[0]["side"], "BUY");
+    assert_eq!(batch[0]["type"], "LIMIT");
+    assert_eq!(batch[0]["timeInForce"], "GTC");
+    assert_eq!(batch[1]["side"], "SELL");
+    assert_eq!(batch[1]["price"].as_str().map(num), Some(num("40000")));
+
+    // the two orders counted against the limit of 3, so two more are throttled locally
+    assert!(matches!(
+        account.place_batch_orders((0..2).map(|_| order(OrderSide::Buy, "30000")).collect()).await,
+        Err(Error::RateLimited { .. })
+    ));
+    assert!(matches!(
+        account.place_batch_orders((0..6).map(|_| order(OrderSide::Buy, "30000")).collect()).await,
+        Err(Error::InvalidOrderError { .. })
+    ));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[cfg(feature = "options")]
+#[tokio::test]
+async fn options_orders() {
+    use binance::options::account::{OptionsAccount, OrderQuery, OrderRequest};
+    use binance::options::rest_model::{OrderSide, OrderType};
+
+    let server = MockServer::start("key", "secret").await;
+    let order = include_str!("../test_data/fixtures/options/rest/order.json");
+    let order: Value = serde_json::from_str(order).unwrap();
+    server.mock("POST", "/eapi/v1/order", MockResponse::json(&order));
+    server.mock("DELETE", "/eapi/v1/order", MockResponse::json(&order));
+    let account: OptionsAccount = Binance::new_with_config(Some("key".into()), Some("secret".into()), &server.config());
+    let placed = account
+        .place_order(OrderRequest {
+            symbol: "BTC-200730-9000-C".to_string(),
+            side: OrderSide::Buy,
+            quantity: num("1"),
+            price: num("100"),
+            ..OrderRequest::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(placed.order_id, 4611875134427365377);
+    assert_eq!(placed.order_type, OrderType::Limit);
+
+    // options only take limit orders, placed with the full result
+    let request = server.requests().pop().unwrap();
+    assert_eq!((request.method.as_str(), request.path.as_str()), ("POST", "/eapi/v1/order"));
+    assert_eq!(request.signature_valid, Some(true));
+    assert_eq!(request.params["type"], "LIMIT");
+    assert_eq!(request.params["newOrderRespType"], "RESULT");
+    assert_eq!(request.params["side"], "BUY");
+    assert_eq!(num(&request.params["quantity"]), num("1"));
+    assert_eq!(num(&request.params["price"]), num("100"));
+    assert!(!request.params.contains_key("timeInForce"));
+
+    let canceled = account
+        .cancel_order(OrderQuery {
+            symbol: "BTC-200730-9000-C".to_string(),
+            order_id: Some(placed.order_id),
+            ..OrderQuery::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(canceled.order_id, placed.order_id);
+    let request = server.requests().pop().unwrap();
+    assert_eq!((request.method.as_str(), request.path.as_str()), ("DELETE", "/eapi/v1/order"));
+    assert_eq!(request.signature_valid, Some(true));
+    assert_eq!(request.params["symbol"], "BTC-200730-9000-C");
+    assert_eq!(request.params["orderId"], "4611875134427365377");
+    assert!(!request.params.contains_key("clientOrderId"));
+}
+
+#[cfg(feature = "portfolio_margin")]
+#[tokio::test]
+async fn portfolio_margin_time_sync() {
+    use binance::portfolio_margin::account::PortfolioMarginAccount;
+
+    // the portfolio margin API has no time endpoint, the server time comes from the spot API
+    let (spot, papi) = (MockServer::start("key", "secret").await, MockServer::start("key", "secret").await);
+    let balance = include_str!("../test_data/fixtures/portfolio_margin/rest/balance.json");
+    let balance: Value = serde_json::from_str(balance).unwrap();
+    papi.mock("GET", "/papi/v1/balance", MockResponse::json(&json!([balance])));
+    let expired = MockResponse::error(400, -1021, "Timestamp for this request is outside of the recvWindow.");
+    papi.mock_once("GET", "/papi/v1/balance", expired);
+    let config = papi
+        .config()
+        .set_rest_api_endpoint(spot.rest_endpoint())
+        .set_time_sync_interval(0);
+    let account: PortfolioMarginAccount = Binance::new_with_config(Some("key".into()), Some("secret".into()), &config);
+    let balances = account.account_balance().await.unwrap();
+    assert_eq!(balances[0].asset, "USDT");
+    // synced before the first request, then again when it was rejected
+    let paths = |server: &MockServer| server.requests().into_iter().map(|r| r.path).collect::<Vec<_>>();
+    assert_eq!(paths(&spot), ["/api/v3/time", "/api/v3/time"]);
+    assert_eq!(paths(&papi), ["/papi/v1/balance", "/papi/v1/balance"]);
+    assert!(papi.requests().iter().all(|r| r.signature_valid == Some(true)));
+}
+
+#[tokio::test]
+async fn custom_signer() {
+    let server = MockServer::start("key", "secret").await;
+    server.mock("GET", "/api/v3/openOrders", MockResponse::json(&json!([])));
+    let config = server.config().set_signer(HmacSigner::new("secret"));
+    let account: Account = Binance::new_with_config(Some("key".into()), None, &config);
+    account.get_open_orders("BTCUSDT").await.unwrap();
+    assert_eq!(server.requests()[0].signature_valid, Some(true));
+
+    // invalid keys fail early, or on the first signed request
+    let config = server.config().set_signature_method(SignatureMethod::Ed25519);
+    let invalid = BinanceClient::new(Some("key".into()), Some("not a key".into()), &config);
+    assert!(matches!(invalid.err(), Some(Error::InvalidKey(_))));
+    let account: Account = Binance::new_with_config(Some("key".into()), Some("not a key".into()), &config);
+    assert!(matches!(account.get_open_orders("BTCUSDT").await, Err(Error::InvalidKey(_))));
+}
+
+/// Fails the first request with a 503
+struct Flaky {
+    inner: ReqwestTransport,
+    sent: Arc<AtomicU32>,
+}
+
+impl Transport for Flaky {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, binance::errors::Result<HttpResponse>> {
+        Box::pin(async move {
+            if self.sent.fetch_add(1, Ordering::Relaxed) == 0 {
+                return Ok(HttpResponse {
+                    status: StatusCode::SERVICE_UNAVAILABLE,
+                    headers: HeaderMap::new(),
+                    body: vec![],
+                });
+            }
+            self.inner.send(request).await
+        })
+    }
+}
+
+#[tokio::test]
+async fn custom_transport() {
+    let server = MockServer::start("key", "secret").await;
+    server.mock("GET", "/api/v3/ticker/price", MockResponse::json(&json!({"symbol": "BTCUSDT", "price": "1.25"})));
+    let sent = Arc::new(AtomicU32::new(0));
+    let transport = Flaky {
+        inner: ReqwestTransport::default(),
+        sent: sent.clone(),
+    };
+    let retry_policy = RetryPolicy {
+        initial_delay_ms: 1,
+        ..RetryPolicy::default()
+    };
+    let config = server.config().set_transport(transport).set_retry_policy(retry_policy);
+    let market: Market = Binance::new_with_config(None, None, &config);
+    assert_eq!(market.get_price("BTCUSDT").await.unwrap().price, num("1.25"));
+    assert_eq!(sent.load(Ordering::Relaxed), 2);
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn network_options() {
+    let server = MockServer::start("key", "secret").await;
+    server.mock("GET", "/api/v3/ticker/price", MockResponse::json(&json!({"symbol": "BTCUSDT", "price": "1.25"})));
+    let config = server
+        .config()
+        .set_local_address([127, 0, 0, 1].into())
+        .set_connect_timeout(1000)
+        .set_tcp_keepalive(30);
+    let market: Market = Binance::new_with_config(None, None, &config);
+    assert_eq!(market.get_price("BTCUSDT").await.unwrap().price, num("1.25"));
+    let (_, mut events) = connect_stream::<WebsocketEvent>(&config, &agg_trade_stream("btcusdt"))
+        .await
+        .unwrap();
+    while server.ws_paths().is_empty() {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    server.disconnect_websockets();
+    assert!(events.next().await.is_none());
+
+    let unreachable = server.config().set_proxy("http://127.0.0.1:1");
+    assert!(connect_stream::<WebsocketEvent>(&unreachable, &agg_trade_stream("btcusdt"))
+        .await
+        .is_err());
+
+    let proxy = MockProxy::start().await;
+    let ws_addr = server.ws_endpoint().replace("ws://", "");
+    let mut schemes = vec!["http"];
+    if cfg!(feature = "socks") {
+        schemes.push("socks5");
+    }
+    for scheme in schemes {
+        let proxied = server.config().set_proxy(proxy.url(scheme));
+        let market: Market = Binance::new_with_config(None, None, &proxied);
+        assert_eq!(market.get_price("BTCUSDT").await.unwrap().price, num("1.25"));
+        let connected = server.ws_paths().len();
+        let (_, mut events) = connect_stream::<WebsocketEvent>(&proxied, &agg_trade_stream("btcusdt"))
+            .await
+            .unwrap();
+        while server.ws_paths().len() == connected {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        server.disconnect_websockets();
+        assert!(events.next().await.is_none());
+    }
+    let rest = format!("GET {}/api/v3/ticker/price?symbol=BTCUSDT HTTP/1.1", server.rest_endpoint());
+    let mut expected = vec![rest, format!("CONNECT {ws_addr} HTTP/1.1")];
+    if cfg!(feature = "socks") {
+        let rest_addr = server.rest_endpoint().replace("http://", "");
+        expected.extend([format!("SOCKS5 {rest_addr}"), format!("SOCKS5 {ws_addr}")]);
+    }
+    assert_eq!(proxy.connections(), expected);
+}